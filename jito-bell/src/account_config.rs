@@ -0,0 +1,93 @@
+use serde::Deserialize;
+
+use crate::notification_info::NotificationInfo;
+
+/// Kind of account, deciding how its data is decoded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountType {
+    /// SPL Stake Pool `StakePool`
+    StakePool,
+
+    /// Jito Vault `Vault`
+    Vault,
+
+    /// Jito Steward `Config`
+    StewardConfig,
+}
+
+impl std::fmt::Display for AccountType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::StakePool => write!(f, "stake_pool"),
+            Self::Vault => write!(f, "vault"),
+            Self::StewardConfig => write!(f, "steward_config"),
+        }
+    }
+}
+
+/// Account subscribed to, with rules evaluated on every change
+#[derive(Debug, Clone, Deserialize)]
+pub struct WatchedAccount {
+    /// Name used in notifications
+    pub name: String,
+
+    /// Account address
+    pub address: String,
+
+    /// Account type
+    pub account_type: AccountType,
+
+    /// Rules on field changes
+    #[serde(default)]
+    pub rules: Vec<AccountRule>,
+}
+
+/// Rule on a decoded account field
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccountRule {
+    /// Field name, as listed by `AccountState`
+    pub field: String,
+
+    /// Condition on the old and new value
+    pub condition: FieldCondition,
+
+    /// Minimum change for `increased` / `decreased`, in the field's unit
+    #[serde(default)]
+    pub min_delta: f64,
+
+    /// Notification info
+    pub notification: NotificationInfo,
+}
+
+/// Condition on the change of a field between two account updates
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldCondition {
+    /// Value changed
+    Changed,
+
+    /// Numeric value increased by at least `min_delta`
+    Increased,
+
+    /// Numeric value decreased by at least `min_delta`
+    Decreased,
+
+    /// Numeric value reached the threshold from below
+    RisesTo(f64),
+
+    /// Numeric value dropped below the threshold
+    FallsBelow(f64),
+}
+
+impl std::fmt::Display for FieldCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Changed => write!(f, "changed"),
+            Self::Increased => write!(f, "increased"),
+            Self::Decreased => write!(f, "decreased"),
+            Self::RisesTo(threshold) => write!(f, "rises to {threshold}"),
+            Self::FallsBelow(threshold) => write!(f, "falls below {threshold}"),
+        }
+    }
+}
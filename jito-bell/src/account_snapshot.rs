@@ -0,0 +1,74 @@
+use std::{collections::HashMap, sync::Arc};
+
+use log::debug;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+
+/// Account data read over RPC, with the slot it was read at
+///
+/// - Used for the old values of governance changes: by the time a transaction is seen,
+///   RPC already serves the account as it changed
+/// - Snapshots are refreshed after each observed change, so later changes compare against it
+pub(crate) struct AccountSnapshots {
+    /// RPC Client
+    rpc_client: Arc<RpcClient>,
+
+    /// Slot and data of each account
+    accounts: HashMap<Pubkey, (u64, Vec<u8>)>,
+}
+
+impl AccountSnapshots {
+    /// Initialize account snapshots
+    pub(crate) fn new(rpc_client: Arc<RpcClient>) -> Self {
+        Self {
+            rpc_client,
+            accounts: HashMap::new(),
+        }
+    }
+
+    /// Data of `address` as of before `slot`
+    ///
+    /// - An account without a snapshot is read now, which only helps if RPC is behind `slot`
+    pub(crate) async fn before(&mut self, address: &Pubkey, slot: u64) -> Option<Vec<u8>> {
+        if !self.accounts.contains_key(address) {
+            let (read_slot, data) = fetch_with_slot(&self.rpc_client, address).await?;
+            self.accounts.insert(*address, (read_slot, data));
+        }
+
+        self.accounts
+            .get(address)
+            .filter(|(read_slot, _)| *read_slot < slot)
+            .map(|(_, data)| data.clone())
+    }
+
+    /// Read `address` again, keeping the snapshot only if RPC has reached `slot`
+    pub(crate) async fn refresh(&mut self, address: &Pubkey, slot: u64) {
+        match fetch_with_slot(&self.rpc_client, address).await {
+            Some((read_slot, data)) if read_slot >= slot => {
+                self.accounts.insert(*address, (read_slot, data));
+            }
+            _ => {
+                self.accounts.remove(address);
+            }
+        }
+    }
+}
+
+/// Read `address` with the slot RPC served it at
+pub(crate) async fn fetch_with_slot(
+    rpc_client: &RpcClient,
+    address: &Pubkey,
+) -> Option<(u64, Vec<u8>)> {
+    match rpc_client
+        .get_account_with_commitment(address, rpc_client.commitment())
+        .await
+    {
+        Ok(response) => response
+            .value
+            .map(|account| (response.context.slot, account.data)),
+        Err(e) => {
+            debug!("Failed to fetch {address}: {e}");
+            None
+        }
+    }
+}
@@ -0,0 +1,407 @@
+use std::collections::BTreeMap;
+
+use borsh::BorshDeserialize;
+use jito_vault_client::accounts::Vault;
+use solana_sdk::{hash::hashv, pubkey::Pubkey};
+use spl_stake_pool::state::{Fee, StakePool};
+
+use crate::{
+    account_config::{AccountRule, AccountType, FieldCondition},
+    error::JitoBellError,
+};
+
+/// Decoded account field
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldValue {
+    Integer(u64),
+    Float(f64),
+    Bool(bool),
+    Pubkey(Pubkey),
+}
+
+impl FieldValue {
+    /// Numeric value, `None` for booleans and addresses
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Integer(value) => Some(*value as f64),
+            Self::Float(value) => Some(*value),
+            Self::Bool(_) | Self::Pubkey(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Float(value) => write!(f, "{value:.4}"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Pubkey(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Fields of a decoded account, by name
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountState {
+    /// Field values
+    pub fields: BTreeMap<&'static str, FieldValue>,
+}
+
+impl AccountState {
+    /// Decode account data according to `account_type`
+    pub fn decode(account_type: AccountType, data: &[u8]) -> Result<Self, JitoBellError> {
+        let fields = match account_type {
+            AccountType::StakePool => {
+                let stake_pool =
+                    <StakePool as borsh1::BorshDeserialize>::deserialize(&mut &data[..])
+                        .map_err(|e| JitoBellError::AccountDecode(format!("StakePool: {e}")))?;
+                Self::stake_pool_fields(&stake_pool)
+            }
+            AccountType::Vault => {
+                let vault = Vault::deserialize(&mut &data[..])
+                    .map_err(|e| JitoBellError::AccountDecode(format!("Vault: {e}")))?;
+                Self::vault_fields(&vault)
+            }
+            AccountType::StewardConfig => Self::steward_config_fields(data)?,
+        };
+
+        Ok(Self {
+            fields: fields.into_iter().collect(),
+        })
+    }
+
+    /// Value of `field`
+    pub fn get(&self, field: &str) -> Option<&FieldValue> {
+        self.fields.get(field)
+    }
+
+    fn stake_pool_fields(stake_pool: &StakePool) -> Vec<(&'static str, FieldValue)> {
+        let fee = |fee: &Fee| {
+            FieldValue::Float(if fee.denominator == 0 {
+                0.0
+            } else {
+                fee.numerator as f64 / fee.denominator as f64
+            })
+        };
+        let optional = |address: Option<Pubkey>| FieldValue::Pubkey(address.unwrap_or_default());
+        let pool_token_price = if stake_pool.pool_token_supply == 0 {
+            0.0
+        } else {
+            stake_pool.total_lamports as f64 / stake_pool.pool_token_supply as f64
+        };
+
+        vec![
+            ("manager", FieldValue::Pubkey(stake_pool.manager)),
+            ("staker", FieldValue::Pubkey(stake_pool.staker)),
+            (
+                "stake_deposit_authority",
+                FieldValue::Pubkey(stake_pool.stake_deposit_authority),
+            ),
+            (
+                "sol_deposit_authority",
+                optional(stake_pool.sol_deposit_authority),
+            ),
+            (
+                "sol_withdraw_authority",
+                optional(stake_pool.sol_withdraw_authority),
+            ),
+            (
+                "manager_fee_account",
+                FieldValue::Pubkey(stake_pool.manager_fee_account),
+            ),
+            (
+                "preferred_deposit_validator",
+                optional(stake_pool.preferred_deposit_validator_vote_address),
+            ),
+            (
+                "preferred_withdraw_validator",
+                optional(stake_pool.preferred_withdraw_validator_vote_address),
+            ),
+            (
+                "total_lamports",
+                FieldValue::Integer(stake_pool.total_lamports),
+            ),
+            (
+                "pool_token_supply",
+                FieldValue::Integer(stake_pool.pool_token_supply),
+            ),
+            ("pool_token_price", FieldValue::Float(pool_token_price)),
+            (
+                "last_update_epoch",
+                FieldValue::Integer(stake_pool.last_update_epoch),
+            ),
+            ("epoch_fee", fee(&stake_pool.epoch_fee)),
+            ("stake_deposit_fee", fee(&stake_pool.stake_deposit_fee)),
+            (
+                "stake_withdrawal_fee",
+                fee(&stake_pool.stake_withdrawal_fee),
+            ),
+            ("sol_deposit_fee", fee(&stake_pool.sol_deposit_fee)),
+            ("sol_withdrawal_fee", fee(&stake_pool.sol_withdrawal_fee)),
+            (
+                "stake_referral_fee",
+                FieldValue::Integer(stake_pool.stake_referral_fee as u64),
+            ),
+            (
+                "sol_referral_fee",
+                FieldValue::Integer(stake_pool.sol_referral_fee as u64),
+            ),
+        ]
+    }
+
+    fn vault_fields(vault: &Vault) -> Vec<(&'static str, FieldValue)> {
+        let deposit_capacity_utilization = if vault.deposit_capacity == 0 {
+            0.0
+        } else {
+            vault.tokens_deposited as f64 * 100.0 / vault.deposit_capacity as f64
+        };
+
+        vec![
+            ("vrt_supply", FieldValue::Integer(vault.vrt_supply)),
+            (
+                "tokens_deposited",
+                FieldValue::Integer(vault.tokens_deposited),
+            ),
+            (
+                "deposit_capacity",
+                FieldValue::Integer(vault.deposit_capacity),
+            ),
+            (
+                "deposit_capacity_utilization",
+                FieldValue::Float(deposit_capacity_utilization),
+            ),
+            (
+                "additional_assets_need_unstaking",
+                FieldValue::Integer(vault.additional_assets_need_unstaking),
+            ),
+            (
+                "vrt_enqueued_for_cooldown_amount",
+                FieldValue::Integer(vault.vrt_enqueued_for_cooldown_amount),
+            ),
+            (
+                "vrt_cooling_down_amount",
+                FieldValue::Integer(vault.vrt_cooling_down_amount),
+            ),
+            (
+                "vrt_ready_to_claim_amount",
+                FieldValue::Integer(vault.vrt_ready_to_claim_amount),
+            ),
+            ("admin", FieldValue::Pubkey(vault.admin)),
+            (
+                "delegation_admin",
+                FieldValue::Pubkey(vault.delegation_admin),
+            ),
+            ("operator_admin", FieldValue::Pubkey(vault.operator_admin)),
+            ("ncn_admin", FieldValue::Pubkey(vault.ncn_admin)),
+            ("slasher_admin", FieldValue::Pubkey(vault.slasher_admin)),
+            ("capacity_admin", FieldValue::Pubkey(vault.capacity_admin)),
+            ("fee_admin", FieldValue::Pubkey(vault.fee_admin)),
+            (
+                "delegate_asset_admin",
+                FieldValue::Pubkey(vault.delegate_asset_admin),
+            ),
+            ("fee_wallet", FieldValue::Pubkey(vault.fee_wallet)),
+            ("mint_burn_admin", FieldValue::Pubkey(vault.mint_burn_admin)),
+            ("metadata_admin", FieldValue::Pubkey(vault.metadata_admin)),
+            ("ncn_count", FieldValue::Integer(vault.ncn_count)),
+            ("operator_count", FieldValue::Integer(vault.operator_count)),
+            ("slasher_count", FieldValue::Integer(vault.slasher_count)),
+            (
+                "last_full_state_update_slot",
+                FieldValue::Integer(vault.last_full_state_update_slot),
+            ),
+            (
+                "deposit_fee_bps",
+                FieldValue::Integer(vault.deposit_fee_bps as u64),
+            ),
+            (
+                "withdrawal_fee_bps",
+                FieldValue::Integer(vault.withdrawal_fee_bps as u64),
+            ),
+            (
+                "next_withdrawal_fee_bps",
+                FieldValue::Integer(vault.next_withdrawal_fee_bps as u64),
+            ),
+            (
+                "reward_fee_bps",
+                FieldValue::Integer(vault.reward_fee_bps as u64),
+            ),
+            (
+                "program_fee_bps",
+                FieldValue::Integer(vault.program_fee_bps as u64),
+            ),
+            ("is_paused", FieldValue::Bool(vault.is_paused)),
+        ]
+    }
+
+    /// Authorities at the start of the steward `Config` account
+    ///
+    /// - The steward crate is not a dependency, so only the leading fields, which have a
+    ///   fixed layout after the Anchor discriminator, are decoded
+    fn steward_config_fields(
+        data: &[u8],
+    ) -> Result<Vec<(&'static str, FieldValue)>, JitoBellError> {
+        const NAMES: [&str; 5] = [
+            "stake_pool",
+            "validator_list",
+            "admin",
+            "parameters_authority",
+            "blacklist_authority",
+        ];
+
+        let discriminator = &hashv(&[b"account:Config"]).to_bytes()[..8];
+        if data.len() < 8 + NAMES.len() * 32 || &data[..8] != discriminator {
+            return Err(JitoBellError::AccountDecode(
+                "Not a steward Config account".to_string(),
+            ));
+        }
+
+        Ok(NAMES
+            .into_iter()
+            .zip(data[8..].chunks_exact(32))
+            .map(|(name, bytes)| {
+                let mut address = [0; 32];
+                address.copy_from_slice(bytes);
+                (name, FieldValue::Pubkey(Pubkey::new_from_array(address)))
+            })
+            .collect())
+    }
+}
+
+impl AccountRule {
+    /// Old and new value of the rule's field if its condition is met
+    pub fn evaluate(
+        &self,
+        previous: &AccountState,
+        current: &AccountState,
+    ) -> Option<(FieldValue, FieldValue)> {
+        let old = *previous.get(&self.field)?;
+        let new = *current.get(&self.field)?;
+
+        let met = match (self.condition, old.as_f64(), new.as_f64()) {
+            (FieldCondition::Changed, _, _) => old != new,
+            (FieldCondition::Increased, Some(old), Some(new)) => {
+                new > old && new - old >= self.min_delta
+            }
+            (FieldCondition::Decreased, Some(old), Some(new)) => {
+                new < old && old - new >= self.min_delta
+            }
+            (FieldCondition::RisesTo(threshold), Some(old), Some(new)) => {
+                old < threshold && new >= threshold
+            }
+            (FieldCondition::FallsBelow(threshold), Some(old), Some(new)) => {
+                old >= threshold && new < threshold
+            }
+            _ => false,
+        };
+
+        met.then_some((old, new))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+    use jito_vault_client::accounts::Vault;
+    use solana_sdk::{hash::hashv, pubkey::Pubkey};
+    use spl_stake_pool::state::StakePool;
+
+    use super::{AccountState, FieldValue};
+    use crate::{
+        account_config::{AccountRule, AccountType, FieldCondition},
+        notification_info::NotificationInfo,
+    };
+
+    fn rule(field: &str, condition: FieldCondition, min_delta: f64) -> AccountRule {
+        AccountRule {
+            field: field.to_string(),
+            condition,
+            min_delta,
+            notification: NotificationInfo {
+                description: String::new(),
+                destinations: vec![],
+            },
+        }
+    }
+
+    fn vault_state(tokens_deposited: u64, is_paused: bool) -> AccountState {
+        let mut vault = Vault::from_bytes(&[0; 4096]).unwrap();
+        vault.tokens_deposited = tokens_deposited;
+        vault.deposit_capacity = 1_000;
+        vault.is_paused = is_paused;
+
+        AccountState::decode(AccountType::Vault, &vault.try_to_vec().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_decode_stake_pool() {
+        let stake_pool = StakePool {
+            total_lamports: 3_000,
+            pool_token_supply: 2_000,
+            ..Default::default()
+        };
+        let mut data = borsh1::to_vec(&stake_pool).unwrap();
+        // Stake pool accounts are zero-padded
+        data.resize(data.len() + 64, 0);
+
+        let state = AccountState::decode(AccountType::StakePool, &data).unwrap();
+
+        assert_eq!(
+            state.get("total_lamports"),
+            Some(&FieldValue::Integer(3_000))
+        );
+        assert_eq!(state.get("pool_token_price"), Some(&FieldValue::Float(1.5)));
+    }
+
+    #[test]
+    fn test_decode_steward_config() {
+        let admin = Pubkey::new_unique();
+        let mut data = hashv(&[b"account:Config"]).to_bytes()[..8].to_vec();
+        data.extend_from_slice(&[0; 64]);
+        data.extend_from_slice(admin.as_ref());
+        data.extend_from_slice(&[0; 128]);
+
+        let state = AccountState::decode(AccountType::StewardConfig, &data).unwrap();
+        assert_eq!(state.get("admin"), Some(&FieldValue::Pubkey(admin)));
+
+        data[0] ^= 1;
+        assert!(AccountState::decode(AccountType::StewardConfig, &data).is_err());
+    }
+
+    #[test]
+    fn test_rule_conditions() {
+        let before = vault_state(900, false);
+        let after = vault_state(1_000, true);
+
+        assert!(rule("is_paused", FieldCondition::Changed, 0.0)
+            .evaluate(&before, &after)
+            .is_some());
+        assert!(rule("tokens_deposited", FieldCondition::Increased, 100.0)
+            .evaluate(&before, &after)
+            .is_some());
+        assert!(rule("tokens_deposited", FieldCondition::Increased, 101.0)
+            .evaluate(&before, &after)
+            .is_none());
+        assert!(rule("tokens_deposited", FieldCondition::Decreased, 0.0)
+            .evaluate(&before, &after)
+            .is_none());
+        assert_eq!(
+            rule(
+                "deposit_capacity_utilization",
+                FieldCondition::RisesTo(100.0),
+                0.0
+            )
+            .evaluate(&before, &after),
+            Some((FieldValue::Float(90.0), FieldValue::Float(100.0)))
+        );
+        assert!(
+            rule("tokens_deposited", FieldCondition::FallsBelow(950.0), 0.0)
+                .evaluate(&after, &before)
+                .is_some()
+        );
+        assert!(rule("unknown_field", FieldCondition::Changed, 0.0)
+            .evaluate(&before, &after)
+            .is_none());
+    }
+}
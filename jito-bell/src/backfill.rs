@@ -0,0 +1,311 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    fs::{File, OpenOptions},
+    io::Write,
+    path::PathBuf,
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
+
+use log::{info, warn};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{clock::UnixTimestamp, pubkey::Pubkey, signature::Signature};
+use tokio::time::{Interval, MissedTickBehavior};
+use yellowstone_grpc_proto::geyser::SubscribeUpdateTransaction;
+
+use crate::{
+    backoff::Backoff,
+    cli_args::BackfillArgs,
+    error::JitoBellError,
+    transaction_source::rpc::{
+        fetch_transaction, signatures_page, AddressSignature, SIGNATURE_PAGE_LIMIT,
+    },
+};
+
+/// Attempts per RPC request before giving up on it
+const MAX_RPC_ATTEMPTS: u32 = 5;
+
+/// Transactions to backfill
+pub enum BackfillTarget {
+    /// Every transaction touching a watched address in `start_slot..=end_slot`
+    SlotRange { start_slot: u64, end_slot: u64 },
+
+    /// Signatures listed in a file, one per line
+    SignaturesFile(PathBuf),
+}
+
+pub struct BackfillOption {
+    /// Transactions to backfill
+    pub target: BackfillTarget,
+
+    /// Send notifications instead of only logging them
+    pub send_notifications: bool,
+
+    /// File recording processed signatures
+    pub checkpoint_file: Option<PathBuf>,
+
+    /// Minimum delay between RPC requests
+    pub rpc_request_interval: Duration,
+}
+
+impl BackfillOption {
+    pub fn new(arg: BackfillArgs) -> Result<Self, JitoBellError> {
+        let target = match (arg.start_slot, arg.end_slot, arg.signatures_file) {
+            (Some(start_slot), Some(end_slot), None) if start_slot <= end_slot => {
+                BackfillTarget::SlotRange {
+                    start_slot,
+                    end_slot,
+                }
+            }
+            (None, None, Some(path)) => BackfillTarget::SignaturesFile(path),
+            _ => {
+                return Err(JitoBellError::Config(
+                    "Backfill needs either --start-slot <= --end-slot or --signatures-file"
+                        .to_string(),
+                ));
+            }
+        };
+
+        Ok(Self {
+            target,
+            send_notifications: arg.send_notifications,
+            checkpoint_file: arg.checkpoint_file,
+            rpc_request_interval: Duration::from_secs(1) / arg.rpc_requests_per_second.max(1),
+        })
+    }
+}
+
+/// Fetches historical transactions over RPC, throttled and retried
+pub(crate) struct BackfillFetcher {
+    /// RPC Client
+    rpc_client: Arc<RpcClient>,
+
+    /// Ticks once per allowed RPC request
+    rate_limit: Interval,
+}
+
+impl BackfillFetcher {
+    /// Initialize backfill fetcher
+    pub(crate) fn new(rpc_client: Arc<RpcClient>, rpc_request_interval: Duration) -> Self {
+        let mut rate_limit = tokio::time::interval(rpc_request_interval);
+        rate_limit.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        Self {
+            rpc_client,
+            rate_limit,
+        }
+    }
+
+    /// Signatures to backfill, oldest first
+    ///
+    /// - Slot ranges are resolved by paging `getSignaturesForAddress` back from the newest
+    ///   signature of each address, so older ranges need more requests
+    pub(crate) async fn signatures(
+        &mut self,
+        target: &BackfillTarget,
+        addresses: &[Pubkey],
+        include_failed: bool,
+    ) -> Result<Vec<Signature>, JitoBellError> {
+        match target {
+            BackfillTarget::SlotRange {
+                start_slot,
+                end_slot,
+            } => {
+                let mut signatures = BTreeSet::new();
+                for address in addresses {
+                    for entry in self
+                        .signatures_in_slot_range(address, *start_slot, *end_slot)
+                        .await?
+                    {
+                        if include_failed || !entry.failed {
+                            signatures.insert((entry.slot, entry.signature));
+                        }
+                    }
+                }
+
+                Ok(signatures
+                    .into_iter()
+                    .map(|(_, signature)| signature)
+                    .collect())
+            }
+            BackfillTarget::SignaturesFile(path) => {
+                let content = std::fs::read_to_string(path)?;
+                read_signatures(&content)
+            }
+        }
+    }
+
+    /// Signatures for `address` landed in `start_slot..=end_slot`
+    async fn signatures_in_slot_range(
+        &mut self,
+        address: &Pubkey,
+        start_slot: u64,
+        end_slot: u64,
+    ) -> Result<Vec<AddressSignature>, JitoBellError> {
+        let mut signatures = Vec::new();
+        let mut before = None;
+
+        loop {
+            let rpc_client = self.rpc_client.clone();
+            let page = self
+                .retry(|| signatures_page(&rpc_client, address, before, None, SIGNATURE_PAGE_LIMIT))
+                .await?;
+            let page_len = page.len();
+            let Some(oldest) = page.last() else {
+                break;
+            };
+            let oldest_slot = oldest.slot;
+            before = Some(oldest.signature);
+
+            signatures.extend(
+                page.into_iter()
+                    .filter(|entry| (start_slot..=end_slot).contains(&entry.slot)),
+            );
+            info!(
+                "Backfill scanned {address} down to slot {oldest_slot}, {} signatures in range",
+                signatures.len()
+            );
+
+            if oldest_slot < start_slot || page_len < SIGNATURE_PAGE_LIMIT {
+                break;
+            }
+        }
+
+        Ok(signatures)
+    }
+
+    /// Fetch a transaction in the Geyser shape, along with its block time
+    pub(crate) async fn transaction(
+        &mut self,
+        signature: &Signature,
+    ) -> Result<(SubscribeUpdateTransaction, Option<UnixTimestamp>), JitoBellError> {
+        let rpc_client = self.rpc_client.clone();
+        self.retry(|| fetch_transaction(&rpc_client, signature))
+            .await
+    }
+
+    /// Run an RPC request within the rate limit, retrying failures with backoff
+    async fn retry<T, F, Fut>(&mut self, mut request: F) -> Result<T, JitoBellError>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<T, JitoBellError>>,
+    {
+        let mut backoff = Backoff::new(Duration::from_millis(500), Duration::from_secs(30));
+
+        loop {
+            self.rate_limit.tick().await;
+
+            match request().await {
+                Ok(value) => return Ok(value),
+                Err(e) if backoff.attempt() + 1 < MAX_RPC_ATTEMPTS => {
+                    let delay = backoff.next_delay();
+                    warn!("Backfill RPC request failed, retrying in {delay:?}: {e}");
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// Processed signatures, persisted so an interrupted backfill can resume
+pub(crate) struct Checkpoint {
+    /// Append-only file, one signature per line
+    file: Option<File>,
+
+    /// Signatures already processed
+    processed: HashSet<Signature>,
+}
+
+impl Checkpoint {
+    /// Load the checkpoint file, creating it if missing
+    pub(crate) fn load(path: Option<&PathBuf>) -> Result<Self, JitoBellError> {
+        let Some(path) = path else {
+            return Ok(Self {
+                file: None,
+                processed: HashSet::new(),
+            });
+        };
+
+        let processed = match std::fs::read_to_string(path) {
+            Ok(content) => read_signatures(&content)?.into_iter().collect(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashSet::new(),
+            Err(e) => return Err(e.into()),
+        };
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        Ok(Self {
+            file: Some(file),
+            processed,
+        })
+    }
+
+    /// Number of signatures already processed
+    pub(crate) fn len(&self) -> usize {
+        self.processed.len()
+    }
+
+    /// Whether `signature` was already processed
+    pub(crate) fn contains(&self, signature: &Signature) -> bool {
+        self.processed.contains(signature)
+    }
+
+    /// Record `signature` as processed
+    pub(crate) fn record(&mut self, signature: Signature) -> Result<(), JitoBellError> {
+        if let Some(file) = self.file.as_mut() {
+            writeln!(file, "{signature}")?;
+        }
+        self.processed.insert(signature);
+
+        Ok(())
+    }
+}
+
+/// Parse one signature per line, ignoring blank lines and `#` comments
+fn read_signatures(content: &str) -> Result<Vec<Signature>, JitoBellError> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            Signature::from_str(line)
+                .map_err(|e| JitoBellError::Config(format!("Invalid signature {line}: {e}")))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::signature::Signature;
+
+    use super::{read_signatures, Checkpoint};
+
+    #[test]
+    fn test_read_signatures() {
+        let first = Signature::new_unique();
+        let second = Signature::new_unique();
+        let content = format!("# incident 42\n{first}\n\n  {second}  \n");
+
+        assert_eq!(read_signatures(&content).unwrap(), vec![first, second]);
+        assert!(read_signatures("not-a-signature").is_err());
+    }
+
+    #[test]
+    fn test_checkpoint_resume() {
+        let path =
+            std::env::temp_dir().join(format!("jito-bell-checkpoint-{}", Signature::new_unique()));
+        let signature = Signature::new_unique();
+
+        let mut checkpoint = Checkpoint::load(Some(&path)).unwrap();
+        assert_eq!(checkpoint.len(), 0);
+        checkpoint.record(signature).unwrap();
+        drop(checkpoint);
+
+        let checkpoint = Checkpoint::load(Some(&path)).unwrap();
+        assert!(checkpoint.contains(&signature));
+        assert_eq!(checkpoint.len(), 1);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
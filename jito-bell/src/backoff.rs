@@ -0,0 +1,94 @@
+use std::time::Duration;
+
+use rand::Rng;
+
+/// Exponential backoff with jitter
+///
+/// - The delay ceiling doubles on each attempt, capped at `max`
+/// - The returned delay is picked uniformly between half the ceiling and the ceiling
+#[derive(Debug)]
+pub struct Backoff {
+    /// Delay ceiling for the first attempt
+    initial: Duration,
+
+    /// Upper bound of the delay ceiling
+    max: Duration,
+
+    /// Number of attempts since the last reset
+    attempt: u32,
+}
+
+impl Backoff {
+    /// Initialize backoff
+    pub fn new(initial: Duration, max: Duration) -> Self {
+        Self {
+            initial,
+            max: max.max(initial),
+            attempt: 0,
+        }
+    }
+
+    /// Number of attempts since the last reset
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Reset attempts after a healthy connection
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+    }
+
+    /// Delay ceiling for the current attempt
+    fn ceiling(&self) -> Duration {
+        let factor = 1_u32.checked_shl(self.attempt.min(31)).unwrap_or(u32::MAX);
+        self.initial.saturating_mul(factor).min(self.max)
+    }
+
+    /// Compute the next delay and record the attempt
+    pub fn next_delay(&mut self) -> Duration {
+        let ceiling = self.ceiling();
+        self.attempt = self.attempt.saturating_add(1);
+
+        let ceiling_ms = ceiling.as_millis() as u64;
+        if ceiling_ms == 0 {
+            return Duration::ZERO;
+        }
+
+        let delay_ms = rand::thread_rng().gen_range(ceiling_ms / 2..=ceiling_ms);
+        Duration::from_millis(delay_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Backoff;
+
+    #[test]
+    fn test_backoff_grows_and_caps() {
+        let initial = Duration::from_millis(100);
+        let max = Duration::from_millis(1_000);
+        let mut backoff = Backoff::new(initial, max);
+
+        let expected_ceilings = [100, 200, 400, 800, 1_000, 1_000];
+        for ceiling in expected_ceilings {
+            let delay = backoff.next_delay().as_millis() as u64;
+            assert!(delay >= ceiling / 2 && delay <= ceiling);
+        }
+        assert_eq!(backoff.attempt(), expected_ceilings.len() as u32);
+    }
+
+    #[test]
+    fn test_backoff_reset() {
+        let mut backoff = Backoff::new(Duration::from_millis(100), Duration::from_secs(30));
+
+        for _ in 0..40 {
+            assert!(backoff.next_delay() <= Duration::from_secs(30));
+        }
+
+        backoff.reset();
+        assert_eq!(backoff.attempt(), 0);
+        assert!(backoff.next_delay() <= Duration::from_millis(100));
+    }
+}
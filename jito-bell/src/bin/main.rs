@@ -2,7 +2,11 @@ use std::{env, io::Write, process::Command};
 
 use clap::Parser;
 use jito_bell::{
-    cli_args::Args, multi_writer::MultiWriter, subscribe_option::SubscribeOption, JitoBellHandler,
+    backfill::BackfillOption,
+    cli_args::{Args, Commands},
+    multi_writer::MultiWriter,
+    subscribe_option::SubscribeOption,
+    JitoBellHandler,
 };
 use log::info;
 use solana_metrics::set_host_id;
@@ -71,8 +75,28 @@ async fn main() -> anyhow::Result<()> {
 
     info!("Jito Bell Config:\n{}", handler.config);
 
-    info!("Starting heartbeat...");
-    handler.heart_beat().await?;
+    match args.command {
+        Some(Commands::Backfill(backfill_args)) => {
+            info!("Starting backfill...");
+            handler
+                .backfill(BackfillOption::new(backfill_args)?)
+                .await?;
+        }
+        Some(Commands::Replay(replay_args)) => {
+            info!("Starting replay...");
+            handler
+                .replay(
+                    replay_args.file,
+                    replay_args.paced,
+                    replay_args.send_notifications,
+                )
+                .await?;
+        }
+        None => {
+            info!("Starting heartbeat...");
+            handler.heart_beat().await?;
+        }
+    }
 
     Ok(())
 }
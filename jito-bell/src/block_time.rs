@@ -0,0 +1,95 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use log::debug;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::clock::UnixTimestamp;
+
+/// Block time of recent slots
+///
+/// - Fed by `blocks_meta` updates and by transactions fetched over RPC
+/// - Misses are resolved with `getBlockTime` and cached
+/// - Evict the lowest slot once `capacity` is reached
+pub(crate) struct BlockTimeCache {
+    /// RPC Client
+    rpc_client: Arc<RpcClient>,
+
+    /// Maximum number of slots to remember
+    capacity: usize,
+
+    /// Block time per slot
+    times: Mutex<BTreeMap<u64, UnixTimestamp>>,
+}
+
+impl BlockTimeCache {
+    /// Initialize block time cache
+    pub(crate) fn new(rpc_client: Arc<RpcClient>, capacity: usize) -> Self {
+        Self {
+            rpc_client,
+            capacity: capacity.max(1),
+            times: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Record the block time of `slot`
+    pub(crate) fn insert(&self, slot: u64, block_time: UnixTimestamp) {
+        let Ok(mut times) = self.times.lock() else {
+            return;
+        };
+
+        times.insert(slot, block_time);
+        while times.len() > self.capacity {
+            times.pop_first();
+        }
+    }
+
+    /// Cached block time of `slot`
+    pub(crate) fn get(&self, slot: u64) -> Option<UnixTimestamp> {
+        self.times.lock().ok()?.get(&slot).copied()
+    }
+
+    /// Block time of `slot`, fetched over RPC on a cache miss
+    ///
+    /// - Recent slots may not be available at the client's commitment yet
+    pub(crate) async fn resolve(&self, slot: u64) -> Option<UnixTimestamp> {
+        if let Some(block_time) = self.get(slot) {
+            return Some(block_time);
+        }
+
+        match self.rpc_client.get_block_time(slot).await {
+            Ok(block_time) => {
+                self.insert(slot, block_time);
+                Some(block_time)
+            }
+            Err(e) => {
+                debug!("Failed to fetch block time of slot {slot}: {e}");
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+
+    use super::BlockTimeCache;
+
+    #[test]
+    fn test_block_time_cache_evicts_lowest_slot() {
+        let rpc_client = Arc::new(RpcClient::new("http://localhost:8899".to_string()));
+        let cache = BlockTimeCache::new(rpc_client, 2);
+
+        cache.insert(12, 1_700_000_012);
+        cache.insert(10, 1_700_000_010);
+        cache.insert(11, 1_700_000_011);
+
+        assert_eq!(cache.get(10), None);
+        assert_eq!(cache.get(11), Some(1_700_000_011));
+        assert_eq!(cache.get(12), Some(1_700_000_012));
+    }
+}
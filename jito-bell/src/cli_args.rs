@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use clap::{Parser, ValueEnum};
+use clap::{Parser, Subcommand, ValueEnum};
 use yellowstone_grpc_proto::geyser::CommitmentLevel;
 
 #[derive(Debug, Clone, Parser)]
@@ -13,6 +13,14 @@ pub struct Args {
     #[clap(long, env = "X_TOKEN")]
     pub x_token: Option<String>,
 
+    /// Additional Geyser endpoints subscribed concurrently with `endpoint`
+    #[clap(long, env, value_delimiter = ',')]
+    pub additional_endpoints: Vec<String>,
+
+    /// X-Tokens for `additional_endpoints`, in the same order (leave an entry empty for none)
+    #[clap(long, env, value_delimiter = ',')]
+    pub additional_x_tokens: Vec<String>,
+
     /// Commitment level: processed, confirmed or finalized
     #[clap(long, env)]
     pub commitment: Option<ArgsCommitment>,
@@ -41,6 +49,38 @@ pub struct Args {
     #[clap(long, env)]
     pub account_required: Vec<String>,
 
+    /// Initial delay before reconnecting to Geyser, in milliseconds
+    #[clap(long, env, default_value_t = 500)]
+    pub reconnect_initial_backoff_ms: u64,
+
+    /// Maximum delay between Geyser reconnect attempts, in milliseconds
+    #[clap(long, env, default_value_t = 30_000)]
+    pub reconnect_max_backoff_ms: u64,
+
+    /// Give up after this many consecutive failed reconnect attempts (retry forever if unset)
+    #[clap(long, env)]
+    pub max_reconnect_attempts: Option<u32>,
+
+    /// Transaction source: geyser or rpc (polls the RPC endpoint for configured programs)
+    #[clap(long, env, value_enum, default_value_t = ArgsTransactionSource::Geyser)]
+    pub transaction_source: ArgsTransactionSource,
+
+    /// Interval between RPC polls when `transaction_source` is rpc, in milliseconds
+    #[clap(long, env, default_value_t = 2_000)]
+    pub rpc_poll_interval_ms: u64,
+
+    /// Append every received update to this file, for later `replay`
+    #[clap(long, env)]
+    pub record: Option<PathBuf>,
+
+    /// Number of workers parsing transactions
+    #[clap(long, env, default_value_t = 4)]
+    pub parse_workers: usize,
+
+    /// Maximum notifications in flight per destination
+    #[clap(long, env, default_value_t = 2)]
+    pub delivery_concurrency: usize,
+
     /// Slack webhook URL for Jito Bell
     #[clap(long, env)]
     pub slack_webhook_url: Option<String>,
@@ -87,6 +127,60 @@ pub struct Args {
 
     #[clap(long, env = "CONFIG_FILE")]
     pub config_file: PathBuf,
+
+    /// Run a one-off command instead of streaming live transactions
+    #[clap(subcommand)]
+    pub command: Option<Commands>,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum Commands {
+    /// Replay historical transactions through the notification rules
+    Backfill(BackfillArgs),
+
+    /// Replay a file written with `--record`, without network access
+    Replay(ReplayArgs),
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct BackfillArgs {
+    /// First slot to backfill, inclusive
+    #[clap(long, requires = "end_slot", conflicts_with = "signatures_file")]
+    pub start_slot: Option<u64>,
+
+    /// Last slot to backfill, inclusive
+    #[clap(long, requires = "start_slot")]
+    pub end_slot: Option<u64>,
+
+    /// File with one transaction signature per line
+    #[clap(long, required_unless_present = "start_slot")]
+    pub signatures_file: Option<PathBuf>,
+
+    /// Send notifications (by default they are only logged)
+    #[clap(long)]
+    pub send_notifications: bool,
+
+    /// File recording processed signatures, used to resume an interrupted backfill
+    #[clap(long)]
+    pub checkpoint_file: Option<PathBuf>,
+
+    /// Maximum RPC requests per second
+    #[clap(long, default_value_t = 10)]
+    pub rpc_requests_per_second: u32,
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct ReplayArgs {
+    /// Recording file
+    pub file: PathBuf,
+
+    /// Replay at the recorded pace instead of full speed
+    #[clap(long)]
+    pub paced: bool,
+
+    /// Send notifications (by default they are only logged)
+    #[clap(long)]
+    pub send_notifications: bool,
 }
 
 #[derive(Debug, Clone, Copy, Default, ValueEnum)]
@@ -106,3 +200,10 @@ impl From<ArgsCommitment> for CommitmentLevel {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ArgsTransactionSource {
+    #[default]
+    Geyser,
+    Rpc,
+}
@@ -2,7 +2,12 @@ use std::collections::HashMap;
 
 use serde::Deserialize;
 
-use crate::program::{EventConfig, Program, ProgramName};
+use crate::{
+    account_config::WatchedAccount,
+    program::{EventConfig, Program, ProgramName},
+    ticket_config::WithdrawalTicketConfig,
+    vault_update_config::VaultUpdateConfig,
+};
 
 #[derive(Deserialize)]
 pub struct JitoBellConfig {
@@ -14,6 +19,22 @@ pub struct JitoBellConfig {
 
     /// Message Templates
     pub message_templates: HashMap<String, String>,
+
+    /// Accounts subscribed to, with rules on their field changes
+    #[serde(default)]
+    pub accounts: Vec<WatchedAccount>,
+
+    /// Labels shown in notifications next to addresses, by address
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+
+    /// Withdrawal ticket lifecycle alerts, by vault
+    #[serde(default)]
+    pub withdrawal_tickets: HashMap<String, WithdrawalTicketConfig>,
+
+    /// Vault update cycle alerts, by vault
+    #[serde(default)]
+    pub vault_updates: HashMap<String, VaultUpdateConfig>,
 }
 
 impl JitoBellConfig {
@@ -133,6 +154,21 @@ impl std::fmt::Display for JitoBellConfig {
                 }
             }
         }
+
+        if !self.accounts.is_empty() {
+            writeln!(f, "Accounts:")?;
+            for account in self.accounts.iter() {
+                writeln!(
+                    f,
+                    "  {} ({}): {}",
+                    account.name, account.account_type, account.address
+                )?;
+                for rule in account.rules.iter() {
+                    writeln!(f, "    Rule: {} {}", rule.field, rule.condition)?;
+                }
+            }
+        }
+
         Ok(())
     }
 }
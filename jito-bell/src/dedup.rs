@@ -0,0 +1,90 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    time::{Duration, Instant},
+};
+
+use solana_sdk::signature::Signature;
+
+/// Outcome of observing a transaction signature
+#[derive(Debug, PartialEq)]
+pub(crate) enum Delivery {
+    /// First time the signature is seen
+    First,
+
+    /// Already seen, delivered this long after the first delivery
+    Duplicate(Duration),
+}
+
+/// Bounded set of recently seen transaction signatures
+///
+/// - Evict the oldest signature once `capacity` is reached
+#[derive(Debug)]
+pub(crate) struct SignatureDeduplicator {
+    /// Maximum number of signatures to remember
+    capacity: usize,
+
+    /// First delivery time per signature
+    seen: HashMap<Signature, Instant>,
+
+    /// Signatures in insertion order
+    order: VecDeque<Signature>,
+}
+
+impl SignatureDeduplicator {
+    /// Initialize deduplicator
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            seen: HashMap::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Record a signature and report whether it was already delivered
+    pub(crate) fn observe(&mut self, signature: Signature) -> Delivery {
+        if let Some(first_seen) = self.seen.get(&signature) {
+            return Delivery::Duplicate(first_seen.elapsed());
+        }
+
+        if self.order.len() >= self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+
+        self.seen.insert(signature, Instant::now());
+        self.order.push_back(signature);
+
+        Delivery::First
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::signature::Signature;
+
+    use super::{Delivery, SignatureDeduplicator};
+
+    #[test]
+    fn test_observe_duplicate() {
+        let mut dedup = SignatureDeduplicator::new(10);
+        let signature = Signature::new_unique();
+
+        assert_eq!(dedup.observe(signature), Delivery::First);
+        assert!(matches!(dedup.observe(signature), Delivery::Duplicate(_)));
+        assert_eq!(dedup.observe(Signature::new_unique()), Delivery::First);
+    }
+
+    #[test]
+    fn test_observe_evicts_oldest() {
+        let mut dedup = SignatureDeduplicator::new(2);
+        let first = Signature::new_unique();
+
+        assert_eq!(dedup.observe(first), Delivery::First);
+        assert_eq!(dedup.observe(Signature::new_unique()), Delivery::First);
+        assert_eq!(dedup.observe(Signature::new_unique()), Delivery::First);
+
+        // `first` was evicted, so it is delivered again
+        assert_eq!(dedup.observe(first), Delivery::First);
+    }
+}
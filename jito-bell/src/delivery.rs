@@ -0,0 +1,1001 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicI64, AtomicU64, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+
+use chrono::{TimeZone, Utc};
+use log::{debug, error};
+use solana_metrics::datapoint_info;
+use solana_sdk::clock::UnixTimestamp;
+use tokio::sync::{mpsc, oneshot, Semaphore};
+use twitterust::{TwitterClient, TwitterCredentials};
+
+use crate::{
+    block_time::BlockTimeCache, config::JitoBellConfig, epoch::EpochProgress, error::JitoBellError,
+    metrics::NotificationMetrics, notification_info::Destination,
+    subscribe_option::SubscribeOption,
+};
+
+/// Every destination a notification can be delivered to
+const DESTINATIONS: [Destination; 6] = [
+    Destination::Telegram,
+    Destination::Discord,
+    Destination::Twitter,
+    Destination::JitoBellSlack,
+    Destination::StakePoolAlertsSlack,
+    Destination::StakenetEventAlertsSlack,
+];
+
+/// Notification waiting to be delivered to one destination
+#[derive(Debug, Clone)]
+pub(crate) struct Notification {
+    /// Destination
+    pub(crate) destination: Destination,
+
+    /// Description
+    pub(crate) description: String,
+
+    /// Amount
+    pub(crate) amount: Option<f64>,
+
+    /// Unit of `amount`
+    pub(crate) unit: Option<String>,
+
+    /// Transaction signature, `None` for alerts not tied to a transaction
+    pub(crate) transaction_signature: Option<String>,
+
+    /// Slot of the transaction or account update
+    pub(crate) slot: u64,
+
+    /// Block time of `slot`, resolved at delivery if unknown when queued
+    pub(crate) block_time: Option<UnixTimestamp>,
+
+    /// Epoch progress when the notification was queued
+    pub(crate) epoch_progress: Option<EpochProgress>,
+
+    /// Time the notification was queued
+    pub(crate) queued_at: Instant,
+}
+
+impl Notification {
+    /// Amount with its unit, `None` for alerts without an amount
+    fn amount_text(&self) -> Option<String> {
+        self.amount.map(|amount| match self.unit.as_deref() {
+            Some(unit) => format!("{amount:.2} {unit}"),
+            None => format!("{amount:.2}"),
+        })
+    }
+
+    /// Transaction signature for logs, or the slot without a transaction
+    fn subject(&self) -> String {
+        match &self.transaction_signature {
+            Some(signature) => signature.clone(),
+            None => format!("slot {}", self.slot),
+        }
+    }
+}
+
+/// Message in a destination's delivery queue
+enum DeliveryMessage {
+    /// Deliver a notification
+    Notify(Box<Notification>),
+
+    /// Reply once every earlier notification has been delivered
+    Flush(oneshot::Sender<()>),
+}
+
+/// Delivery metrics of one destination
+#[derive(Debug, Default)]
+struct DestinationMetrics {
+    /// Notifications waiting in the queue
+    queue_depth: AtomicI64,
+
+    /// Notifications delivered since the last report
+    delivered: AtomicU64,
+
+    /// Notifications that failed since the last report
+    failed: AtomicU64,
+
+    /// Notifications dropped on a full queue since the last report
+    dropped: AtomicU64,
+
+    /// Total time from queueing to delivery since the last report, in milliseconds
+    latency_ms: AtomicU64,
+}
+
+/// Destination's queue and metrics
+struct DestinationQueue {
+    /// Queue drained by the destination's dispatcher
+    tx: mpsc::Sender<DeliveryMessage>,
+
+    /// Delivery metrics
+    metrics: Arc<DestinationMetrics>,
+}
+
+/// Bounded delivery queue per destination, so a slow platform never holds up the stream
+///
+/// - Each destination is delivered by its own dispatcher with at most `concurrency`
+///   requests in flight
+/// - Only destinations with credentials get a queue
+pub(crate) struct DeliveryQueue {
+    /// Queue per configured destination
+    queues: HashMap<Destination, DestinationQueue>,
+}
+
+impl DeliveryQueue {
+    /// Spawn a dispatcher for every configured destination
+    pub(crate) fn spawn(
+        notifier: Notifier,
+        capacity: usize,
+        concurrency: usize,
+        notification_metrics: Arc<NotificationMetrics>,
+    ) -> Self {
+        let mut queues = HashMap::new();
+
+        for destination in DESTINATIONS {
+            if !notifier.is_configured(destination) {
+                continue;
+            }
+
+            let (tx, rx) = mpsc::channel(capacity.max(1));
+            let metrics = Arc::new(DestinationMetrics::default());
+            tokio::spawn(dispatch(
+                notifier.clone(),
+                rx,
+                concurrency.max(1),
+                metrics.clone(),
+                notification_metrics.clone(),
+            ));
+            queues.insert(destination, DestinationQueue { tx, metrics });
+        }
+
+        Self { queues }
+    }
+
+    /// Queue a notification without waiting
+    ///
+    /// - Skip destinations without credentials
+    /// - Drop the notification if the destination's queue is full
+    pub(crate) fn enqueue(&self, notification: Notification) {
+        let destination = notification.destination;
+        let Some(queue) = self.queues.get(&destination) else {
+            debug!("Skipping {destination} - not configured");
+            return;
+        };
+        let subject = notification.subject();
+        match queue
+            .tx
+            .try_send(DeliveryMessage::Notify(Box::new(notification)))
+        {
+            Ok(()) => {
+                queue.metrics.queue_depth.fetch_add(1, Ordering::Relaxed);
+            }
+            Err(e) => {
+                queue.metrics.dropped.fetch_add(1, Ordering::Relaxed);
+                error!(
+                    "Dropping {destination} notification for {subject}, delivery queue unavailable: {e}"
+                );
+            }
+        }
+    }
+
+    /// Wait until every queued notification has been delivered
+    pub(crate) async fn flush(&self) {
+        for queue in self.queues.values() {
+            let (done_tx, done_rx) = oneshot::channel();
+            if queue.tx.send(DeliveryMessage::Flush(done_tx)).await.is_ok() {
+                let _ = done_rx.await;
+            }
+        }
+    }
+
+    /// Export queue depth and delivery latency per destination
+    pub(crate) fn report(&self) {
+        for (destination, queue) in self.queues.iter() {
+            let metrics = &queue.metrics;
+            let delivered = metrics.delivered.swap(0, Ordering::Relaxed);
+            let failed = metrics.failed.swap(0, Ordering::Relaxed);
+            let latency_ms = metrics.latency_ms.swap(0, Ordering::Relaxed);
+
+            datapoint_info!(
+                "jito-bell-delivery",
+                ("destination", destination.to_string(), String),
+                (
+                    "queue_depth",
+                    metrics.queue_depth.load(Ordering::Relaxed),
+                    i64
+                ),
+                ("delivered", delivered, i64),
+                ("failed", failed, i64),
+                ("dropped", metrics.dropped.swap(0, Ordering::Relaxed), i64),
+                (
+                    "latency_ms",
+                    latency_ms.checked_div(delivered + failed).unwrap_or(0),
+                    i64
+                ),
+            );
+        }
+    }
+}
+
+/// Deliver a destination's notifications until its queue is closed
+async fn dispatch(
+    notifier: Notifier,
+    mut rx: mpsc::Receiver<DeliveryMessage>,
+    concurrency: usize,
+    metrics: Arc<DestinationMetrics>,
+    notification_metrics: Arc<NotificationMetrics>,
+) {
+    let in_flight = Arc::new(Semaphore::new(concurrency));
+
+    while let Some(message) = rx.recv().await {
+        match message {
+            DeliveryMessage::Notify(notification) => {
+                metrics.queue_depth.fetch_sub(1, Ordering::Relaxed);
+                let Ok(permit) = in_flight.clone().acquire_owned().await else {
+                    return;
+                };
+
+                let notifier = notifier.clone();
+                let metrics = metrics.clone();
+                let notification_metrics = notification_metrics.clone();
+                tokio::spawn(async move {
+                    let result = notifier.send(&notification).await;
+                    metrics.latency_ms.fetch_add(
+                        notification.queued_at.elapsed().as_millis() as u64,
+                        Ordering::Relaxed,
+                    );
+
+                    match result {
+                        Ok(()) => {
+                            metrics.delivered.fetch_add(1, Ordering::Relaxed);
+                            notification_metrics.increment_success();
+                        }
+                        Err(e) => {
+                            metrics.failed.fetch_add(1, Ordering::Relaxed);
+                            notification_metrics.increment_fail();
+                            error!(
+                                "Failed to deliver {} notification for {}: {e}",
+                                notification.destination,
+                                notification.subject()
+                            );
+                        }
+                    }
+                    drop(permit);
+                });
+            }
+            DeliveryMessage::Flush(done) => {
+                if let Ok(permits) = in_flight.acquire_many(concurrency as u32).await {
+                    drop(permits);
+                }
+                let _ = done.send(());
+            }
+        }
+    }
+}
+
+/// Sends notifications to the configured platforms
+#[derive(Clone)]
+pub(crate) struct Notifier {
+    /// HTTP client shared by every webhook
+    client: reqwest::Client,
+
+    /// Platform credentials
+    subscribe_option: Arc<SubscribeOption>,
+
+    /// Block explorer url
+    explorer_url: String,
+
+    /// Message Templates
+    message_templates: HashMap<String, String>,
+
+    /// Block time of recent slots
+    block_times: Arc<BlockTimeCache>,
+}
+
+impl Notifier {
+    /// Initialize notifier
+    pub(crate) fn new(
+        subscribe_option: Arc<SubscribeOption>,
+        config: &JitoBellConfig,
+        block_times: Arc<BlockTimeCache>,
+    ) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            subscribe_option,
+            explorer_url: config.explorer_url.clone(),
+            message_templates: config.message_templates.clone(),
+            block_times,
+        }
+    }
+
+    /// Whether credentials for `destination` are set
+    fn is_configured(&self, destination: Destination) -> bool {
+        let option = &self.subscribe_option;
+        match destination {
+            Destination::Telegram => {
+                option.telegram_bot_token.is_some() && option.telegram_chat_id.is_some()
+            }
+            Destination::Discord => option.discord_webhook_url.is_some(),
+            Destination::Twitter => {
+                option.twitter_api_key.is_some()
+                    && option.twitter_api_secret.is_some()
+                    && option.twitter_access_token.is_some()
+                    && option.twitter_access_token_secret.is_some()
+            }
+            Destination::JitoBellSlack => option.jito_bell_slack_webhook_url.is_some(),
+            Destination::StakePoolAlertsSlack => {
+                option.stake_pool_alerts_slack_webhook_url.is_some()
+            }
+            Destination::StakenetEventAlertsSlack => {
+                option.stakenet_event_alerts_slack_webhook_url.is_some()
+            }
+        }
+    }
+
+    /// Send a notification to its destination
+    async fn send(&self, notification: &Notification) -> Result<(), JitoBellError> {
+        let description = notification.description.as_str();
+        let sig = notification.transaction_signature.as_deref();
+        let amount = notification.amount_text();
+        let amount = amount.as_deref();
+        let slot = notification.slot;
+        let block_time = match notification.block_time {
+            Some(block_time) => Some(block_time),
+            None => self.block_times.resolve(slot).await,
+        };
+
+        match notification.destination {
+            Destination::Telegram => self.send_telegram_message(notification, block_time).await,
+            Destination::Discord => {
+                self.send_discord_message(description, amount, sig, slot, block_time)
+                    .await
+            }
+            Destination::Twitter => self.send_twitter_message(description, amount, sig).await,
+            Destination::JitoBellSlack => {
+                self.send_slack_message_to_jito_bell(description, amount, sig, slot, block_time)
+                    .await
+            }
+            Destination::StakePoolAlertsSlack => {
+                self.send_slack_message(
+                    self.subscribe_option
+                        .stake_pool_alerts_slack_webhook_url
+                        .as_deref(),
+                    description,
+                    sig,
+                    slot,
+                    block_time,
+                )
+                .await
+            }
+            Destination::StakenetEventAlertsSlack => {
+                self.send_slack_message(
+                    self.subscribe_option
+                        .stakenet_event_alerts_slack_webhook_url
+                        .as_deref(),
+                    description,
+                    sig,
+                    slot,
+                    block_time,
+                )
+                .await
+            }
+        }
+    }
+
+    /// POST a JSON payload to a webhook
+    async fn post_json(
+        &self,
+        platform: &str,
+        webhook_url: &str,
+        payload: &serde_json::Value,
+    ) -> Result<(), JitoBellError> {
+        let response = self
+            .client
+            .post(webhook_url)
+            .header("Content-Type", "application/json")
+            .json(payload)
+            .send()
+            .await
+            .map_err(|e| {
+                JitoBellError::Notification(format!("Error sending {platform} message: {e}"))
+            })?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(JitoBellError::Notification(format!(
+                "Failed to send {platform} message: Status {}",
+                response.status()
+            )))
+        }
+    }
+
+    /// Send message to Telegram
+    async fn send_telegram_message(
+        &self,
+        notification: &Notification,
+        block_time: Option<UnixTimestamp>,
+    ) -> Result<(), JitoBellError> {
+        let (Some(bot_token), Some(chat_id)) = (
+            &self.subscribe_option.telegram_bot_token,
+            &self.subscribe_option.telegram_chat_id,
+        ) else {
+            return Ok(());
+        };
+
+        let template = self
+            .message_templates
+            .get("telegram")
+            .or_else(|| self.message_templates.get("default"))
+            .map_or("{{description}}", String::as_str);
+        let message = render_template(template, notification, block_time);
+
+        let url = format!("https://api.telegram.org/bot{}/sendMessage", bot_token);
+
+        let response = self
+            .client
+            .post(&url)
+            .form(&[("chat_id", chat_id), ("text", &message)])
+            .send()
+            .await
+            .map_err(|e| {
+                JitoBellError::Notification(format!("Failed to send Telegram message: {}", e))
+            })?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(JitoBellError::Notification(format!(
+                "Failed to send Telegram message: {}",
+                response.status(),
+            )))
+        }
+    }
+
+    /// Send message to Discord
+    async fn send_discord_message(
+        &self,
+        description: &str,
+        amount: Option<&str>,
+        sig: Option<&str>,
+        slot: u64,
+        block_time: Option<UnixTimestamp>,
+    ) -> Result<(), JitoBellError> {
+        let Some(webhook_url) = &self.subscribe_option.discord_webhook_url else {
+            return Ok(());
+        };
+
+        let mut fields: Vec<serde_json::Value> = amount
+            .map(|amount| {
+                serde_json::json!({
+                    "name": "Amount",
+                    "value": amount,
+                    "inline": true
+                })
+            })
+            .into_iter()
+            .collect();
+        if let Some(sig) = sig {
+            fields.push(serde_json::json!({
+                "name": "Transaction",
+                "value": format!("[View on Explorer]({}/tx/{})", self.explorer_url, sig),
+                "inline": true
+            }));
+        }
+        fields.push(serde_json::json!({
+            "name": "Slot",
+            "value": slot.to_string(),
+            "inline": true
+        }));
+
+        let mut payload = serde_json::json!({
+            "embeds": [{
+                "title": "New Transaction Detected",
+                "description": description,
+                "color": 3447003, // Blue color
+                "fields": fields
+            }]
+        });
+
+        // Discord renders the embed timestamp in the reader's timezone
+        if let Some(timestamp) = block_time.and_then(format_block_time) {
+            payload["embeds"][0]["timestamp"] = serde_json::Value::String(timestamp);
+        }
+
+        self.post_json("Discord", webhook_url, &payload).await
+    }
+
+    /// Send message to Slack to Jito Bell Channel
+    async fn send_slack_message_to_jito_bell(
+        &self,
+        description: &str,
+        amount: Option<&str>,
+        sig: Option<&str>,
+        slot: u64,
+        block_time: Option<UnixTimestamp>,
+    ) -> Result<(), JitoBellError> {
+        let Some(webhook_url) = &self.subscribe_option.jito_bell_slack_webhook_url else {
+            return Ok(());
+        };
+
+        let mut fields: Vec<serde_json::Value> = amount
+            .map(|amount| {
+                serde_json::json!({
+                    "type": "mrkdwn",
+                    "text": format!("*Amount:* {amount}")
+                })
+            })
+            .into_iter()
+            .collect();
+        fields.extend(self.slack_transaction_field(sig));
+        fields.push(serde_json::json!({
+            "type": "mrkdwn",
+            "text": format!("*Slot:* {}", slot_text(slot, block_time))
+        }));
+
+        // Build a Slack message with blocks for better formatting
+        let payload = serde_json::json!({
+            "blocks": [
+                {
+                    "type": "header",
+                    "text": {
+                        "type": "plain_text",
+                        "text": "New Transaction Detected"
+                    }
+                },
+                {
+                    "type": "section",
+                    "text": {
+                        "type": "mrkdwn",
+                        "text": format!("*Description:* {}", description)
+                    }
+                },
+                {
+                    "type": "section",
+                    "fields": fields
+                }
+            ]
+        });
+
+        self.post_json("Slack", webhook_url, &payload).await
+    }
+
+    /// Send message to a Slack alerts channel, without an amount
+    async fn send_slack_message(
+        &self,
+        webhook_url: Option<&str>,
+        description: &str,
+        sig: Option<&str>,
+        slot: u64,
+        block_time: Option<UnixTimestamp>,
+    ) -> Result<(), JitoBellError> {
+        let Some(webhook_url) = webhook_url else {
+            return Ok(());
+        };
+
+        let mut fields: Vec<serde_json::Value> =
+            self.slack_transaction_field(sig).into_iter().collect();
+        fields.push(serde_json::json!({
+            "type": "mrkdwn",
+            "text": format!("*Slot:* {}", slot_text(slot, block_time))
+        }));
+
+        // Build a Slack message with blocks for better formatting
+        let payload = serde_json::json!({
+            "blocks": [
+                {
+                    "type": "header",
+                    "text": {
+                        "type": "plain_text",
+                        "text": "New Transaction Detected"
+                    }
+                },
+                {
+                    "type": "section",
+                    "text": {
+                        "type": "mrkdwn",
+                        "text": format!("*Description:* {}", description)
+                    }
+                },
+                {
+                    "type": "section",
+                    "fields": fields
+                }
+            ]
+        });
+
+        self.post_json("Slack", webhook_url, &payload).await
+    }
+
+    /// Slack field linking the transaction, if any
+    fn slack_transaction_field(&self, sig: Option<&str>) -> Option<serde_json::Value> {
+        sig.map(|sig| {
+            serde_json::json!({
+                "type": "mrkdwn",
+                "text": format!("*Transaction:* <{}/tx/{}|View on Explorer>", self.explorer_url, sig)
+            })
+        })
+    }
+
+    /// Send message to Twitter
+    async fn send_twitter_message(
+        &self,
+        description: &str,
+        amount: Option<&str>,
+        sig: Option<&str>,
+    ) -> Result<(), JitoBellError> {
+        let (api_key, api_secret, access_token, access_token_secret) = match (
+            &self.subscribe_option.twitter_api_key,
+            &self.subscribe_option.twitter_api_secret,
+            &self.subscribe_option.twitter_access_token,
+            &self.subscribe_option.twitter_access_token_secret,
+        ) {
+            (Some(key), Some(secret), Some(token), Some(token_secret)) => {
+                (key, secret, token, token_secret)
+            }
+            _ => return Ok(()),
+        };
+
+        let credentials =
+            TwitterCredentials::new(api_key, api_secret, access_token, access_token_secret);
+
+        let client = TwitterClient::new(credentials);
+
+        let tweet_text = tweet_text(description, amount, &self.explorer_url, sig);
+
+        client.tweet(tweet_text).await.map(|_| ()).map_err(|e| {
+            JitoBellError::Notification(format!("Error sending Twitter message: {:?}", e))
+        })
+    }
+}
+
+/// Tweet of a notification, shortened to fit Twitter's 280 character limit
+///
+/// - The amount and transaction lines are left out without an amount or signature
+fn tweet_text(
+    description: &str,
+    amount: Option<&str>,
+    explorer_url: &str,
+    sig: Option<&str>,
+) -> String {
+    let amount_line = amount
+        .map(|amount| format!("💰 Amount: {amount}\n"))
+        .unwrap_or_default();
+    let transaction = sig
+        .map(|sig| format!("🔗 Transaction: {explorer_url}/tx/{sig}\n"))
+        .unwrap_or_default();
+    let tweet_text = format!("Jito Bell\n\n🚨 {description}\n\n{amount_line}{transaction}\n");
+    if tweet_text.len() <= 280 {
+        return tweet_text;
+    }
+
+    // Create a shorter version, with a truncated hash
+    let amount_line = amount
+        .map(|amount| format!("💰 {amount}\n"))
+        .unwrap_or_default();
+    let transaction = sig
+        .map(|sig| format!("🔗 {explorer_url}/tx/{}\n", sig.get(..8).unwrap_or(sig)))
+        .unwrap_or_default();
+    format!("Jito Bell\n\n🚨 {description}\n{amount_line}{transaction}")
+}
+
+/// Fill a message template with a notification's placeholders
+///
+/// - `{{timestamp}}` is the block time in RFC 3339, `{{block_time}}` in Unix seconds
+/// - Placeholders without a value, such as an unresolved block time, become empty
+/// - Without a transaction or an amount, the line, or ` - ` separated part of a line, holding
+///   `{{tx_hash}}` or `{{amount}}` / `{{currency_unit}}` is left out
+fn render_template(
+    template: &str,
+    notification: &Notification,
+    block_time: Option<UnixTimestamp>,
+) -> String {
+    let epoch_progress = notification.epoch_progress;
+    let mut missing = Vec::new();
+    if notification.transaction_signature.is_none() {
+        missing.push("{{tx_hash}}");
+    }
+    if notification.amount.is_none() {
+        missing.extend(["{{amount}}", "{{currency_unit}}"]);
+    }
+    let template = without_placeholders(template, &missing);
+
+    template
+        .replace("{{description}}", &notification.description)
+        .replace(
+            "{{amount}}",
+            &format!("{:.2}", notification.amount.unwrap_or_default()),
+        )
+        .replace(
+            "{{currency_unit}}",
+            notification.unit.as_deref().unwrap_or_default(),
+        )
+        .replace(
+            "{{tx_hash}}",
+            notification
+                .transaction_signature
+                .as_deref()
+                .unwrap_or_default(),
+        )
+        .replace("{{slot}}", &notification.slot.to_string())
+        .replace(
+            "{{block_time}}",
+            &block_time.map_or(String::new(), |t| t.to_string()),
+        )
+        .replace(
+            "{{timestamp}}",
+            &block_time.and_then(format_block_time).unwrap_or_default(),
+        )
+        .replace(
+            "{{epoch}}",
+            &epoch_progress.map_or(String::new(), |p| p.epoch.to_string()),
+        )
+        .replace(
+            "{{epoch_progress}}",
+            &epoch_progress.map_or(String::new(), |p| p.to_string()),
+        )
+}
+
+/// `template` without the parts referring to one of `placeholders`
+fn without_placeholders(template: &str, placeholders: &[&str]) -> String {
+    let refers = |text: &str| {
+        placeholders
+            .iter()
+            .any(|placeholder| text.contains(placeholder))
+    };
+    if placeholders.is_empty() {
+        return template.to_string();
+    }
+
+    template
+        .lines()
+        .filter_map(|line| {
+            if !refers(line) {
+                return Some(line.to_string());
+            }
+            let parts: Vec<&str> = line.split(" - ").filter(|part| !refers(part)).collect();
+            (!parts.is_empty()).then(|| parts.join(" - "))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Block time in RFC 3339, UTC
+fn format_block_time(block_time: UnixTimestamp) -> Option<String> {
+    Utc.timestamp_opt(block_time, 0)
+        .single()
+        .map(|time| time.to_rfc3339())
+}
+
+/// Slot, followed by its block time when known
+fn slot_text(slot: u64, block_time: Option<UnixTimestamp>) -> String {
+    match block_time.and_then(format_block_time) {
+        Some(timestamp) => format!("{slot} ({timestamp})"),
+        None => slot.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc, time::Instant};
+
+    use tokio::sync::mpsc;
+
+    use super::{
+        render_template, tweet_text, DeliveryMessage, DeliveryQueue, DestinationQueue, Notification,
+    };
+    use crate::{epoch::EpochProgress, notification_info::Destination};
+
+    fn notification() -> Notification {
+        Notification {
+            destination: Destination::Telegram,
+            description: "Large deposit".to_string(),
+            amount: Some(1234.5),
+            unit: Some("SOL".to_string()),
+            transaction_signature: Some("5sig".to_string()),
+            slot: 302_400_123,
+            block_time: None,
+            epoch_progress: Some(EpochProgress {
+                epoch: 700,
+                slot_index: 123,
+                slots_in_epoch: 432_000,
+            }),
+            queued_at: Instant::now(),
+        }
+    }
+
+    #[test]
+    fn test_render_template() {
+        let template = "{{description}}: {{amount}} {{currency_unit}} in slot {{slot}} at {{timestamp}} ({{block_time}}), epoch {{epoch}} - {{tx_hash}}";
+
+        assert_eq!(
+            render_template(template, &notification(), Some(1_700_000_000)),
+            "Large deposit: 1234.50 SOL in slot 302400123 at 2023-11-14T22:13:20+00:00 (1700000000), epoch 700 - 5sig"
+        );
+        assert_eq!(
+            render_template(
+                "{{slot}} {{timestamp}}|{{block_time}}",
+                &notification(),
+                None
+            ),
+            "302400123 |"
+        );
+    }
+
+    #[test]
+    fn test_render_template_without_transaction() {
+        let notification = Notification {
+            transaction_signature: None,
+            ..notification()
+        };
+
+        assert_eq!(
+            render_template(
+                "{{description}} - Amount: {{amount}} {{currency_unit}} - Tx: https://explorer.solana.com/tx/{{tx_hash}}",
+                &notification,
+                None
+            ),
+            "Large deposit - Amount: 1234.50 SOL"
+        );
+        assert_eq!(
+            render_template(
+                "{{description}}\nTx: {{tx_hash}}\nSlot {{slot}}",
+                &notification,
+                None
+            ),
+            "Large deposit\nSlot 302400123"
+        );
+    }
+
+    #[test]
+    fn test_render_governance_change_without_amount() {
+        let notification = Notification {
+            description: "JitoSOL manager changed\n\nStake pool: <pool>\n`manager`: <old> → <new>"
+                .to_string(),
+            amount: None,
+            unit: None,
+            ..notification()
+        };
+
+        assert_eq!(
+            render_template(
+                "{{description}} - Amount: {{amount}} {{currency_unit}} - Tx: https://explorer.solana.com/tx/{{tx_hash}}",
+                &notification,
+                None
+            ),
+            "JitoSOL manager changed\n\nStake pool: <pool>\n`manager`: <old> → <new> - Tx: https://explorer.solana.com/tx/5sig"
+        );
+    }
+
+    #[test]
+    fn test_render_template_without_amount() {
+        let notification = Notification {
+            amount: None,
+            unit: None,
+            ..notification()
+        };
+
+        assert_eq!(
+            render_template(
+                "{{description}} - Amount: {{amount}} {{currency_unit}} - Tx: https://explorer.solana.com/tx/{{tx_hash}}",
+                &notification,
+                None
+            ),
+            "Large deposit - Tx: https://explorer.solana.com/tx/5sig"
+        );
+    }
+
+    #[test]
+    fn test_long_tweet_without_transaction() {
+        let description = "Vault is stale ".repeat(20);
+        let tweet = tweet_text(&description, None, "https://explorer.solana.com", None);
+
+        assert!(!tweet.contains("/tx/"));
+        assert!(!tweet.contains("💰"));
+        assert!(tweet.starts_with("Jito Bell\n\n🚨 Vault is stale"));
+    }
+
+    #[test]
+    fn test_enqueue_without_amount() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let queue = DeliveryQueue {
+            queues: HashMap::from([(
+                Destination::Telegram,
+                DestinationQueue {
+                    tx,
+                    metrics: Arc::default(),
+                },
+            )]),
+        };
+
+        queue.enqueue(Notification {
+            amount: None,
+            unit: None,
+            transaction_signature: None,
+            ..notification()
+        });
+
+        match rx.try_recv() {
+            Ok(DeliveryMessage::Notify(notification)) => {
+                assert_eq!(notification.description, "Large deposit");
+                assert!(notification.amount.is_none());
+            }
+            _ => panic!("notification without an amount was not queued"),
+        }
+    }
+
+    /// Alerts without an amount reach every destination they are routed to
+    #[test]
+    fn test_enqueue_alerts_without_amount() {
+        let alerts = [
+            // Vault admin and program config changes
+            (
+                Destination::JitoBellSlack,
+                "Vault admin changed\n\n`admin`: <old> → <new>",
+            ),
+            (
+                Destination::Telegram,
+                "Vault program fee changed\n\n`program_fee_bps`: 10 → 20",
+            ),
+            // NCN and slasher ticket lifecycle events
+            (
+                Destination::JitoBellSlack,
+                "Vault slasher cooldown\n\nVault X started cooling down slasher Z of NCN Y\nAdmin: <admin>",
+            ),
+            (
+                Destination::Telegram,
+                "Vault NCN cooldown\n\nVault X started cooling down from NCN Y\nAdmin: <admin>",
+            ),
+            // Token 2022 transfer fee withdrawals and changes
+            (
+                Destination::JitoBellSlack,
+                "VRT transfer fees withdrawn\n\nMint: <mint>\nDestination: <account>",
+            ),
+            (
+                Destination::Telegram,
+                "VRT transfer fee changed\n\nMint: <mint>\nTransfer fee: 10 bps\nMaximum fee: 1000",
+            ),
+            // Withdrawal ticket owner changes
+            (
+                Destination::JitoBellSlack,
+                "Withdrawal ticket owner changed\n\nVault: <vault>\nTicket: <ticket>\nOwner: <old> → <new>",
+            ),
+            // Stake pool governance changes
+            (
+                Destination::Telegram,
+                "JitoSOL manager changed\n\nStake pool: <pool>\n`manager`: <old> → <new>",
+            ),
+        ];
+
+        for (destination, description) in alerts {
+            let (tx, mut rx) = mpsc::channel(1);
+            let queue = DeliveryQueue {
+                queues: HashMap::from([(
+                    destination,
+                    DestinationQueue {
+                        tx,
+                        metrics: Arc::default(),
+                    },
+                )]),
+            };
+
+            queue.enqueue(Notification {
+                destination,
+                description: description.to_string(),
+                amount: None,
+                unit: None,
+                ..notification()
+            });
+
+            assert!(
+                matches!(rx.try_recv(), Ok(DeliveryMessage::Notify(_))),
+                "{description} was not queued for {destination}"
+            );
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use solana_sdk::epoch_schedule::EpochSchedule;
+
+/// Position of a slot within its epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EpochProgress {
+    /// Epoch
+    pub epoch: u64,
+
+    /// Index of the slot within the epoch
+    pub slot_index: u64,
+
+    /// Number of slots in the epoch
+    pub slots_in_epoch: u64,
+}
+
+impl EpochProgress {
+    /// Locate `slot` in `epoch_schedule`
+    ///
+    /// - Accounts for warmup epochs and the first normal slot
+    pub fn new(epoch_schedule: &EpochSchedule, slot: u64) -> Self {
+        let (epoch, slot_index) = epoch_schedule.get_epoch_and_slot_index(slot);
+
+        Self {
+            epoch,
+            slot_index,
+            slots_in_epoch: epoch_schedule.get_slots_in_epoch(epoch),
+        }
+    }
+
+    /// Share of the epoch elapsed, from 0 to 100
+    pub fn percent_complete(&self) -> f64 {
+        if self.slots_in_epoch == 0 {
+            return 0.0;
+        }
+
+        self.slot_index as f64 * 100.0 / self.slots_in_epoch as f64
+    }
+
+    /// Slots left until the next epoch
+    pub fn slots_remaining(&self) -> u64 {
+        self.slots_in_epoch.saturating_sub(self.slot_index)
+    }
+}
+
+impl std::fmt::Display for EpochProgress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Epoch {} ({:.2}%, slot {}/{})",
+            self.epoch,
+            self.percent_complete(),
+            self.slot_index,
+            self.slots_in_epoch
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::epoch_schedule::EpochSchedule;
+
+    use super::EpochProgress;
+
+    #[test]
+    fn test_epoch_progress_without_warmup() {
+        let epoch_schedule = EpochSchedule::without_warmup();
+
+        let progress = EpochProgress::new(&epoch_schedule, 432_000 * 700 + 108_000);
+
+        assert_eq!(progress.epoch, 700);
+        assert_eq!(progress.slot_index, 108_000);
+        assert_eq!(progress.slots_in_epoch, 432_000);
+        assert_eq!(progress.percent_complete(), 25.0);
+        assert_eq!(progress.slots_remaining(), 324_000);
+    }
+
+    #[test]
+    fn test_epoch_progress_with_warmup() {
+        // Warmup epochs double from 32 slots until reaching 8192
+        let epoch_schedule = EpochSchedule::custom(8192, 8192, true);
+        let first_normal_slot = epoch_schedule.first_normal_slot;
+
+        // Epoch 0 spans slots 0..32, epoch 1 spans 32..96
+        let warmup = EpochProgress::new(&epoch_schedule, 40);
+        assert_eq!(warmup.epoch, 1);
+        assert_eq!(warmup.slot_index, 8);
+        assert_eq!(warmup.slots_in_epoch, 64);
+
+        let normal = EpochProgress::new(&epoch_schedule, first_normal_slot + 8192 + 1);
+        assert_eq!(normal.epoch, epoch_schedule.first_normal_epoch + 1);
+        assert_eq!(normal.slot_index, 1);
+        assert_eq!(normal.slots_in_epoch, 8192);
+    }
+}
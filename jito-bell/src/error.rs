@@ -19,11 +19,14 @@ pub enum JitoBellError {
     #[error("Config error: {0}")]
     Config(String),
 
+    #[error("Account decode error: {0}")]
+    AccountDecode(String),
+
     #[error("Geyser client builder error: {0}")]
     GeyserGrpcBuilder(#[from] GeyserGrpcBuilderError),
 
     #[error("Geyser client error: {0}")]
-    GeyserGrpcClient(#[from] GeyserGrpcClientError),
+    GeyserGrpcClient(Box<GeyserGrpcClientError>),
 
     #[error("Subscription Error: {0}")]
     Subscription(String),
@@ -32,7 +35,7 @@ pub enum JitoBellError {
     SolanaProgram(#[from] solana_program::program_error::ProgramError),
 
     #[error("Solana RPC Client error: {0}")]
-    SolanaRpcClient(#[from] solana_rpc_client_api::client_error::Error),
+    SolanaRpcClient(Box<solana_rpc_client_api::client_error::Error>),
 
     #[error("Defillama error: {0}")]
     DefiLlama(#[from] defillama_rs::DefillamaError),
@@ -44,3 +47,16 @@ impl From<serde_yaml::Error> for JitoBellError {
         JitoBellError::Config(err.to_string())
     }
 }
+
+// Boxed to keep `Result<_, JitoBellError>` small
+impl From<GeyserGrpcClientError> for JitoBellError {
+    fn from(err: GeyserGrpcClientError) -> Self {
+        JitoBellError::GeyserGrpcClient(Box::new(err))
+    }
+}
+
+impl From<solana_rpc_client_api::client_error::Error> for JitoBellError {
+    fn from(err: solana_rpc_client_api::client_error::Error) -> Self {
+        JitoBellError::SolanaRpcClient(Box::new(err))
+    }
+}
@@ -14,6 +14,9 @@ use crate::events::jito_steward::{
 const PROGRAM_LOG: &str = "Program log: ";
 const PROGRAM_DATA: &str = "Program data: ";
 
+/// Anchor `EVENT_IX_TAG`, little endian, prefixed to `emit_cpi!` self-CPI data
+pub const EVENT_IX_TAG_LE: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+
 #[derive(Debug, Clone)]
 pub enum JitoStewardEvent {
     AutoRemoveValidator(AutoRemoveValidatorEvent),
@@ -65,13 +68,27 @@ impl JitoStewardEvent {
             }
         };
 
+        Self::decode(&log_bytes)
+    }
+
+    /// Parse the data of an Anchor `emit_cpi!` self-CPI
+    ///
+    /// - Returns `None` for instruction data without the event tag
+    pub fn parse_event_cpi(instruction_data: &[u8]) -> Option<JitoStewardEvent> {
+        let event_bytes = instruction_data.strip_prefix(&EVENT_IX_TAG_LE)?;
+
+        Self::decode(event_bytes)
+    }
+
+    /// Decode a discriminator-prefixed event
+    fn decode(event_bytes: &[u8]) -> Option<JitoStewardEvent> {
         // Need at least 8 bytes for discriminator
-        if log_bytes.len() < 8 {
+        if event_bytes.len() < 8 {
             return None;
         }
 
-        let discriminator = &log_bytes[0..8];
-        let event_data = &log_bytes[8..];
+        let discriminator = &event_bytes[0..8];
+        let event_data = &event_bytes[8..];
 
         // Try each event type by comparing discriminators
 
@@ -155,6 +172,21 @@ impl JitoStewardEvent {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_event_cpi() {
+        let log_bytes = STANDARD
+            .decode("agl496lqzumDAwAAAAAAAFUiLBcAAAAACQAAAFJlYmFsYW5jZQQAAABJZGxl")
+            .unwrap();
+        let mut instruction_data = EVENT_IX_TAG_LE.to_vec();
+        instruction_data.extend_from_slice(&log_bytes);
+
+        assert!(matches!(
+            JitoStewardEvent::parse_event_cpi(&instruction_data),
+            Some(JitoStewardEvent::StateTransition(_))
+        ));
+        assert!(JitoStewardEvent::parse_event_cpi(&log_bytes).is_none());
+    }
+
     #[test]
     fn test_parse_rebalance_event_log() {
         let log = "Program data: eBt162gqhEtMsYgkyyouvKpCwIQK+PQ/fXApJFO4g1PcCGJg3zGq0YMDAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA==";
@@ -1,8 +1,31 @@
+use solana_pubkey::Pubkey;
+
 use crate::event_parser::jito_steward::JitoStewardEvent;
 
 pub mod jito_steward;
+pub mod program_log;
 
 #[derive(Debug)]
 pub enum EventParser {
     JitoSteward(JitoStewardEvent),
 }
+
+/// Parsed event with the invocation that emitted it
+#[derive(Debug)]
+pub struct ParsedEvent {
+    /// Parsed event
+    pub event: EventParser,
+
+    /// Program that emitted the event
+    pub program_id: Pubkey,
+
+    /// Index of the top-level instruction that emitted the event
+    pub top_level_index: usize,
+
+    /// Invocation depth of the emitting program, 1 at top level
+    pub stack_height: usize,
+
+    /// Index of the emitting invocation among the inner instructions of the top-level
+    /// instruction, `None` for the top-level invocation itself
+    pub inner_index: Option<usize>,
+}
@@ -0,0 +1,174 @@
+use std::str::FromStr;
+
+use solana_pubkey::Pubkey;
+
+/// Log line attributed to the invocation that wrote it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgramLog<'a> {
+    /// Program that wrote the line
+    pub program_id: Pubkey,
+
+    /// Index of the top-level instruction being executed
+    pub top_level_index: usize,
+
+    /// Invocation depth of the program, 1 at top level
+    pub stack_height: usize,
+
+    /// Index of the invocation among the inner instructions of the top-level instruction,
+    /// `None` for the top-level invocation itself
+    pub inner_index: Option<usize>,
+
+    /// Full log line, e.g. `Program data: <base64>`
+    pub log: &'a str,
+}
+
+/// Invocation boundary logged by the runtime
+enum Boundary {
+    /// `Program <id> invoke [<depth>]`
+    Invoke(Pubkey, usize),
+
+    /// `Program <id> success` or `Program <id> failed: <error>`
+    Return,
+}
+
+impl Boundary {
+    /// Parse a runtime invoke or return line
+    fn parse(log: &str) -> Option<Self> {
+        let mut parts = log.strip_prefix("Program ")?.split_whitespace();
+        let program_id = Pubkey::from_str(parts.next()?).ok()?;
+
+        match parts.next()? {
+            "invoke" => {
+                let depth = parts
+                    .next()?
+                    .strip_prefix('[')?
+                    .strip_suffix(']')?
+                    .parse()
+                    .ok()?;
+                Some(Self::Invoke(program_id, depth))
+            }
+            "success" | "failed:" => Some(Self::Return),
+            _ => None,
+        }
+    }
+}
+
+/// Attribute `Program log:` and `Program data:` lines to the program that wrote them
+///
+/// - Tracks the invocation stack from `Program <id> invoke [n]` and `success` / `failed` lines
+/// - Top-level instructions are counted from `invoke [1]` lines, which precompiles do not log
+/// - Inner instructions are counted from deeper `invoke` lines, in the order they are recorded
+///   in the transaction meta
+/// - Lines after `Log truncated` are dropped, since the stack can no longer be followed
+pub fn attribute_logs(logs: &[String]) -> Vec<ProgramLog<'_>> {
+    let mut attributed = Vec::new();
+    let mut stack: Vec<(Pubkey, Option<usize>)> = Vec::new();
+    let mut top_level_index = None;
+    let mut inner_count = 0;
+
+    for log in logs {
+        if log == "Log truncated" {
+            break;
+        }
+
+        if log.starts_with("Program log: ") || log.starts_with("Program data: ") {
+            if let (Some((program_id, inner_index)), Some(top_level_index)) =
+                (stack.last(), top_level_index)
+            {
+                attributed.push(ProgramLog {
+                    program_id: *program_id,
+                    top_level_index,
+                    stack_height: stack.len(),
+                    inner_index: *inner_index,
+                    log,
+                });
+            }
+            continue;
+        }
+
+        match Boundary::parse(log) {
+            Some(Boundary::Invoke(program_id, depth)) => {
+                let inner_index = if depth <= 1 {
+                    top_level_index = Some(top_level_index.map_or(0, |index| index + 1));
+                    inner_count = 0;
+                    None
+                } else {
+                    inner_count += 1;
+                    Some(inner_count - 1)
+                };
+                stack.truncate(depth.saturating_sub(1));
+                stack.push((program_id, inner_index));
+            }
+            Some(Boundary::Return) => {
+                stack.pop();
+            }
+            None => {}
+        }
+    }
+
+    attributed
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_pubkey::Pubkey;
+
+    use super::attribute_logs;
+
+    #[test]
+    fn test_attribute_logs() {
+        let router = Pubkey::new_unique();
+        let steward = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let logs = vec![
+            format!("Program {router} invoke [1]"),
+            "Program log: Instruction: Route".to_string(),
+            format!("Program {steward} invoke [2]"),
+            "Program data: c3Rld2FyZA==".to_string(),
+            format!("Program {steward} consumed 1000 of 200000 compute units"),
+            format!("Program {steward} success"),
+            "Program data: cm91dGVy".to_string(),
+            format!("Program {router} success"),
+            format!("Program {other} invoke [1]"),
+            "Program data: b3RoZXI=".to_string(),
+            format!("Program {other} failed: custom program error: 0x1"),
+        ];
+
+        let attributed = attribute_logs(&logs);
+        let summary: Vec<(Pubkey, usize, usize, Option<usize>, &str)> = attributed
+            .iter()
+            .map(|log| {
+                (
+                    log.program_id,
+                    log.top_level_index,
+                    log.stack_height,
+                    log.inner_index,
+                    log.log,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                (router, 0, 1, None, "Program log: Instruction: Route"),
+                (steward, 0, 2, Some(0), "Program data: c3Rld2FyZA=="),
+                (router, 0, 1, None, "Program data: cm91dGVy"),
+                (other, 1, 1, None, "Program data: b3RoZXI="),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_attribute_logs_stops_at_truncation() {
+        let program = Pubkey::new_unique();
+        let logs = vec![
+            format!("Program {program} invoke [1]"),
+            "Program data: AQ==".to_string(),
+            "Log truncated".to_string(),
+            "Program data: Ag==".to_string(),
+        ];
+
+        assert_eq!(attribute_logs(&logs).len(), 1);
+    }
+}
@@ -0,0 +1,385 @@
+use jito_vault_client::{
+    accounts::{Config, Vault},
+    log::metadata::Metadata,
+};
+use jito_vault_sdk::instruction::VaultAdminRole;
+use solana_sdk::pubkey::Pubkey;
+use spl_stake_pool::{
+    instruction::{FundingType, PreferredValidatorType},
+    state::{Fee, FeeType, StakePool},
+};
+
+use crate::ix_parser::{stake_pool::SplStakePoolProgram, vault::JitoVaultProgram};
+
+/// Stake pool instructions that change its authorities, fees or metadata
+pub const STAKE_POOL_GOVERNANCE_INSTRUCTIONS: [&str; 7] = [
+    "set_manager",
+    "set_staker",
+    "set_fee",
+    "set_funding_authority",
+    "set_preferred_validator",
+    "create_token_metadata",
+    "update_token_metadata",
+];
+
+/// Vault program instructions that change a vault's or the program's admins, fees or limits
+pub const VAULT_GOVERNANCE_INSTRUCTIONS: [&str; 8] = [
+    "set_deposit_capacity",
+    "set_fees",
+    "set_program_fee",
+    "set_program_fee_wallet",
+    "set_is_paused",
+    "set_admin",
+    "set_secondary_admin",
+    "set_config_admin",
+];
+
+/// Change of a governed field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GovernanceChange {
+    /// Field name
+    pub field: &'static str,
+
+    /// Value before the change, `None` if it could not be read
+    pub old: Option<String>,
+
+    /// Value set by the instruction
+    pub new: String,
+}
+
+impl GovernanceChange {
+    fn new(field: &'static str, old: Option<String>, new: String) -> Self {
+        Self { field, old, new }
+    }
+}
+
+impl std::fmt::Display for GovernanceChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "`{}`: {} → {}",
+            self.field,
+            self.old.as_deref().unwrap_or("unknown"),
+            self.new
+        )
+    }
+}
+
+/// Optional address, `none` if unset
+fn optional(address: Option<Pubkey>) -> String {
+    address.map_or_else(|| "none".to_string(), |address| address.to_string())
+}
+
+/// Basis points
+fn bps(value: u16) -> String {
+    format!("{value} bps")
+}
+
+/// Metaplex strings are padded with NUL bytes
+fn metadata_string(value: &str) -> String {
+    value.trim_end_matches('\0').to_string()
+}
+
+/// Fields changed by a stake pool governance instruction
+///
+/// - Old values come from `stake_pool`, or `metadata` for token metadata updates
+/// - Other instructions change no governed field
+pub fn stake_pool_changes(
+    program: &SplStakePoolProgram,
+    stake_pool: Option<&StakePool>,
+    metadata: Option<&Metadata>,
+) -> Vec<GovernanceChange> {
+    match program {
+        SplStakePoolProgram::SetManager { ix } => vec![
+            GovernanceChange::new(
+                "manager",
+                stake_pool.map(|pool| pool.manager.to_string()),
+                ix.accounts[2].pubkey.to_string(),
+            ),
+            GovernanceChange::new(
+                "manager_fee_account",
+                stake_pool.map(|pool| pool.manager_fee_account.to_string()),
+                ix.accounts[3].pubkey.to_string(),
+            ),
+        ],
+        SplStakePoolProgram::SetStaker { ix } => vec![GovernanceChange::new(
+            "staker",
+            stake_pool.map(|pool| pool.staker.to_string()),
+            ix.accounts[2].pubkey.to_string(),
+        )],
+        SplStakePoolProgram::SetFee { fee, .. } => {
+            let fee_change = |field, old: fn(&StakePool) -> Fee, new: &Fee| {
+                GovernanceChange::new(
+                    field,
+                    stake_pool.map(|pool| old(pool).to_string()),
+                    new.to_string(),
+                )
+            };
+            let referral_change = |field, old: fn(&StakePool) -> u8, new: &u8| {
+                GovernanceChange::new(
+                    field,
+                    stake_pool.map(|pool| format!("{}%", old(pool))),
+                    format!("{new}%"),
+                )
+            };
+
+            vec![match fee {
+                FeeType::Epoch(new) => fee_change("epoch_fee", |pool| pool.epoch_fee, new),
+                FeeType::StakeWithdrawal(new) => fee_change(
+                    "stake_withdrawal_fee",
+                    |pool| pool.stake_withdrawal_fee,
+                    new,
+                ),
+                FeeType::SolWithdrawal(new) => {
+                    fee_change("sol_withdrawal_fee", |pool| pool.sol_withdrawal_fee, new)
+                }
+                FeeType::StakeDeposit(new) => {
+                    fee_change("stake_deposit_fee", |pool| pool.stake_deposit_fee, new)
+                }
+                FeeType::SolDeposit(new) => {
+                    fee_change("sol_deposit_fee", |pool| pool.sol_deposit_fee, new)
+                }
+                FeeType::StakeReferral(new) => {
+                    referral_change("stake_referral_fee", |pool| pool.stake_referral_fee, new)
+                }
+                FeeType::SolReferral(new) => {
+                    referral_change("sol_referral_fee", |pool| pool.sol_referral_fee, new)
+                }
+            }]
+        }
+        SplStakePoolProgram::SetFundingAuthority {
+            funding_type,
+            new_authority,
+            ..
+        } => {
+            let change = match funding_type {
+                // Unsetting the stake deposit authority restores the pool's default PDA
+                FundingType::StakeDeposit => GovernanceChange::new(
+                    "stake_deposit_authority",
+                    stake_pool.map(|pool| pool.stake_deposit_authority.to_string()),
+                    new_authority.map_or_else(|| "default".to_string(), |a| a.to_string()),
+                ),
+                FundingType::SolDeposit => GovernanceChange::new(
+                    "sol_deposit_authority",
+                    stake_pool.map(|pool| optional(pool.sol_deposit_authority)),
+                    optional(*new_authority),
+                ),
+                FundingType::SolWithdraw => GovernanceChange::new(
+                    "sol_withdraw_authority",
+                    stake_pool.map(|pool| optional(pool.sol_withdraw_authority)),
+                    optional(*new_authority),
+                ),
+            };
+            vec![change]
+        }
+        SplStakePoolProgram::SetPreferredValidator {
+            validator_type,
+            validator_vote_address,
+            ..
+        } => {
+            let change = match validator_type {
+                PreferredValidatorType::Deposit => GovernanceChange::new(
+                    "preferred_deposit_validator",
+                    stake_pool.map(|pool| optional(pool.preferred_deposit_validator_vote_address)),
+                    optional(*validator_vote_address),
+                ),
+                PreferredValidatorType::Withdraw => GovernanceChange::new(
+                    "preferred_withdraw_validator",
+                    stake_pool.map(|pool| optional(pool.preferred_withdraw_validator_vote_address)),
+                    optional(*validator_vote_address),
+                ),
+            };
+            vec![change]
+        }
+        SplStakePoolProgram::CreateTokenMetadata {
+            name, symbol, uri, ..
+        }
+        | SplStakePoolProgram::UpdateTokenMetadata {
+            name, symbol, uri, ..
+        } => {
+            // Token metadata created by the pool has no previous values
+            let created = matches!(program, SplStakePoolProgram::CreateTokenMetadata { .. });
+            let old = |value: fn(&Metadata) -> &String| {
+                if created {
+                    Some("none".to_string())
+                } else {
+                    metadata.map(|metadata| metadata_string(value(metadata)))
+                }
+            };
+
+            vec![
+                GovernanceChange::new("name", old(|m| &m.name), name.clone()),
+                GovernanceChange::new("symbol", old(|m| &m.symbol), symbol.clone()),
+                GovernanceChange::new("uri", old(|m| &m.uri), uri.clone()),
+            ]
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Fields changed by a vault program admin instruction
+///
+/// - Old values come from `vault`, or `config` for program-wide settings
+/// - Deposit capacity is in base units of the vault's supported token
+/// - Other instructions change no governed field
+pub fn vault_changes(
+    program: &JitoVaultProgram,
+    vault: Option<&Vault>,
+    config: Option<&Config>,
+) -> Vec<GovernanceChange> {
+    match program {
+        JitoVaultProgram::SetDepositCapacity { amount, .. } => vec![GovernanceChange::new(
+            "deposit_capacity",
+            vault.map(|vault| vault.deposit_capacity.to_string()),
+            amount.to_string(),
+        )],
+        JitoVaultProgram::SetFees {
+            deposit_fee_bps,
+            withdrawal_fee_bps,
+            reward_fee_bps,
+            ..
+        } => {
+            let fee_change = |field, old: fn(&Vault) -> u16, new: &Option<u16>| {
+                new.map(|new| {
+                    GovernanceChange::new(field, vault.map(|vault| bps(old(vault))), bps(new))
+                })
+            };
+
+            // Fees left as `None` keep their value
+            [
+                fee_change(
+                    "deposit_fee",
+                    |vault| vault.deposit_fee_bps,
+                    deposit_fee_bps,
+                ),
+                fee_change(
+                    "withdrawal_fee",
+                    |vault| vault.withdrawal_fee_bps,
+                    withdrawal_fee_bps,
+                ),
+                fee_change("reward_fee", |vault| vault.reward_fee_bps, reward_fee_bps),
+            ]
+            .into_iter()
+            .flatten()
+            .collect()
+        }
+        JitoVaultProgram::SetProgramFee { new_fee_bps, .. } => vec![GovernanceChange::new(
+            "program_fee",
+            config.map(|config| bps(config.program_fee_bps)),
+            bps(*new_fee_bps),
+        )],
+        JitoVaultProgram::SetProgramFeeWallet { ix } => vec![GovernanceChange::new(
+            "program_fee_wallet",
+            config.map(|config| config.program_fee_wallet.to_string()),
+            ix.accounts[2].pubkey.to_string(),
+        )],
+        JitoVaultProgram::SetIsPaused { is_paused, .. } => vec![GovernanceChange::new(
+            "is_paused",
+            vault.map(|vault| vault.is_paused.to_string()),
+            is_paused.to_string(),
+        )],
+        JitoVaultProgram::SetAdmin { ix } => vec![GovernanceChange::new(
+            "admin",
+            vault.map(|vault| vault.admin.to_string()),
+            ix.accounts[3].pubkey.to_string(),
+        )],
+        JitoVaultProgram::SetSecondaryAdmin { ix, role } => {
+            let (field, old): (_, fn(&Vault) -> Pubkey) = match role {
+                VaultAdminRole::DelegationAdmin => ("delegation_admin", |v| v.delegation_admin),
+                VaultAdminRole::OperatorAdmin => ("operator_admin", |v| v.operator_admin),
+                VaultAdminRole::NcnAdmin => ("ncn_admin", |v| v.ncn_admin),
+                VaultAdminRole::SlasherAdmin => ("slasher_admin", |v| v.slasher_admin),
+                VaultAdminRole::CapacityAdmin => ("capacity_admin", |v| v.capacity_admin),
+                VaultAdminRole::FeeWallet => ("fee_wallet", |v| v.fee_wallet),
+                VaultAdminRole::MintBurnAdmin => ("mint_burn_admin", |v| v.mint_burn_admin),
+                VaultAdminRole::DelegateAssetAdmin => {
+                    ("delegate_asset_admin", |v| v.delegate_asset_admin)
+                }
+                VaultAdminRole::FeeAdmin => ("fee_admin", |v| v.fee_admin),
+                VaultAdminRole::MetadataAdmin => ("metadata_admin", |v| v.metadata_admin),
+            };
+            vec![GovernanceChange::new(
+                field,
+                vault.map(|vault| old(vault).to_string()),
+                ix.accounts[3].pubkey.to_string(),
+            )]
+        }
+        JitoVaultProgram::SetConfigAdmin { ix } => vec![GovernanceChange::new(
+            "config_admin",
+            config.map(|config| config.admin.to_string()),
+            ix.accounts[2].pubkey.to_string(),
+        )],
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+    use spl_stake_pool::state::{Fee, FeeType, StakePool};
+
+    use super::{stake_pool_changes, vault_changes, GovernanceChange};
+    use crate::ix_parser::{stake_pool::SplStakePoolProgram, vault::JitoVaultProgram};
+
+    fn ix(accounts: usize) -> Instruction {
+        let accounts = (0..accounts)
+            .map(|_| solana_sdk::instruction::AccountMeta::new(Pubkey::new_unique(), false))
+            .collect();
+        Instruction::new_with_bytes(SplStakePoolProgram::program_id(), &[], accounts)
+    }
+
+    #[test]
+    fn test_set_staker_change() {
+        let staker = Pubkey::new_unique();
+        let stake_pool = StakePool {
+            staker,
+            ..Default::default()
+        };
+        let ix = ix(3);
+        let new_staker = ix.accounts[2].pubkey;
+        let program = SplStakePoolProgram::SetStaker { ix };
+
+        assert_eq!(
+            stake_pool_changes(&program, Some(&stake_pool), None),
+            vec![GovernanceChange {
+                field: "staker",
+                old: Some(staker.to_string()),
+                new: new_staker.to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_set_fee_change_without_stake_pool() {
+        let program = SplStakePoolProgram::SetFee {
+            ix: ix(2),
+            fee: FeeType::SolDeposit(Fee {
+                denominator: 1_000,
+                numerator: 1,
+            }),
+        };
+
+        let changes = stake_pool_changes(&program, None, None);
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(
+            changes[0].to_string(),
+            "`sol_deposit_fee`: unknown → 1/1000"
+        );
+    }
+
+    #[test]
+    fn test_set_fees_changes_only_set_fees() {
+        let program = JitoVaultProgram::SetFees {
+            ix: ix(3),
+            deposit_fee_bps: None,
+            withdrawal_fee_bps: Some(25),
+            reward_fee_bps: None,
+        };
+
+        let changes = vault_changes(&program, None, None);
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].to_string(), "`withdrawal_fee`: unknown → 25 bps");
+    }
+}
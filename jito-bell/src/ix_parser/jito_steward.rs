@@ -121,8 +121,8 @@ impl JitoStewardInstruction {
         account_keys: &[Pubkey],
     ) -> Option<JitoStewardInstruction> {
         let instruction_data = instruction.data();
-        match instruction_data[0..8] {
-            [135, 132, 9, 127, 189, 161, 14, 5] => {
+        match instruction_data.get(..8)? {
+            [135, 132, 9, 127, 189, 161, 14, 5] if instruction_data.len() >= 52 => {
                 let vote_pubkey = {
                     let mut pubkey_array = [0; 32];
                     pubkey_array.copy_from_slice(&instruction_data[8..40]);
@@ -1,3 +1,5 @@
+use solana_pubkey::Pubkey;
+use spl_token::SplTokenProgram;
 use stake_pool::SplStakePoolProgram;
 use token_2022::SplToken2022Program;
 use vault::JitoVaultProgram;
@@ -6,6 +8,7 @@ use crate::ix_parser::jito_steward::JitoStewardInstruction;
 
 pub mod instruction;
 pub mod jito_steward;
+pub mod spl_token;
 pub mod stake_pool;
 pub mod token_2022;
 pub mod vault;
@@ -13,6 +16,7 @@ pub mod vault;
 #[derive(Debug)]
 pub enum InstructionParser {
     JitoSteward(JitoStewardInstruction),
+    SplToken(SplTokenProgram),
     SplToken2022(SplToken2022Program),
     SplStakePool(SplStakePoolProgram),
     JitoVault(JitoVaultProgram),
@@ -21,6 +25,7 @@ pub enum InstructionParser {
 impl std::fmt::Display for InstructionParser {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            InstructionParser::SplToken(_) => write!(f, "spl-token"),
             InstructionParser::SplToken2022(_) => write!(f, "spl-token-2022"),
             InstructionParser::SplStakePool(_) => write!(f, "spl_stake_pool"),
             InstructionParser::JitoVault(_) => write!(f, "jito_vault"),
@@ -28,3 +33,45 @@ impl std::fmt::Display for InstructionParser {
         }
     }
 }
+
+/// Parsed instruction with its place in the transaction's CPI tree
+#[derive(Debug)]
+pub struct ParsedInstruction {
+    /// Parsed instruction
+    pub instruction: InstructionParser,
+
+    /// Program executing the instruction
+    pub program_id: Pubkey,
+
+    /// Index of the top-level instruction it belongs to
+    pub top_level_index: usize,
+
+    /// Index among the inner instructions of the top-level instruction, `None` at top level
+    pub inner_index: Option<usize>,
+
+    /// Invocation depth, 1 at top level
+    pub stack_height: u32,
+
+    /// `inner_index` of the invoking instruction, `None` if invoked by the top-level instruction
+    pub parent_inner_index: Option<usize>,
+
+    /// Program of the invoking instruction, `None` at top level
+    pub parent_program_id: Option<Pubkey>,
+
+    /// Program of the top-level instruction that triggered it
+    pub top_level_program_id: Pubkey,
+}
+
+impl ParsedInstruction {
+    /// Whether the instruction was invoked through CPI
+    pub fn is_cpi(&self) -> bool {
+        self.inner_index.is_some()
+    }
+
+    /// Whether `parent` invoked the instruction through a direct CPI
+    pub fn is_invoked_by(&self, parent: &ParsedInstruction) -> bool {
+        self.is_cpi()
+            && self.top_level_index == parent.top_level_index
+            && self.parent_inner_index == parent.inner_index
+    }
+}
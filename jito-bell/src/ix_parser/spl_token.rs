@@ -0,0 +1,417 @@
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use spl_token::instruction::TokenInstruction;
+
+use super::instruction::ParsableInstruction;
+
+/// SPL Token Program
+#[derive(Debug)]
+pub enum SplTokenProgram {
+    MintTo {
+        ix: Instruction,
+        amount: u64,
+    },
+    MintToChecked {
+        ix: Instruction,
+        amount: u64,
+        decimals: u8,
+    },
+    Burn {
+        ix: Instruction,
+        amount: u64,
+    },
+    BurnChecked {
+        ix: Instruction,
+        amount: u64,
+        decimals: u8,
+    },
+    Transfer {
+        ix: Instruction,
+        amount: u64,
+    },
+    TransferChecked {
+        ix: Instruction,
+        amount: u64,
+        decimals: u8,
+    },
+}
+
+impl std::fmt::Display for SplTokenProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SplTokenProgram::MintTo { .. } => write!(f, "mint_to"),
+            SplTokenProgram::MintToChecked { .. } => write!(f, "mint_to_checked"),
+            SplTokenProgram::Burn { .. } => write!(f, "burn"),
+            SplTokenProgram::BurnChecked { .. } => write!(f, "burn_checked"),
+            SplTokenProgram::Transfer { .. } => write!(f, "transfer"),
+            SplTokenProgram::TransferChecked { .. } => write!(f, "transfer_checked"),
+        }
+    }
+}
+
+impl SplTokenProgram {
+    /// Retrieve Program ID of SPL Token Program
+    pub fn program_id() -> Pubkey {
+        spl_token::id()
+    }
+
+    /// Mint the instruction moves tokens of, if named in its accounts
+    ///
+    /// - `Transfer` only names token accounts, the mint must be read from the source account
+    pub fn mint(&self) -> Option<&Pubkey> {
+        let (ix, index) = match self {
+            SplTokenProgram::MintTo { ix, .. } | SplTokenProgram::MintToChecked { ix, .. } => {
+                (ix, 0)
+            }
+            SplTokenProgram::Burn { ix, .. }
+            | SplTokenProgram::BurnChecked { ix, .. }
+            | SplTokenProgram::TransferChecked { ix, .. } => (ix, 1),
+            SplTokenProgram::Transfer { .. } => return None,
+        };
+
+        ix.accounts.get(index).map(|account| &account.pubkey)
+    }
+
+    /// Parse SPL Token program
+    pub fn parse_spl_token_program<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<SplTokenProgram> {
+        let token_ix = match TokenInstruction::unpack(instruction.data()) {
+            Ok(ix) => ix,
+            Err(_) => return None,
+        };
+
+        match token_ix {
+            TokenInstruction::MintTo { amount } => {
+                let ix = Self::parse_mint_to_ix(instruction, account_keys);
+                Some(SplTokenProgram::MintTo { ix, amount })
+            }
+            TokenInstruction::MintToChecked { amount, decimals } => {
+                let ix = Self::parse_mint_to_ix(instruction, account_keys);
+                Some(SplTokenProgram::MintToChecked {
+                    ix,
+                    amount,
+                    decimals,
+                })
+            }
+            TokenInstruction::Burn { amount } => {
+                let ix = Self::parse_burn_ix(instruction, account_keys);
+                Some(SplTokenProgram::Burn { ix, amount })
+            }
+            TokenInstruction::BurnChecked { amount, decimals } => {
+                let ix = Self::parse_burn_ix(instruction, account_keys);
+                Some(SplTokenProgram::BurnChecked {
+                    ix,
+                    amount,
+                    decimals,
+                })
+            }
+            TokenInstruction::Transfer { amount } => {
+                let ix = Self::parse_transfer_ix(instruction, account_keys);
+                Some(SplTokenProgram::Transfer { ix, amount })
+            }
+            TokenInstruction::TransferChecked { amount, decimals } => {
+                let ix = Self::parse_transfer_checked_ix(instruction, account_keys);
+                Some(SplTokenProgram::TransferChecked {
+                    ix,
+                    amount,
+                    decimals,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Mints new tokens to an account, `MintTo` and `MintToChecked`
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The mint.
+    ///   1. `[writable]` The account to mint tokens to.
+    ///   2. `[signer]` The mint's minting authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The mint.
+    ///   1. `[writable]` The account to mint tokens to.
+    ///   2. `[]` The mint's multisignature mint-tokens authority.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    fn parse_mint_to_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Burns tokens by removing them from an account, `Burn` and `BurnChecked`
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The account to burn from.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[signer]` The account's owner/delegate.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The account to burn from.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[]` The account's multisignature owner/delegate.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    fn parse_burn_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Transfers tokens from one account to another
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[signer]` The source account's owner/delegate.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[]` The source account's multisignature owner/delegate.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    fn parse_transfer_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Transfers tokens from one account to another, asserting the mint and decimals
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[signer]` The source account's owner/delegate.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[]` The source account's multisignature owner/delegate.
+    ///   4. ..4+M `[signer]` M signer accounts.
+    fn parse_transfer_checked_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Resolve `account_metas` from the instruction's account indexes
+    ///
+    /// - Multisig signers beyond the named accounts are appended as readonly signers
+    fn build_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+        mut account_metas: Vec<AccountMeta>,
+    ) -> Instruction {
+        for (index, account) in instruction.accounts().iter().enumerate() {
+            let Some(account) = account_keys.get(*account as usize) else {
+                continue;
+            };
+
+            match account_metas.get_mut(index) {
+                Some(account_meta) => account_meta.pubkey = *account,
+                None => account_metas.push(AccountMeta::new_readonly(*account, true)),
+            }
+        }
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts: account_metas,
+            data: instruction.data().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::ix_parser::spl_token::SplTokenProgram;
+
+    fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Keypair::new().pubkey()).collect()
+    }
+
+    fn create_compiled_instruction(
+        program_id_index: u32,
+        accounts: Vec<u8>,
+        data: Vec<u8>,
+    ) -> CompiledInstruction {
+        CompiledInstruction {
+            program_id_index,
+            accounts,
+            data,
+        }
+    }
+
+    fn amount_data(ix_number: u8, amount: u64) -> Vec<u8> {
+        let mut data = vec![ix_number];
+        data.extend_from_slice(&amount.to_le_bytes());
+        data
+    }
+
+    fn checked_amount_data(ix_number: u8, amount: u64, decimals: u8) -> Vec<u8> {
+        let mut data = amount_data(ix_number, amount);
+        data.push(decimals);
+        data
+    }
+
+    fn parse(num_account: usize, data: Vec<u8>) -> (Vec<Pubkey>, SplTokenProgram) {
+        let account_keys = create_test_pubkeys(num_account);
+        let accounts = (0..num_account).map(|i| i as u8).collect();
+        let instruction = create_compiled_instruction(1, accounts, data);
+
+        let parsed = SplTokenProgram::parse_spl_token_program(&instruction, &account_keys)
+            .expect("Expected a parsed instruction");
+
+        (account_keys, parsed)
+    }
+
+    #[test]
+    fn test_mint_to() {
+        let (account_keys, parsed) = parse(3, amount_data(7, 5));
+
+        if let SplTokenProgram::MintTo { ix, amount } = parsed {
+            assert_eq!(amount, 5);
+            assert_eq!(ix.accounts[0].pubkey, account_keys[0]);
+            assert_eq!(ix.accounts[1].pubkey, account_keys[1]);
+            assert_eq!(ix.accounts[2].pubkey, account_keys[2]);
+        } else {
+            panic!("Expected MintTo variant");
+        }
+    }
+
+    #[test]
+    fn test_mint_to_checked() {
+        let (_, parsed) = parse(3, checked_amount_data(14, 5_000_000_000, 9));
+
+        if let SplTokenProgram::MintToChecked {
+            amount, decimals, ..
+        } = parsed
+        {
+            assert_eq!(amount, 5_000_000_000);
+            assert_eq!(decimals, 9);
+        } else {
+            panic!("Expected MintToChecked variant");
+        }
+    }
+
+    #[test]
+    fn test_burn() {
+        let (account_keys, parsed) = parse(3, amount_data(8, 42));
+
+        assert_eq!(parsed.mint(), Some(&account_keys[1]));
+        if let SplTokenProgram::Burn { ix, amount } = parsed {
+            assert_eq!(amount, 42);
+            assert_eq!(ix.accounts[1].pubkey, account_keys[1]);
+        } else {
+            panic!("Expected Burn variant");
+        }
+    }
+
+    #[test]
+    fn test_burn_checked() {
+        let (_, parsed) = parse(3, checked_amount_data(15, 42, 6));
+
+        if let SplTokenProgram::BurnChecked {
+            amount, decimals, ..
+        } = parsed
+        {
+            assert_eq!(amount, 42);
+            assert_eq!(decimals, 6);
+        } else {
+            panic!("Expected BurnChecked variant");
+        }
+    }
+
+    #[test]
+    fn test_transfer_with_multisig_signers() {
+        let (account_keys, parsed) = parse(5, amount_data(3, 1_000));
+
+        if let SplTokenProgram::Transfer { ix, amount } = parsed {
+            assert_eq!(amount, 1_000);
+            assert_eq!(ix.accounts.len(), 5);
+            assert_eq!(ix.accounts[4].pubkey, account_keys[4]);
+            assert!(ix.accounts[4].is_signer);
+        } else {
+            panic!("Expected Transfer variant");
+        }
+    }
+
+    #[test]
+    fn test_transfer_has_no_mint() {
+        let (_, parsed) = parse(3, amount_data(3, 1_000));
+
+        assert_eq!(parsed.mint(), None);
+    }
+
+    #[test]
+    fn test_transfer_checked() {
+        let (account_keys, parsed) = parse(4, checked_amount_data(12, 1_000, 9));
+
+        if let SplTokenProgram::TransferChecked {
+            ix,
+            amount,
+            decimals,
+        } = parsed
+        {
+            assert_eq!(amount, 1_000);
+            assert_eq!(decimals, 9);
+            assert_eq!(ix.accounts[1].pubkey, account_keys[1]);
+            assert_eq!(ix.accounts[2].pubkey, account_keys[2]);
+        } else {
+            panic!("Expected TransferChecked variant");
+        }
+    }
+
+    #[test]
+    fn test_unsupported_instruction() {
+        let account_keys = create_test_pubkeys(2);
+        // CloseAccount
+        let instruction = create_compiled_instruction(1, vec![0, 1], vec![9]);
+
+        assert!(SplTokenProgram::parse_spl_token_program(&instruction, &account_keys).is_none());
+    }
+}
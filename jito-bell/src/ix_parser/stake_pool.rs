@@ -5,7 +5,10 @@ use solana_sdk::{
     native_token::lamports_to_sol,
     pubkey::Pubkey,
 };
-use spl_stake_pool::instruction::StakePoolInstruction;
+use spl_stake_pool::{
+    instruction::{FundingType, PreferredValidatorType, StakePoolInstruction},
+    state::FeeType,
+};
 
 use super::instruction::ParsableInstruction;
 
@@ -13,14 +16,25 @@ use super::instruction::ParsableInstruction;
 #[derive(Debug, PartialEq)]
 pub enum SplStakePoolProgram {
     Initialize,
-    AddValidatorToPool,
-    RemoveValidatorFromPool,
+    AddValidatorToPool {
+        ix: Instruction,
+        stake_account: Pubkey,
+        vote_account: Pubkey,
+    },
+    RemoveValidatorFromPool {
+        ix: Instruction,
+        stake_account: Pubkey,
+    },
     DecreaseValidatorStake,
     IncreaseValidatorStake {
         ix: Instruction,
         amount: f64,
     },
-    SetPreferredValidator,
+    SetPreferredValidator {
+        ix: Instruction,
+        validator_type: PreferredValidatorType,
+        validator_vote_address: Option<Pubkey>,
+    },
     UpdateValidatorListBalance,
     UpdateStakePoolBalance,
     CleanupRemovedValidatorEntries,
@@ -31,44 +45,99 @@ pub enum SplStakePoolProgram {
         ix: Instruction,
         minimum_lamports_out: f64,
     },
-    SetManager,
-    SetFee,
-    SetStaker,
+    SetManager {
+        ix: Instruction,
+    },
+    SetFee {
+        ix: Instruction,
+        fee: FeeType,
+    },
+    SetStaker {
+        ix: Instruction,
+    },
     DepositSol {
         ix: Instruction,
         amount: f64,
     },
-    SetFundingAuthority,
+    SetFundingAuthority {
+        ix: Instruction,
+        funding_type: FundingType,
+        new_authority: Option<Pubkey>,
+    },
     WithdrawSol {
         ix: Instruction,
         amount: f64,
     },
-    CreateTokenMetadata,
-    UpdateTokenMetadata,
-    IncreaseAdditionalValidatorStake,
-    DecreaseAdditionalValidatorStake,
+    CreateTokenMetadata {
+        ix: Instruction,
+        name: String,
+        symbol: String,
+        uri: String,
+    },
+    UpdateTokenMetadata {
+        ix: Instruction,
+        name: String,
+        symbol: String,
+        uri: String,
+    },
+    IncreaseAdditionalValidatorStake {
+        ix: Instruction,
+        amount: f64,
+        stake_account: Pubkey,
+        vote_account: Pubkey,
+    },
+    DecreaseAdditionalValidatorStake {
+        ix: Instruction,
+        amount: f64,
+        stake_account: Pubkey,
+    },
     DecreaseValidatorStakeWithReserve {
         ix: Instruction,
         amount: f64,
     },
-    Redelegate,
-    DepositStakeWithSlippage,
-    WithdrawStakeWithSlippage,
-    DepositSolWithSlippage,
-    WithdrawSolWithSlippage,
+    Redelegate {
+        ix: Instruction,
+        amount: f64,
+        source_stake_account: Pubkey,
+        destination_stake_account: Pubkey,
+        destination_vote_account: Pubkey,
+    },
+    DepositStakeWithSlippage {
+        ix: Instruction,
+        minimum_pool_tokens_out: f64,
+    },
+    WithdrawStakeWithSlippage {
+        ix: Instruction,
+        pool_tokens_in: f64,
+        minimum_lamports_out: f64,
+    },
+    DepositSolWithSlippage {
+        ix: Instruction,
+        amount: f64,
+        minimum_pool_tokens_out: f64,
+    },
+    WithdrawSolWithSlippage {
+        ix: Instruction,
+        amount: f64,
+        minimum_lamports_out: f64,
+    },
 }
 
 impl std::fmt::Display for SplStakePoolProgram {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             SplStakePoolProgram::Initialize => write!(f, "initialize"),
-            SplStakePoolProgram::AddValidatorToPool => write!(f, "add_validator_pool"),
-            SplStakePoolProgram::RemoveValidatorFromPool => write!(f, "remove_validator_from_pool"),
+            SplStakePoolProgram::AddValidatorToPool { .. } => write!(f, "add_validator_pool"),
+            SplStakePoolProgram::RemoveValidatorFromPool { .. } => {
+                write!(f, "remove_validator_from_pool")
+            }
             SplStakePoolProgram::DecreaseValidatorStake => write!(f, "decrease_validator_stake"),
             SplStakePoolProgram::IncreaseValidatorStake { ix: _, amount: _ } => {
                 write!(f, "increase_validator_stake")
             }
-            SplStakePoolProgram::SetPreferredValidator => write!(f, "set_preferred_validator"),
+            SplStakePoolProgram::SetPreferredValidator { .. } => {
+                write!(f, "set_preferred_validator")
+            }
             SplStakePoolProgram::UpdateValidatorListBalance => {
                 write!(f, "update_validator_list_balance")
             }
@@ -81,32 +150,36 @@ impl std::fmt::Display for SplStakePoolProgram {
                 ix: _,
                 minimum_lamports_out: _,
             } => write!(f, "withdraw_stake"),
-            SplStakePoolProgram::SetManager => write!(f, "set_manager"),
-            SplStakePoolProgram::SetFee => write!(f, "set_fee"),
-            SplStakePoolProgram::SetStaker => write!(f, "set_staker"),
+            SplStakePoolProgram::SetManager { .. } => write!(f, "set_manager"),
+            SplStakePoolProgram::SetFee { .. } => write!(f, "set_fee"),
+            SplStakePoolProgram::SetStaker { .. } => write!(f, "set_staker"),
             SplStakePoolProgram::DepositSol { ix: _, amount: _ } => write!(f, "deposit_sol"),
-            SplStakePoolProgram::SetFundingAuthority => write!(f, "set_funding_authority"),
+            SplStakePoolProgram::SetFundingAuthority { .. } => write!(f, "set_funding_authority"),
             SplStakePoolProgram::WithdrawSol { ix: _, amount: _ } => write!(f, "withdraw_sol"),
-            SplStakePoolProgram::CreateTokenMetadata => write!(f, "create_token_metadata"),
-            SplStakePoolProgram::UpdateTokenMetadata => write!(f, "update_token_metadata"),
-            SplStakePoolProgram::IncreaseAdditionalValidatorStake => {
+            SplStakePoolProgram::CreateTokenMetadata { .. } => write!(f, "create_token_metadata"),
+            SplStakePoolProgram::UpdateTokenMetadata { .. } => write!(f, "update_token_metadata"),
+            SplStakePoolProgram::IncreaseAdditionalValidatorStake { .. } => {
                 write!(f, "increase_additional_validator_stake")
             }
-            SplStakePoolProgram::DecreaseAdditionalValidatorStake => {
+            SplStakePoolProgram::DecreaseAdditionalValidatorStake { .. } => {
                 write!(f, "decrease_additional_validator_stake")
             }
             SplStakePoolProgram::DecreaseValidatorStakeWithReserve { ix: _, amount: _ } => {
                 write!(f, "decrease_validator_stake_with_reserve")
             }
-            SplStakePoolProgram::Redelegate => write!(f, "redelegate"),
-            SplStakePoolProgram::DepositStakeWithSlippage => {
+            SplStakePoolProgram::Redelegate { .. } => write!(f, "redelegate"),
+            SplStakePoolProgram::DepositStakeWithSlippage { .. } => {
                 write!(f, "deposit_stake_with_slippage")
             }
-            SplStakePoolProgram::WithdrawStakeWithSlippage => {
+            SplStakePoolProgram::WithdrawStakeWithSlippage { .. } => {
                 write!(f, "withdraw_stake_with_slippage")
             }
-            SplStakePoolProgram::DepositSolWithSlippage => write!(f, "deposit_sol_with_slippage"),
-            SplStakePoolProgram::WithdrawSolWithSlippage => write!(f, "withdraw_sol_with_slippage"),
+            SplStakePoolProgram::DepositSolWithSlippage { .. } => {
+                write!(f, "deposit_sol_with_slippage")
+            }
+            SplStakePoolProgram::WithdrawSolWithSlippage { .. } => {
+                write!(f, "withdraw_sol_with_slippage")
+            }
         }
     }
 }
@@ -117,6 +190,36 @@ impl SplStakePoolProgram {
         Pubkey::from_str("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy").unwrap()
     }
 
+    /// Pool tokens burned by a `WithdrawStake` or `WithdrawStakeWithSlippage`
+    ///
+    /// - `WithdrawStake` carries only the pool token amount, under `minimum_lamports_out`
+    /// - The minimum out of `WithdrawStakeWithSlippage` is a lower bound set by the user, often 0
+    pub fn withdrawn_pool_tokens(&self) -> Option<f64> {
+        match self {
+            SplStakePoolProgram::WithdrawStake {
+                minimum_lamports_out,
+                ..
+            } => Some(*minimum_lamports_out),
+            SplStakePoolProgram::WithdrawStakeWithSlippage { pool_tokens_in, .. } => {
+                Some(*pool_tokens_in)
+            }
+            _ => None,
+        }
+    }
+
+    /// Minimum SOL out of a `WithdrawStakeWithSlippage`, for the alert description
+    ///
+    /// - Converted from lamports when parsed
+    pub fn minimum_out_text(&self) -> Option<String> {
+        match self {
+            SplStakePoolProgram::WithdrawStakeWithSlippage {
+                minimum_lamports_out,
+                ..
+            } => Some(format!("Minimum out: {minimum_lamports_out:.2} SOL")),
+            _ => None,
+        }
+    }
+
     /// Parse SPL Stake Pool program
     pub fn parse_spl_stake_pool_program<T: ParsableInstruction>(
         instruction: &T,
@@ -137,23 +240,72 @@ impl SplStakePoolProgram {
                 lamports,
             )),
             StakePoolInstruction::DepositStake => {
-                Some(Self::parse_deposit_stake_ix(instruction, account_keys))
+                let ix = Self::parse_deposit_stake_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::DepositStake { ix })
+            }
+            StakePoolInstruction::WithdrawStake(amount) => {
+                let ix = Self::parse_withdraw_stake_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::WithdrawStake {
+                    ix,
+                    minimum_lamports_out: lamports_to_sol(amount),
+                })
+            }
+            StakePoolInstruction::DepositSol(amount) => {
+                let ix = Self::parse_deposit_sol_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::DepositSol {
+                    ix,
+                    amount: lamports_to_sol(amount),
+                })
+            }
+            StakePoolInstruction::WithdrawSol(amount) => {
+                let ix = Self::parse_withdraw_sol_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::WithdrawSol {
+                    ix,
+                    amount: lamports_to_sol(amount),
+                })
+            }
+            StakePoolInstruction::DepositStakeWithSlippage {
+                minimum_pool_tokens_out,
+            } => {
+                let ix = Self::parse_deposit_stake_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::DepositStakeWithSlippage {
+                    ix,
+                    minimum_pool_tokens_out: lamports_to_sol(minimum_pool_tokens_out),
+                })
+            }
+            StakePoolInstruction::WithdrawStakeWithSlippage {
+                pool_tokens_in,
+                minimum_lamports_out,
+            } => {
+                let ix = Self::parse_withdraw_stake_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::WithdrawStakeWithSlippage {
+                    ix,
+                    pool_tokens_in: lamports_to_sol(pool_tokens_in),
+                    minimum_lamports_out: lamports_to_sol(minimum_lamports_out),
+                })
+            }
+            StakePoolInstruction::DepositSolWithSlippage {
+                lamports_in,
+                minimum_pool_tokens_out,
+            } => {
+                let ix = Self::parse_deposit_sol_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::DepositSolWithSlippage {
+                    ix,
+                    amount: lamports_to_sol(lamports_in),
+                    minimum_pool_tokens_out: lamports_to_sol(minimum_pool_tokens_out),
+                })
+            }
+            StakePoolInstruction::WithdrawSolWithSlippage {
+                pool_tokens_in,
+                minimum_lamports_out,
+            } => {
+                let ix = Self::parse_withdraw_sol_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::WithdrawSolWithSlippage {
+                    ix,
+                    amount: lamports_to_sol(pool_tokens_in),
+                    minimum_lamports_out: lamports_to_sol(minimum_lamports_out),
+                })
             }
-            StakePoolInstruction::WithdrawStake(amount) => Some(Self::parse_withdraw_stake_ix(
-                instruction,
-                account_keys,
-                amount,
-            )),
-            StakePoolInstruction::DepositSol(amount) => Some(Self::parse_deposit_sol_ix(
-                instruction,
-                account_keys,
-                amount,
-            )),
-            StakePoolInstruction::WithdrawSol(amount) => Some(Self::parse_withdraw_sol_ix(
-                instruction,
-                account_keys,
-                amount,
-            )),
             StakePoolInstruction::DecreaseValidatorStakeWithReserve {
                 lamports,
                 transient_stake_seed: _,
@@ -162,6 +314,103 @@ impl SplStakePoolProgram {
                 account_keys,
                 lamports,
             )),
+            StakePoolInstruction::AddValidatorToPool(_) => {
+                let ix = Self::parse_add_validator_to_pool_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::AddValidatorToPool {
+                    stake_account: ix.accounts[5].pubkey,
+                    vote_account: ix.accounts[6].pubkey,
+                    ix,
+                })
+            }
+            StakePoolInstruction::RemoveValidatorFromPool => {
+                let ix = Self::parse_remove_validator_from_pool_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::RemoveValidatorFromPool {
+                    stake_account: ix.accounts[4].pubkey,
+                    ix,
+                })
+            }
+            StakePoolInstruction::IncreaseAdditionalValidatorStake { lamports, .. } => {
+                let ix =
+                    Self::parse_increase_additional_validator_stake_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::IncreaseAdditionalValidatorStake {
+                    amount: lamports_to_sol(lamports),
+                    stake_account: ix.accounts[7].pubkey,
+                    vote_account: ix.accounts[8].pubkey,
+                    ix,
+                })
+            }
+            StakePoolInstruction::DecreaseAdditionalValidatorStake { lamports, .. } => {
+                let ix =
+                    Self::parse_decrease_additional_validator_stake_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::DecreaseAdditionalValidatorStake {
+                    amount: lamports_to_sol(lamports),
+                    stake_account: ix.accounts[5].pubkey,
+                    ix,
+                })
+            }
+            #[allow(deprecated)]
+            StakePoolInstruction::Redelegate { lamports, .. } => {
+                let ix = Self::parse_redelegate_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::Redelegate {
+                    amount: lamports_to_sol(lamports),
+                    source_stake_account: ix.accounts[5].pubkey,
+                    destination_stake_account: ix.accounts[9].pubkey,
+                    destination_vote_account: ix.accounts[10].pubkey,
+                    ix,
+                })
+            }
+            StakePoolInstruction::SetManager => {
+                let ix = Self::parse_set_manager_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::SetManager { ix })
+            }
+            StakePoolInstruction::SetFee { fee } => {
+                let ix = Self::parse_set_fee_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::SetFee { ix, fee })
+            }
+            StakePoolInstruction::SetStaker => {
+                let ix = Self::parse_set_staker_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::SetStaker { ix })
+            }
+            StakePoolInstruction::SetFundingAuthority(funding_type) => {
+                let ix = Self::parse_set_funding_authority_ix(instruction, account_keys);
+                // Without the third account the authority is unset
+                let new_authority =
+                    (instruction.accounts().len() > 2).then(|| ix.accounts[2].pubkey);
+                Some(SplStakePoolProgram::SetFundingAuthority {
+                    ix,
+                    funding_type,
+                    new_authority,
+                })
+            }
+            StakePoolInstruction::SetPreferredValidator {
+                validator_type,
+                validator_vote_address,
+            } => {
+                let ix = Self::parse_set_preferred_validator_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::SetPreferredValidator {
+                    ix,
+                    validator_type,
+                    validator_vote_address,
+                })
+            }
+            StakePoolInstruction::CreateTokenMetadata { name, symbol, uri } => {
+                let ix = Self::parse_create_token_metadata_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::CreateTokenMetadata {
+                    ix,
+                    name,
+                    symbol,
+                    uri,
+                })
+            }
+            StakePoolInstruction::UpdateTokenMetadata { name, symbol, uri } => {
+                let ix = Self::parse_update_token_metadata_ix(instruction, account_keys);
+                Some(SplStakePoolProgram::UpdateTokenMetadata {
+                    ix,
+                    name,
+                    symbol,
+                    uri,
+                })
+            }
             _ => None,
         }
     }
@@ -227,7 +476,7 @@ impl SplStakePoolProgram {
         }
     }
 
-    /// Parse Deposit Stake Instruction
+    /// Parse Deposit Stake Instruction, `DepositStake` and `DepositStakeWithSlippage`
     /// https://github.com/solana-labs/solana-program-library/blob/b7dd8fee93815b486fce98d3d43d1d0934980226/stake-pool/program/src/instruction.rs#L271-L289
     ///
     ///   0. `[w]` Stake pool
@@ -252,7 +501,7 @@ impl SplStakePoolProgram {
     fn parse_deposit_stake_ix<T: ParsableInstruction>(
         instruction: &T,
         account_keys: &[Pubkey],
-    ) -> Self {
+    ) -> Instruction {
         let mut account_metas = [
             AccountMeta::new(Pubkey::new_unique(), false),
             AccountMeta::new(Pubkey::new_unique(), false),
@@ -279,16 +528,14 @@ impl SplStakePoolProgram {
             }
         }
 
-        let ix = Instruction {
+        Instruction {
             program_id: SplStakePoolProgram::program_id(),
             accounts: account_metas.to_vec(),
             data: instruction.data().to_vec(),
-        };
-
-        SplStakePoolProgram::DepositStake { ix }
+        }
     }
 
-    /// Parse Withdraw Stake Instruction
+    /// Parse Withdraw Stake Instruction, `WithdrawStake` and `WithdrawStakeWithSlippage`
     /// https://github.com/solana-labs/solana-program-library/blob/b7dd8fee93815b486fce98d3d43d1d0934980226/stake-pool/program/src/instruction.rs#L313C1-L325C36
     ///
     ///   0. `[w]` Stake pool
@@ -307,8 +554,7 @@ impl SplStakePoolProgram {
     fn parse_withdraw_stake_ix<T: ParsableInstruction>(
         instruction: &T,
         account_keys: &[Pubkey],
-        minimum_lamports_out: u64,
-    ) -> SplStakePoolProgram {
+    ) -> Instruction {
         let mut account_metas = [
             AccountMeta::new(Pubkey::new_unique(), false),
             AccountMeta::new(Pubkey::new_unique(), false),
@@ -333,19 +579,14 @@ impl SplStakePoolProgram {
             }
         }
 
-        let ix = Instruction {
+        Instruction {
             program_id: SplStakePoolProgram::program_id(),
             accounts: account_metas.to_vec(),
             data: instruction.data().to_vec(),
-        };
-
-        SplStakePoolProgram::WithdrawStake {
-            ix,
-            minimum_lamports_out: lamports_to_sol(minimum_lamports_out),
         }
     }
 
-    /// Parse Deposit SOL Instruction
+    /// Parse Deposit SOL Instruction, `DepositSol` and `DepositSolWithSlippage`
     /// https://github.com/solana-labs/solana-program-library/blob/b7dd8fee93815b486fce98d3d43d1d0934980226/stake-pool/program/src/instruction.rs#L357C1-L367C64
     ///
     ///   0. `[w]` Stake pool
@@ -362,8 +603,7 @@ impl SplStakePoolProgram {
     fn parse_deposit_sol_ix<T: ParsableInstruction>(
         instruction: &T,
         account_keys: &[Pubkey],
-        amount: u64,
-    ) -> SplStakePoolProgram {
+    ) -> Instruction {
         let mut account_metas = [
             AccountMeta::new(Pubkey::new_unique(), false),
             AccountMeta::new_readonly(Pubkey::new_unique(), false),
@@ -386,19 +626,14 @@ impl SplStakePoolProgram {
             }
         }
 
-        let ix = Instruction {
+        Instruction {
             program_id: SplStakePoolProgram::program_id(),
             accounts: account_metas.to_vec(),
             data: instruction.data().to_vec(),
-        };
-
-        SplStakePoolProgram::DepositSol {
-            ix,
-            amount: lamports_to_sol(amount),
         }
     }
 
-    /// Parse Withdraw SOL Instruction
+    /// Parse Withdraw SOL Instruction, `WithdrawSol` and `WithdrawSolWithSlippage`
     /// https://github.com/solana-labs/solana-program-library/blob/b7dd8fee93815b486fce98d3d43d1d0934980226/stake-pool/program/src/instruction.rs#L381C1-L394C64
     ///
     ///   0. `[w]` Stake pool
@@ -418,8 +653,7 @@ impl SplStakePoolProgram {
     fn parse_withdraw_sol_ix<T: ParsableInstruction>(
         instruction: &T,
         account_keys: &[Pubkey],
-        amount: u64,
-    ) -> SplStakePoolProgram {
+    ) -> Instruction {
         let mut account_metas = [
             AccountMeta::new(Pubkey::new_unique(), false),
             AccountMeta::new_readonly(Pubkey::new_unique(), false),
@@ -444,15 +678,10 @@ impl SplStakePoolProgram {
             }
         }
 
-        let ix = Instruction {
+        Instruction {
             program_id: SplStakePoolProgram::program_id(),
             accounts: account_metas.to_vec(),
             data: instruction.data().to_vec(),
-        };
-
-        SplStakePoolProgram::WithdrawSol {
-            ix,
-            amount: lamports_to_sol(amount),
         }
     }
 
@@ -508,6 +737,352 @@ impl SplStakePoolProgram {
             amount: lamports_to_sol(lamports),
         }
     }
+    /// Parse Add Validator To Pool Instruction
+    ///
+    ///  0. `[w]` Stake pool
+    ///  1. `[s]` Staker
+    ///  2. `[w]` Reserve stake account
+    ///  3. `[]` Stake pool withdraw authority
+    ///  4. `[w]` Validator stake list storage account
+    ///  5. `[w]` Stake account to add to the pool
+    ///  6. `[]` Validator this stake account will be delegated to
+    ///  7. `[]` Rent sysvar
+    ///  8. `[]` Clock sysvar
+    ///  9. `[]` Stake history sysvar
+    /// 10. `[]` Stake config sysvar
+    /// 11. `[]` System program
+    /// 12. `[]` Stake program
+    fn parse_add_validator_to_pool_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Parse Remove Validator From Pool Instruction
+    ///
+    ///  0. `[w]` Stake pool
+    ///  1. `[s]` Staker
+    ///  2. `[]` Stake pool withdraw authority
+    ///  3. `[w]` Validator stake list storage account
+    ///  4. `[w]` Stake account to remove from the pool
+    ///  5. `[w]` Transient stake account, to deactivate if necessary
+    ///  6. `[]` Sysvar clock
+    ///  7. `[]` Stake program id
+    fn parse_remove_validator_from_pool_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Parse Increase Additional Validator Stake Instruction
+    ///
+    ///  0. `[]` Stake pool
+    ///  1. `[s]` Stake pool staker
+    ///  2. `[]` Stake pool withdraw authority
+    ///  3. `[w]` Validator list
+    ///  4. `[w]` Stake pool reserve stake
+    ///  5. `[w]` Uninitialized ephemeral stake account to receive stake
+    ///  6. `[w]` Transient stake account
+    ///  7. `[]` Validator stake account
+    ///  8. `[]` Validator vote account to delegate to
+    ///  9. `[]` Clock sysvar
+    /// 10. `[]` Stake History sysvar
+    /// 11. `[]` Stake Config sysvar
+    /// 12. `[]` System program
+    /// 13. `[]` Stake program
+    fn parse_increase_additional_validator_stake_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Parse Decrease Additional Validator Stake Instruction
+    ///
+    ///  0. `[]` Stake pool
+    ///  1. `[s]` Stake pool staker
+    ///  2. `[]` Stake pool withdraw authority
+    ///  3. `[w]` Validator list
+    ///  4. `[w]` Reserve stake account, to fund rent exempt reserve
+    ///  5. `[w]` Canonical stake account to split from
+    ///  6. `[w]` Uninitialized ephemeral stake account to receive stake
+    ///  7. `[w]` Transient stake account
+    ///  8. `[]` Clock sysvar
+    ///  9. `[]` Stake history sysvar
+    /// 10. `[]` System program
+    /// 11. `[]` Stake program
+    fn parse_decrease_additional_validator_stake_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Parse Redelegate Instruction, deprecated since the stake program never enabled it
+    ///
+    ///  0. `[]` Stake pool
+    ///  1. `[s]` Stake pool staker
+    ///  2. `[]` Stake pool withdraw authority
+    ///  3. `[w]` Validator list
+    ///  4. `[w]` Reserve stake account, to withdraw rent exempt reserve
+    ///  5. `[w]` Source canonical stake account to split from
+    ///  6. `[w]` Source transient stake account to receive split and be redelegated
+    ///  7. `[w]` Uninitialized ephemeral stake account to receive redelegation
+    ///  8. `[w]` Destination transient stake account to receive ephemeral stake by merge
+    ///  9. `[]` Destination stake account to receive transient stake after activation
+    /// 10. `[]` Destination validator vote account
+    /// 11. `[]` Clock sysvar
+    /// 12. `[]` Stake History sysvar
+    /// 13. `[]` Stake Config sysvar
+    /// 14. `[]` System program
+    /// 15. `[]` Stake program
+    fn parse_redelegate_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Parse Set Manager Instruction
+    ///
+    ///  0. `[w]` StakePool
+    ///  1. `[s]` Manager
+    ///  2. `[s]` New manager
+    ///  3. `[]` New manager fee account
+    fn parse_set_manager_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Parse Set Fee Instruction
+    ///
+    ///  0. `[w]` StakePool
+    ///  1. `[s]` Manager
+    fn parse_set_fee_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Parse Set Staker Instruction
+    ///
+    ///  0. `[w]` StakePool
+    ///  1. `[s]` Manager or current staker
+    ///  2. `[]` New staker pubkey
+    fn parse_set_staker_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Parse Set Funding Authority Instruction
+    ///
+    ///  0. `[w]` StakePool
+    ///  1. `[s]` Manager
+    ///  2. `[]` New authority pubkey or none
+    fn parse_set_funding_authority_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Parse Set Preferred Validator Instruction
+    ///
+    ///  0. `[w]` Stake pool
+    ///  1. `[s]` Stake pool staker
+    ///  2. `[]` Validator list
+    fn parse_set_preferred_validator_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Parse Create Token Metadata Instruction
+    ///
+    ///  0. `[]` Stake pool
+    ///  1. `[s]` Manager
+    ///  2. `[]` Stake pool withdraw authority
+    ///  3. `[]` Pool token mint account
+    ///  4. `[s, w]` Payer for creation of token metadata account
+    ///  5. `[w]` Token metadata account
+    ///  6. `[]` Metadata program id
+    ///  7. `[]` System program id
+    fn parse_create_token_metadata_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), true),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Parse Update Token Metadata Instruction
+    ///
+    ///  0. `[]` Stake pool
+    ///  1. `[s]` Manager
+    ///  2. `[]` Stake pool withdraw authority
+    ///  3. `[w]` Token metadata account
+    ///  4. `[]` Metadata program id
+    fn parse_update_token_metadata_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Resolve `account_metas` from the instruction's account indexes
+    fn build_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+        mut account_metas: Vec<AccountMeta>,
+    ) -> Instruction {
+        for (index, account) in instruction.accounts().iter().enumerate() {
+            if let Some(account_meta) = account_metas.get_mut(index) {
+                if let Some(account) = account_keys.get(*account as usize) {
+                    account_meta.pubkey = *account;
+                }
+            }
+        }
+
+        Instruction {
+            program_id: SplStakePoolProgram::program_id(),
+            accounts: account_metas,
+            data: instruction.data().to_vec(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -517,6 +1092,13 @@ mod tests {
     };
     use yellowstone_grpc_proto::prelude::CompiledInstruction;
 
+    use spl_stake_pool::{
+        instruction::{
+            withdraw_stake_with_slippage, FundingType, PreferredValidatorType, StakePoolInstruction,
+        },
+        state::{Fee, FeeType},
+    };
+
     use crate::ix_parser::stake_pool::SplStakePoolProgram;
 
     fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
@@ -704,4 +1286,349 @@ mod tests {
             panic!("Expected DecreaseValidatorStakeWithReserve variant");
         }
     }
+
+    #[test]
+    fn test_parse_deposit_stake_with_slippage() {
+        let ix_number = 23;
+        let num_account = 15;
+        let minimum_pool_tokens_out: u64 = 4_000_000_000;
+
+        let account_keys = create_test_pubkeys(num_account);
+
+        let mut data = vec![ix_number];
+        data.extend_from_slice(&minimum_pool_tokens_out.to_le_bytes());
+
+        let accounts = (0..num_account).map(|i| i as u8).collect();
+
+        let instruction = create_compiled_instruction(1, accounts, data);
+
+        let parsed = SplStakePoolProgram::parse_spl_stake_pool_program(&instruction, &account_keys);
+
+        if let Some(SplStakePoolProgram::DepositStakeWithSlippage {
+            ix,
+            minimum_pool_tokens_out: minimum_out,
+        }) = parsed
+        {
+            assert_eq!(minimum_out, lamports_to_sol(minimum_pool_tokens_out));
+            assert_eq!(ix.accounts[10].pubkey, account_keys[10]);
+        } else {
+            panic!("Expected DepositStakeWithSlippage variant");
+        }
+    }
+
+    /// Parse a `WithdrawStakeWithSlippage` built by the SPL Stake Pool instruction builder
+    fn parse_withdraw_stake_with_slippage(
+        account_keys: &[Pubkey],
+        pool_tokens_in: u64,
+        minimum_lamports_out: u64,
+    ) -> SplStakePoolProgram {
+        let ix = withdraw_stake_with_slippage(
+            &SplStakePoolProgram::program_id(),
+            &account_keys[0],
+            &account_keys[1],
+            &account_keys[2],
+            &account_keys[3],
+            &account_keys[4],
+            &account_keys[5],
+            &account_keys[6],
+            &account_keys[7],
+            &account_keys[8],
+            &account_keys[9],
+            &account_keys[10],
+            pool_tokens_in,
+            minimum_lamports_out,
+        );
+        // Sysvars and the stake program are appended by the builder
+        let ix_account_keys: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+        let accounts = (0..ix_account_keys.len() as u8).collect();
+
+        let instruction = create_compiled_instruction(11, accounts, ix.data);
+        SplStakePoolProgram::parse_spl_stake_pool_program(&instruction, &ix_account_keys)
+            .expect("WithdrawStakeWithSlippage should parse")
+    }
+
+    #[test]
+    fn test_withdrawn_pool_tokens_of_withdraw_stake_with_slippage() {
+        let account_keys = create_test_pubkeys(11);
+        let pool_tokens_in: u64 = 250_000_000_000_000;
+        let parsed = parse_withdraw_stake_with_slippage(&account_keys, pool_tokens_in, 0);
+
+        assert_eq!(
+            parsed.withdrawn_pool_tokens(),
+            Some(lamports_to_sol(pool_tokens_in))
+        );
+        if let SplStakePoolProgram::WithdrawStakeWithSlippage {
+            ix,
+            minimum_lamports_out,
+            ..
+        } = parsed
+        {
+            assert_eq!(minimum_lamports_out, 0.0);
+            assert_eq!(ix.accounts[9].pubkey, account_keys[9]);
+        } else {
+            panic!("Expected WithdrawStakeWithSlippage variant");
+        }
+    }
+
+    #[test]
+    fn test_withdraw_stake_with_slippage_minimum_out_text() {
+        let account_keys = create_test_pubkeys(11);
+        let parsed =
+            parse_withdraw_stake_with_slippage(&account_keys, 5_000_000_000, 5_500_000_000);
+
+        assert_eq!(
+            parsed.minimum_out_text().as_deref(),
+            Some("Minimum out: 5.50 SOL")
+        );
+    }
+
+    #[test]
+    fn test_parse_withdraw_stake_with_slippage() {
+        let ix_number = 24;
+        let num_account = 13;
+        let pool_tokens_in: u64 = 5_000_000_000;
+        let minimum_lamports_out: u64 = 5_500_000_000;
+
+        let account_keys = create_test_pubkeys(num_account);
+
+        let mut data = vec![ix_number];
+        data.extend_from_slice(&pool_tokens_in.to_le_bytes());
+        data.extend_from_slice(&minimum_lamports_out.to_le_bytes());
+
+        let accounts = (0..num_account).map(|i| i as u8).collect();
+
+        let instruction = create_compiled_instruction(1, accounts, data);
+
+        let parsed = SplStakePoolProgram::parse_spl_stake_pool_program(&instruction, &account_keys);
+
+        if let Some(SplStakePoolProgram::WithdrawStakeWithSlippage {
+            ix,
+            pool_tokens_in: tokens_in,
+            minimum_lamports_out: minimum_out,
+        }) = parsed
+        {
+            assert_eq!(tokens_in, lamports_to_sol(pool_tokens_in));
+            assert_eq!(minimum_out, lamports_to_sol(minimum_lamports_out));
+            assert_eq!(ix.accounts[9].pubkey, account_keys[9]);
+        } else {
+            panic!("Expected WithdrawStakeWithSlippage variant");
+        }
+    }
+
+    #[test]
+    fn test_parse_deposit_sol_with_slippage() {
+        let ix_number = 25;
+        let num_account = 10;
+        let lamports_in: u64 = 5_000_000_000;
+        let minimum_pool_tokens_out: u64 = 4_000_000_000;
+
+        let account_keys = create_test_pubkeys(num_account);
+
+        let mut data = vec![ix_number];
+        data.extend_from_slice(&lamports_in.to_le_bytes());
+        data.extend_from_slice(&minimum_pool_tokens_out.to_le_bytes());
+
+        let accounts = (0..num_account).map(|i| i as u8).collect();
+
+        let instruction = create_compiled_instruction(1, accounts, data);
+
+        let parsed = SplStakePoolProgram::parse_spl_stake_pool_program(&instruction, &account_keys);
+
+        if let Some(SplStakePoolProgram::DepositSolWithSlippage {
+            ix,
+            amount,
+            minimum_pool_tokens_out: minimum_out,
+        }) = parsed
+        {
+            assert_eq!(amount, lamports_to_sol(lamports_in));
+            assert_eq!(minimum_out, lamports_to_sol(minimum_pool_tokens_out));
+            assert_eq!(ix.accounts[7].pubkey, account_keys[7]);
+        } else {
+            panic!("Expected DepositSolWithSlippage variant");
+        }
+    }
+
+    #[test]
+    fn test_parse_withdraw_sol_with_slippage() {
+        let ix_number = 26;
+        let num_account = 12;
+        let pool_tokens_in: u64 = 5_000_000_000;
+        let minimum_lamports_out: u64 = 5_500_000_000;
+
+        let account_keys = create_test_pubkeys(num_account);
+
+        let mut data = vec![ix_number];
+        data.extend_from_slice(&pool_tokens_in.to_le_bytes());
+        data.extend_from_slice(&minimum_lamports_out.to_le_bytes());
+
+        let accounts = (0..num_account).map(|i| i as u8).collect();
+
+        let instruction = create_compiled_instruction(1, accounts, data);
+
+        let parsed = SplStakePoolProgram::parse_spl_stake_pool_program(&instruction, &account_keys);
+
+        if let Some(SplStakePoolProgram::WithdrawSolWithSlippage {
+            ix,
+            amount,
+            minimum_lamports_out: minimum_out,
+        }) = parsed
+        {
+            assert_eq!(amount, lamports_to_sol(pool_tokens_in));
+            assert_eq!(minimum_out, lamports_to_sol(minimum_lamports_out));
+            assert_eq!(ix.accounts[7].pubkey, account_keys[7]);
+        } else {
+            panic!("Expected WithdrawSolWithSlippage variant");
+        }
+    }
+
+    #[test]
+    fn test_parse_set_fee() {
+        let fee = FeeType::Epoch(Fee {
+            denominator: 1_000,
+            numerator: 5,
+        });
+        let data = borsh1::to_vec(&StakePoolInstruction::SetFee { fee: fee.clone() }).unwrap();
+        let account_keys = create_test_pubkeys(2);
+
+        let instruction = create_compiled_instruction(1, vec![0, 1], data);
+
+        let parsed = SplStakePoolProgram::parse_spl_stake_pool_program(&instruction, &account_keys);
+
+        if let Some(SplStakePoolProgram::SetFee {
+            ix,
+            fee: parsed_fee,
+        }) = parsed
+        {
+            assert_eq!(parsed_fee, fee);
+            assert_eq!(ix.accounts[0].pubkey, account_keys[0]);
+        } else {
+            panic!("Expected SetFee variant");
+        }
+    }
+
+    #[test]
+    fn test_parse_set_funding_authority_unset() {
+        let data = borsh1::to_vec(&StakePoolInstruction::SetFundingAuthority(
+            FundingType::SolDeposit,
+        ))
+        .unwrap();
+        let account_keys = create_test_pubkeys(2);
+
+        let instruction = create_compiled_instruction(1, vec![0, 1], data);
+
+        let parsed = SplStakePoolProgram::parse_spl_stake_pool_program(&instruction, &account_keys);
+
+        if let Some(SplStakePoolProgram::SetFundingAuthority {
+            funding_type,
+            new_authority,
+            ..
+        }) = parsed
+        {
+            assert_eq!(funding_type, FundingType::SolDeposit);
+            assert_eq!(new_authority, None);
+        } else {
+            panic!("Expected SetFundingAuthority variant");
+        }
+    }
+
+    #[test]
+    fn test_parse_set_preferred_validator() {
+        let vote = Pubkey::new_unique();
+        let data = borsh1::to_vec(&StakePoolInstruction::SetPreferredValidator {
+            validator_type: PreferredValidatorType::Deposit,
+            validator_vote_address: Some(vote),
+        })
+        .unwrap();
+        let account_keys = create_test_pubkeys(3);
+
+        let instruction = create_compiled_instruction(1, vec![0, 1, 2], data);
+
+        let parsed = SplStakePoolProgram::parse_spl_stake_pool_program(&instruction, &account_keys);
+
+        if let Some(SplStakePoolProgram::SetPreferredValidator {
+            validator_type,
+            validator_vote_address,
+            ..
+        }) = parsed
+        {
+            assert_eq!(validator_type, PreferredValidatorType::Deposit);
+            assert_eq!(validator_vote_address, Some(vote));
+        } else {
+            panic!("Expected SetPreferredValidator variant");
+        }
+    }
+
+    #[test]
+    fn test_parse_update_token_metadata() {
+        let data = borsh1::to_vec(&StakePoolInstruction::UpdateTokenMetadata {
+            name: "Jito Staked SOL".to_string(),
+            symbol: "JitoSOL".to_string(),
+            uri: "https://example.com/jitosol.json".to_string(),
+        })
+        .unwrap();
+        let account_keys = create_test_pubkeys(5);
+
+        let instruction = create_compiled_instruction(1, vec![0, 1, 2, 3, 4], data);
+
+        let parsed = SplStakePoolProgram::parse_spl_stake_pool_program(&instruction, &account_keys);
+
+        if let Some(SplStakePoolProgram::UpdateTokenMetadata { ix, symbol, .. }) = parsed {
+            assert_eq!(symbol, "JitoSOL");
+            assert_eq!(ix.accounts[3].pubkey, account_keys[3]);
+        } else {
+            panic!("Expected UpdateTokenMetadata variant");
+        }
+    }
+
+    #[test]
+    fn test_parse_add_validator_to_pool() {
+        let data = borsh1::to_vec(&StakePoolInstruction::AddValidatorToPool(0)).unwrap();
+        let account_keys = create_test_pubkeys(13);
+        let accounts = (0..13).collect();
+
+        let instruction = create_compiled_instruction(1, accounts, data);
+
+        let parsed = SplStakePoolProgram::parse_spl_stake_pool_program(&instruction, &account_keys);
+
+        if let Some(SplStakePoolProgram::AddValidatorToPool {
+            stake_account,
+            vote_account,
+            ..
+        }) = parsed
+        {
+            assert_eq!(stake_account, account_keys[5]);
+            assert_eq!(vote_account, account_keys[6]);
+        } else {
+            panic!("Expected AddValidatorToPool variant");
+        }
+    }
+
+    #[test]
+    fn test_parse_decrease_additional_validator_stake() {
+        let lamports: u64 = 7_000_000_000;
+        let data = borsh1::to_vec(&StakePoolInstruction::DecreaseAdditionalValidatorStake {
+            lamports,
+            transient_stake_seed: 1,
+            ephemeral_stake_seed: 2,
+        })
+        .unwrap();
+        let account_keys = create_test_pubkeys(12);
+        let accounts = (0..12).collect();
+
+        let instruction = create_compiled_instruction(1, accounts, data);
+
+        let parsed = SplStakePoolProgram::parse_spl_stake_pool_program(&instruction, &account_keys);
+
+        if let Some(SplStakePoolProgram::DecreaseAdditionalValidatorStake {
+            amount,
+            stake_account,
+            ..
+        }) = parsed
+        {
+            assert_eq!(amount, lamports_to_sol(lamports));
+            assert_eq!(stake_account, account_keys[5]);
+        } else {
+            panic!("Expected DecreaseAdditionalValidatorStake variant");
+        }
+    }
 }
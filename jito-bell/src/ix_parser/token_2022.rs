@@ -2,20 +2,87 @@ use solana_sdk::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
 };
-use spl_token_2022::instruction::TokenInstruction;
+use spl_token_2022::{
+    extension::transfer_fee::instruction::TransferFeeInstruction, instruction::TokenInstruction,
+};
 
 use super::instruction::ParsableInstruction;
 
-/// SPL Stake Pool Program
+/// SPL Token 2022 Program
 #[derive(Debug)]
 pub enum SplToken2022Program {
-    MintTo { ix: Instruction, amount: u64 },
+    MintTo {
+        ix: Instruction,
+        amount: u64,
+    },
+    MintToChecked {
+        ix: Instruction,
+        amount: u64,
+        decimals: u8,
+    },
+    Burn {
+        ix: Instruction,
+        amount: u64,
+    },
+    BurnChecked {
+        ix: Instruction,
+        amount: u64,
+        decimals: u8,
+    },
+    Transfer {
+        ix: Instruction,
+        amount: u64,
+    },
+    TransferChecked {
+        ix: Instruction,
+        amount: u64,
+        decimals: u8,
+    },
+    TransferCheckedWithFee {
+        ix: Instruction,
+        amount: u64,
+        decimals: u8,
+        fee: u64,
+    },
+    WithdrawWithheldTokensFromMint {
+        ix: Instruction,
+    },
+    WithdrawWithheldTokensFromAccounts {
+        ix: Instruction,
+        num_token_accounts: u8,
+    },
+    HarvestWithheldTokensToMint {
+        ix: Instruction,
+    },
+    SetTransferFee {
+        ix: Instruction,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    },
 }
 
 impl std::fmt::Display for SplToken2022Program {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            SplToken2022Program::MintTo { ix: _, amount: _ } => write!(f, "mint_to"),
+            SplToken2022Program::MintTo { .. } => write!(f, "mint_to"),
+            SplToken2022Program::MintToChecked { .. } => write!(f, "mint_to_checked"),
+            SplToken2022Program::Burn { .. } => write!(f, "burn"),
+            SplToken2022Program::BurnChecked { .. } => write!(f, "burn_checked"),
+            SplToken2022Program::Transfer { .. } => write!(f, "transfer"),
+            SplToken2022Program::TransferChecked { .. } => write!(f, "transfer_checked"),
+            SplToken2022Program::TransferCheckedWithFee { .. } => {
+                write!(f, "transfer_checked_with_fee")
+            }
+            SplToken2022Program::WithdrawWithheldTokensFromMint { .. } => {
+                write!(f, "withdraw_withheld_tokens_from_mint")
+            }
+            SplToken2022Program::WithdrawWithheldTokensFromAccounts { .. } => {
+                write!(f, "withdraw_withheld_tokens_from_accounts")
+            }
+            SplToken2022Program::HarvestWithheldTokensToMint { .. } => {
+                write!(f, "harvest_withheld_tokens_to_mint")
+            }
+            SplToken2022Program::SetTransferFee { .. } => write!(f, "set_transfer_fee"),
         }
     }
 }
@@ -26,6 +93,27 @@ impl SplToken2022Program {
         spl_token_2022::id()
     }
 
+    /// Mint the instruction moves tokens of, if named in its accounts
+    ///
+    /// - `Transfer` only names token accounts, the mint must be read from the source account
+    pub fn mint(&self) -> Option<&Pubkey> {
+        let (ix, index) = match self {
+            SplToken2022Program::MintTo { ix, .. }
+            | SplToken2022Program::MintToChecked { ix, .. }
+            | SplToken2022Program::WithdrawWithheldTokensFromMint { ix }
+            | SplToken2022Program::WithdrawWithheldTokensFromAccounts { ix, .. }
+            | SplToken2022Program::HarvestWithheldTokensToMint { ix }
+            | SplToken2022Program::SetTransferFee { ix, .. } => (ix, 0),
+            SplToken2022Program::Burn { ix, .. }
+            | SplToken2022Program::BurnChecked { ix, .. }
+            | SplToken2022Program::TransferChecked { ix, .. }
+            | SplToken2022Program::TransferCheckedWithFee { ix, .. } => (ix, 1),
+            SplToken2022Program::Transfer { .. } => return None,
+        };
+
+        ix.accounts.get(index).map(|account| &account.pubkey)
+    }
+
     /// Parse SPL Token 2022 program
     pub fn parse_spl_token_2022_program<T: ParsableInstruction>(
         instruction: &T,
@@ -38,14 +126,101 @@ impl SplToken2022Program {
 
         match token_ix {
             TokenInstruction::MintTo { amount } => {
-                Some(Self::parse_mint_to_ix(instruction, account_keys, amount))
+                let ix = Self::parse_mint_to_ix(instruction, account_keys);
+                Some(SplToken2022Program::MintTo { ix, amount })
+            }
+            TokenInstruction::MintToChecked { amount, decimals } => {
+                let ix = Self::parse_mint_to_ix(instruction, account_keys);
+                Some(SplToken2022Program::MintToChecked {
+                    ix,
+                    amount,
+                    decimals,
+                })
+            }
+            TokenInstruction::Burn { amount } => {
+                let ix = Self::parse_burn_ix(instruction, account_keys);
+                Some(SplToken2022Program::Burn { ix, amount })
+            }
+            TokenInstruction::BurnChecked { amount, decimals } => {
+                let ix = Self::parse_burn_ix(instruction, account_keys);
+                Some(SplToken2022Program::BurnChecked {
+                    ix,
+                    amount,
+                    decimals,
+                })
+            }
+            #[allow(deprecated)]
+            TokenInstruction::Transfer { amount } => {
+                let ix = Self::parse_transfer_ix(instruction, account_keys);
+                Some(SplToken2022Program::Transfer { ix, amount })
+            }
+            TokenInstruction::TransferChecked { amount, decimals } => {
+                let ix = Self::parse_transfer_checked_ix(instruction, account_keys);
+                Some(SplToken2022Program::TransferChecked {
+                    ix,
+                    amount,
+                    decimals,
+                })
+            }
+            TokenInstruction::TransferFeeExtension => {
+                Self::parse_transfer_fee_extension(instruction, account_keys)
             }
             _ => None,
         }
     }
 
-    /// Mints new tokens to an account.  The native mint does not support
-    /// minting.
+    /// Parse a transfer fee extension instruction, tagged by the byte after the token instruction
+    fn parse_transfer_fee_extension<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<SplToken2022Program> {
+        let fee_ix = TransferFeeInstruction::unpack(instruction.data().get(1..)?).ok()?;
+
+        match fee_ix {
+            TransferFeeInstruction::TransferCheckedWithFee {
+                amount,
+                decimals,
+                fee,
+            } => {
+                let ix = Self::parse_transfer_checked_ix(instruction, account_keys);
+                Some(SplToken2022Program::TransferCheckedWithFee {
+                    ix,
+                    amount,
+                    decimals,
+                    fee,
+                })
+            }
+            TransferFeeInstruction::WithdrawWithheldTokensFromMint => {
+                let ix = Self::parse_withdraw_withheld_tokens_ix(instruction, account_keys);
+                Some(SplToken2022Program::WithdrawWithheldTokensFromMint { ix })
+            }
+            TransferFeeInstruction::WithdrawWithheldTokensFromAccounts { num_token_accounts } => {
+                let ix = Self::parse_withdraw_withheld_tokens_ix(instruction, account_keys);
+                Some(SplToken2022Program::WithdrawWithheldTokensFromAccounts {
+                    ix,
+                    num_token_accounts,
+                })
+            }
+            TransferFeeInstruction::HarvestWithheldTokensToMint => {
+                let ix = Self::parse_harvest_withheld_tokens_to_mint_ix(instruction, account_keys);
+                Some(SplToken2022Program::HarvestWithheldTokensToMint { ix })
+            }
+            TransferFeeInstruction::SetTransferFee {
+                transfer_fee_basis_points,
+                maximum_fee,
+            } => {
+                let ix = Self::parse_set_transfer_fee_ix(instruction, account_keys);
+                Some(SplToken2022Program::SetTransferFee {
+                    ix,
+                    transfer_fee_basis_points,
+                    maximum_fee,
+                })
+            }
+            TransferFeeInstruction::InitializeTransferFeeConfig { .. } => None,
+        }
+    }
+
+    /// Mints new tokens to an account, `MintTo` and `MintToChecked`
     ///
     /// Accounts expected by this instruction:
     ///
@@ -59,34 +234,196 @@ impl SplToken2022Program {
     ///   1. `[writable]` The account to mint tokens to.
     ///   2. `[]` The mint's multisignature mint-tokens authority.
     ///   3. ..3+M `[signer]` M signer accounts.
-    pub fn parse_mint_to_ix<T: ParsableInstruction>(
+    fn parse_mint_to_ix<T: ParsableInstruction>(
         instruction: &T,
         account_keys: &[Pubkey],
-        amount: u64,
-    ) -> SplToken2022Program {
-        let mut account_metas = [
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Burns tokens by removing them from an account, `Burn` and `BurnChecked`
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The account to burn from.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[signer]` The account's owner/delegate.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The account to burn from.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[]` The account's multisignature owner/delegate.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    fn parse_burn_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Transfers tokens from one account to another
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[signer]` The source account's owner/delegate.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[]` The source account's multisignature owner/delegate.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    fn parse_transfer_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Transfers tokens from one account to another, asserting the mint and decimals,
+    /// `TransferChecked` and `TransferCheckedWithFee`
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[signer]` The source account's owner/delegate.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[]` The source account's multisignature owner/delegate.
+    ///   4. ..4+M `[signer]` M signer accounts.
+    fn parse_transfer_checked_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
             AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
             AccountMeta::new(Pubkey::new_unique(), false),
             AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Transfers withheld transfer fees to an account, `WithdrawWithheldTokensFromMint`
+    /// and `WithdrawWithheldTokensFromAccounts`
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The token mint.
+    ///   1. `[writable]` The fee receiver account.
+    ///   2. `[signer]` The mint's `withdraw_withheld_authority`.
+    ///   3. ..3+N `[writable]` The source accounts to withdraw from.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The token mint.
+    ///   1. `[writable]` The fee receiver account.
+    ///   2. `[]` The mint's multisig `withdraw_withheld_authority`.
+    ///   3. ..3+M `[signer]` M signer accounts, followed by the N source accounts.
+    fn parse_withdraw_withheld_tokens_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
             AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Moves withheld transfer fees from token accounts to the mint
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The mint.
+    ///   1. ..1+N `[writable]` The source accounts to harvest from.
+    fn parse_harvest_withheld_tokens_to_mint_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![AccountMeta::new(Pubkey::new_unique(), false)];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Sets the transfer fee of a mint with the `TransferFeeConfig` extension
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The mint.
+    ///   1. `[signer]` The mint's fee account owner.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The mint.
+    ///   1. `[]` The mint's multisignature fee account owner.
+    ///   2. ..2+M `[signer]` M signer accounts.
+    fn parse_set_transfer_fee_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
             AccountMeta::new_readonly(Pubkey::new_unique(), true),
         ];
 
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Resolve `account_metas` from the instruction's account indexes
+    ///
+    /// - Accounts beyond the named ones (multisig signers, fee sources) are appended as readonly
+    fn build_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+        mut account_metas: Vec<AccountMeta>,
+    ) -> Instruction {
         for (index, account) in instruction.accounts().iter().enumerate() {
-            if let Some(account_meta) = account_metas.get_mut(index) {
-                if let Some(account) = account_keys.get(*account as usize) {
-                    account_meta.pubkey = *account;
-                }
+            let Some(account) = account_keys.get(*account as usize) else {
+                continue;
+            };
+
+            match account_metas.get_mut(index) {
+                Some(account_meta) => account_meta.pubkey = *account,
+                None => account_metas.push(AccountMeta::new_readonly(*account, false)),
             }
         }
 
-        let ix = Instruction {
+        Instruction {
             program_id: Self::program_id(),
-            accounts: account_metas.to_vec(),
+            accounts: account_metas,
             data: instruction.data().to_vec(),
-        };
-
-        SplToken2022Program::MintTo { ix, amount }
+        }
     }
 }
 
@@ -97,6 +434,9 @@ mod tests {
 
     use crate::ix_parser::token_2022::SplToken2022Program;
 
+    /// `TokenInstruction::TransferFeeExtension`
+    const TRANSFER_FEE_EXTENSION: u8 = 26;
+
     fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
         (0..count).map(|_| Keypair::new().pubkey()).collect()
     }
@@ -113,31 +453,158 @@ mod tests {
         }
     }
 
+    fn amount_data(ix_number: u8, amount: u64) -> Vec<u8> {
+        let mut data = vec![ix_number];
+        data.extend_from_slice(&amount.to_le_bytes());
+        data
+    }
+
+    fn checked_amount_data(ix_number: u8, amount: u64, decimals: u8) -> Vec<u8> {
+        let mut data = amount_data(ix_number, amount);
+        data.push(decimals);
+        data
+    }
+
+    fn parse(num_account: usize, data: Vec<u8>) -> (Vec<Pubkey>, SplToken2022Program) {
+        let account_keys = create_test_pubkeys(num_account);
+        let accounts = (0..num_account).map(|i| i as u8).collect();
+        let instruction = create_compiled_instruction(1, accounts, data);
+
+        let parsed = SplToken2022Program::parse_spl_token_2022_program(&instruction, &account_keys)
+            .expect("Expected a parsed instruction");
+
+        (account_keys, parsed)
+    }
+
     #[test]
     fn test_mint_to() {
-        let ix_number = 7;
-        let num_account = 4;
-        let sol: u64 = 5; // 5 SOL
+        let (account_keys, parsed) = parse(3, amount_data(7, 5));
 
-        let account_keys = create_test_pubkeys(num_account);
+        assert_eq!(parsed.mint(), Some(&account_keys[0]));
+        if let SplToken2022Program::MintTo { amount, .. } = parsed {
+            assert_eq!(amount, 5);
+        } else {
+            panic!("Expected MintTo variant");
+        }
+    }
 
-        let mut data = vec![ix_number];
-        data.extend_from_slice(&sol.to_le_bytes());
+    #[test]
+    fn test_mint_to_checked() {
+        let (_, parsed) = parse(3, checked_amount_data(14, 5_000_000_000, 9));
 
-        // Create account indices
-        let accounts = (0..num_account).map(|i| i as u8).collect();
+        if let SplToken2022Program::MintToChecked {
+            amount, decimals, ..
+        } = parsed
+        {
+            assert_eq!(amount, 5_000_000_000);
+            assert_eq!(decimals, 9);
+        } else {
+            panic!("Expected MintToChecked variant");
+        }
+    }
 
-        let instruction = create_compiled_instruction(1, accounts, data);
+    #[test]
+    fn test_burn_checked() {
+        let (account_keys, parsed) = parse(3, checked_amount_data(15, 42, 9));
+
+        assert_eq!(parsed.mint(), Some(&account_keys[1]));
+        if let SplToken2022Program::BurnChecked {
+            amount, decimals, ..
+        } = parsed
+        {
+            assert_eq!(amount, 42);
+            assert_eq!(decimals, 9);
+        } else {
+            panic!("Expected BurnChecked variant");
+        }
+    }
+
+    #[test]
+    fn test_transfer_has_no_mint() {
+        let (_, parsed) = parse(3, amount_data(3, 1_000));
 
-        // Parse the instruction
-        let parsed = SplToken2022Program::parse_spl_token_2022_program(&instruction, &account_keys);
+        assert!(matches!(
+            parsed,
+            SplToken2022Program::Transfer { amount: 1_000, .. }
+        ));
+        assert_eq!(parsed.mint(), None);
+    }
+
+    #[test]
+    fn test_transfer_checked_with_fee() {
+        let mut data = vec![TRANSFER_FEE_EXTENSION];
+        data.extend_from_slice(&checked_amount_data(1, 1_000_000, 9));
+        data.extend_from_slice(&250_u64.to_le_bytes());
 
-        // Validate result
-        assert!(parsed.is_some());
-        if let Some(SplToken2022Program::MintTo { amount, .. }) = parsed {
-            assert_eq!(amount, sol);
+        let (account_keys, parsed) = parse(4, data);
+
+        assert_eq!(parsed.mint(), Some(&account_keys[1]));
+        if let SplToken2022Program::TransferCheckedWithFee {
+            ix,
+            amount,
+            decimals,
+            fee,
+        } = parsed
+        {
+            assert_eq!(amount, 1_000_000);
+            assert_eq!(decimals, 9);
+            assert_eq!(fee, 250);
+            assert_eq!(ix.accounts[2].pubkey, account_keys[2]);
         } else {
-            panic!("Expected MintTo variant");
+            panic!("Expected TransferCheckedWithFee variant");
+        }
+    }
+
+    #[test]
+    fn test_withdraw_withheld_tokens_from_accounts() {
+        let (account_keys, parsed) = parse(5, vec![TRANSFER_FEE_EXTENSION, 3, 2]);
+
+        if let SplToken2022Program::WithdrawWithheldTokensFromAccounts {
+            ix,
+            num_token_accounts,
+        } = parsed
+        {
+            assert_eq!(num_token_accounts, 2);
+            assert_eq!(ix.accounts.len(), 5);
+            assert_eq!(ix.accounts[1].pubkey, account_keys[1]);
+            assert_eq!(ix.accounts[4].pubkey, account_keys[4]);
+        } else {
+            panic!("Expected WithdrawWithheldTokensFromAccounts variant");
         }
     }
+
+    #[test]
+    fn test_set_transfer_fee() {
+        let mut data = vec![TRANSFER_FEE_EXTENSION, 5];
+        data.extend_from_slice(&50_u16.to_le_bytes());
+        data.extend_from_slice(&1_000_000_u64.to_le_bytes());
+
+        let (account_keys, parsed) = parse(2, data);
+
+        assert_eq!(parsed.to_string(), "set_transfer_fee");
+        assert_eq!(parsed.mint(), Some(&account_keys[0]));
+        if let SplToken2022Program::SetTransferFee {
+            transfer_fee_basis_points,
+            maximum_fee,
+            ..
+        } = parsed
+        {
+            assert_eq!(transfer_fee_basis_points, 50);
+            assert_eq!(maximum_fee, 1_000_000);
+        } else {
+            panic!("Expected SetTransferFee variant");
+        }
+    }
+
+    #[test]
+    fn test_unsupported_instruction() {
+        let account_keys = create_test_pubkeys(2);
+        // CloseAccount
+        let instruction = create_compiled_instruction(1, vec![0, 1], vec![9]);
+
+        assert!(
+            SplToken2022Program::parse_spl_token_2022_program(&instruction, &account_keys)
+                .is_none()
+        );
+    }
 }
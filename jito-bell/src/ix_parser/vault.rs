@@ -1,5 +1,5 @@
 use borsh::BorshDeserialize;
-use jito_vault_sdk::instruction::VaultInstruction;
+use jito_vault_sdk::instruction::{VaultAdminRole, VaultInstruction};
 use solana_sdk::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
@@ -13,14 +13,30 @@ pub enum JitoVaultProgram {
     InitializeConfig,
     InitializeVault,
     InitializeVaultWithMint,
-    InitializeVaultOperatorDelegation,
-    InitializeVaultNcnTicket,
-    InitializeVaultNcnSlasherOperatorTicket,
-    InitializeVaultNcnSlasherTicket,
-    WarmupVaultNcnTicket,
-    CooldownVaultNcnTicket,
-    WarmupVaultNcnSlasherTicket,
-    CooldownVaultNcnSlasherTicket,
+    InitializeVaultOperatorDelegation {
+        ix: Instruction,
+    },
+    InitializeVaultNcnTicket {
+        ix: Instruction,
+    },
+    InitializeVaultNcnSlasherOperatorTicket {
+        ix: Instruction,
+    },
+    InitializeVaultNcnSlasherTicket {
+        ix: Instruction,
+    },
+    WarmupVaultNcnTicket {
+        ix: Instruction,
+    },
+    CooldownVaultNcnTicket {
+        ix: Instruction,
+    },
+    WarmupVaultNcnSlasherTicket {
+        ix: Instruction,
+    },
+    CooldownVaultNcnSlasherTicket {
+        ix: Instruction,
+    },
     MintTo {
         ix: Instruction,
         min_amount_out: u64,
@@ -29,25 +45,67 @@ pub enum JitoVaultProgram {
         ix: Instruction,
         amount: u64,
     },
-    ChangeWithdrawalTicketOwner,
-    BurnWithdrawalTicket,
-    SetDepositCapacity,
-    SetFees,
-    SetProgramFee,
-    SetProgramFeeWallet,
-    SetIsPaused,
+    ChangeWithdrawalTicketOwner {
+        ix: Instruction,
+    },
+    BurnWithdrawalTicket {
+        ix: Instruction,
+    },
+    SetDepositCapacity {
+        ix: Instruction,
+        amount: u64,
+    },
+    SetFees {
+        ix: Instruction,
+        deposit_fee_bps: Option<u16>,
+        withdrawal_fee_bps: Option<u16>,
+        reward_fee_bps: Option<u16>,
+    },
+    SetProgramFee {
+        ix: Instruction,
+        new_fee_bps: u16,
+    },
+    SetProgramFeeWallet {
+        ix: Instruction,
+    },
+    SetIsPaused {
+        ix: Instruction,
+        is_paused: bool,
+    },
     DelegateTokenAccount,
-    SetAdmin,
-    SetSecondaryAdmin,
-    AddDelegation,
-    CooldownDelegation,
-    UpdateVaultBalance,
-    InitializeVaultUpdateStateTracker,
-    CrankVaultUpdateStateTracker,
-    CloseVaultUpdateStateTracker,
+    SetAdmin {
+        ix: Instruction,
+    },
+    SetSecondaryAdmin {
+        ix: Instruction,
+        role: VaultAdminRole,
+    },
+    AddDelegation {
+        ix: Instruction,
+        amount: u64,
+    },
+    CooldownDelegation {
+        ix: Instruction,
+        amount: u64,
+    },
+    UpdateVaultBalance {
+        ix: Instruction,
+    },
+    InitializeVaultUpdateStateTracker {
+        ix: Instruction,
+    },
+    CrankVaultUpdateStateTracker {
+        ix: Instruction,
+    },
+    CloseVaultUpdateStateTracker {
+        ix: Instruction,
+        ncn_epoch: u64,
+    },
     CreateTokenMetadata,
     UpdateTokenMetadata,
-    SetConfigAdmin,
+    SetConfigAdmin {
+        ix: Instruction,
+    },
 }
 
 impl std::fmt::Display for JitoVaultProgram {
@@ -56,27 +114,29 @@ impl std::fmt::Display for JitoVaultProgram {
             JitoVaultProgram::InitializeConfig => write!(f, "initialize_config"),
             JitoVaultProgram::InitializeVault => write!(f, "initialize_vault"),
             JitoVaultProgram::InitializeVaultWithMint => write!(f, "initialize_vault_with_mint"),
-            JitoVaultProgram::InitializeVaultOperatorDelegation => {
+            JitoVaultProgram::InitializeVaultOperatorDelegation { .. } => {
                 write!(f, "initialize_vault_operator_delegation")
             }
-            JitoVaultProgram::InitializeVaultNcnTicket => write!(f, "initialize_vault_ncn_ticket"),
-            JitoVaultProgram::InitializeVaultNcnSlasherOperatorTicket => {
+            JitoVaultProgram::InitializeVaultNcnTicket { .. } => {
+                write!(f, "initialize_vault_ncn_ticket")
+            }
+            JitoVaultProgram::InitializeVaultNcnSlasherOperatorTicket { .. } => {
                 write!(f, "initialize_vault_ncn_slasher_operator_ticket")
             }
-            JitoVaultProgram::InitializeVaultNcnSlasherTicket => {
+            JitoVaultProgram::InitializeVaultNcnSlasherTicket { .. } => {
                 write!(f, "initialize_vault_ncn_slasher_ticket")
             }
-            JitoVaultProgram::WarmupVaultNcnTicket => {
+            JitoVaultProgram::WarmupVaultNcnTicket { .. } => {
                 write!(f, "warmup_vault_ncn_ticket")
             }
-            JitoVaultProgram::CooldownVaultNcnTicket => {
+            JitoVaultProgram::CooldownVaultNcnTicket { .. } => {
                 write!(f, "cooldown_vault_ncn_ticket")
             }
 
-            JitoVaultProgram::WarmupVaultNcnSlasherTicket => {
+            JitoVaultProgram::WarmupVaultNcnSlasherTicket { .. } => {
                 write!(f, "warmup_vault_ncn_slasher_ticket")
             }
-            JitoVaultProgram::CooldownVaultNcnSlasherTicket => {
+            JitoVaultProgram::CooldownVaultNcnSlasherTicket { .. } => {
                 write!(f, "cooldown_vault_ncn_slasher_ticket")
             }
             JitoVaultProgram::MintTo {
@@ -88,53 +148,53 @@ impl std::fmt::Display for JitoVaultProgram {
             JitoVaultProgram::EnqueueWithdrawal { ix: _, amount: _ } => {
                 write!(f, "enqueue_withdrawal")
             }
-            JitoVaultProgram::ChangeWithdrawalTicketOwner => {
+            JitoVaultProgram::ChangeWithdrawalTicketOwner { .. } => {
                 write!(f, "change_withdrawal_ticket_owner")
             }
-            JitoVaultProgram::BurnWithdrawalTicket => {
+            JitoVaultProgram::BurnWithdrawalTicket { .. } => {
                 write!(f, "burn_withdrawal_ticket")
             }
-            JitoVaultProgram::SetDepositCapacity => {
+            JitoVaultProgram::SetDepositCapacity { .. } => {
                 write!(f, "set_deposit_capacity")
             }
-            JitoVaultProgram::SetFees => {
+            JitoVaultProgram::SetFees { .. } => {
                 write!(f, "set_fees")
             }
-            JitoVaultProgram::SetProgramFee => {
+            JitoVaultProgram::SetProgramFee { .. } => {
                 write!(f, "set_program_fee")
             }
-            JitoVaultProgram::SetProgramFeeWallet => {
+            JitoVaultProgram::SetProgramFeeWallet { .. } => {
                 write!(f, "set_program_fee_wallet")
             }
-            JitoVaultProgram::SetIsPaused => {
+            JitoVaultProgram::SetIsPaused { .. } => {
                 write!(f, "set_is_paused")
             }
             JitoVaultProgram::DelegateTokenAccount => {
                 write!(f, "delegate_token_account")
             }
-            JitoVaultProgram::SetAdmin => {
+            JitoVaultProgram::SetAdmin { .. } => {
                 write!(f, "set_admin")
             }
-            JitoVaultProgram::SetSecondaryAdmin => {
+            JitoVaultProgram::SetSecondaryAdmin { .. } => {
                 write!(f, "set_secondary_admin")
             }
-            JitoVaultProgram::AddDelegation => {
+            JitoVaultProgram::AddDelegation { .. } => {
                 write!(f, "add_delegation")
             }
-            JitoVaultProgram::CooldownDelegation => {
+            JitoVaultProgram::CooldownDelegation { .. } => {
                 write!(f, "cooldown_delegation")
             }
 
-            JitoVaultProgram::UpdateVaultBalance => {
+            JitoVaultProgram::UpdateVaultBalance { .. } => {
                 write!(f, "update_vault_balance")
             }
-            JitoVaultProgram::InitializeVaultUpdateStateTracker => {
+            JitoVaultProgram::InitializeVaultUpdateStateTracker { .. } => {
                 write!(f, "initialize_vault_update_state_tracker")
             }
-            JitoVaultProgram::CrankVaultUpdateStateTracker => {
+            JitoVaultProgram::CrankVaultUpdateStateTracker { .. } => {
                 write!(f, "crank_vault_update_state_tracker")
             }
-            JitoVaultProgram::CloseVaultUpdateStateTracker => {
+            JitoVaultProgram::CloseVaultUpdateStateTracker { .. } => {
                 write!(f, "close_vault_update_state_tracker")
             }
             JitoVaultProgram::CreateTokenMetadata => {
@@ -143,7 +203,7 @@ impl std::fmt::Display for JitoVaultProgram {
             JitoVaultProgram::UpdateTokenMetadata => {
                 write!(f, "update_token_metadata")
             }
-            JitoVaultProgram::SetConfigAdmin => {
+            JitoVaultProgram::SetConfigAdmin { .. } => {
                 write!(f, "set_config_admin")
             }
         }
@@ -155,6 +215,182 @@ impl JitoVaultProgram {
         jito_vault_client::programs::JITO_VAULT_ID
     }
 
+    /// Config account of an admin or update instruction
+    pub fn config(&self) -> Option<&Pubkey> {
+        match self {
+            JitoVaultProgram::UpdateVaultBalance { ix }
+            | JitoVaultProgram::InitializeVaultUpdateStateTracker { ix }
+            | JitoVaultProgram::CrankVaultUpdateStateTracker { ix }
+            | JitoVaultProgram::CloseVaultUpdateStateTracker { ix, .. } => {
+                Some(&ix.accounts[0].pubkey)
+            }
+            _ => self.admin_ix().map(|ix| &ix.accounts[0].pubkey),
+        }
+    }
+
+    /// Vault account of a withdrawal, admin, delegation, ticket or update instruction
+    ///
+    /// - `None` for instructions on the program config
+    pub fn vault(&self) -> Option<&Pubkey> {
+        match self {
+            JitoVaultProgram::InitializeVaultOperatorDelegation { ix }
+            | JitoVaultProgram::InitializeVaultNcnTicket { ix }
+            | JitoVaultProgram::InitializeVaultNcnSlasherOperatorTicket { ix }
+            | JitoVaultProgram::InitializeVaultNcnSlasherTicket { ix }
+            | JitoVaultProgram::WarmupVaultNcnTicket { ix }
+            | JitoVaultProgram::CooldownVaultNcnTicket { ix }
+            | JitoVaultProgram::WarmupVaultNcnSlasherTicket { ix }
+            | JitoVaultProgram::CooldownVaultNcnSlasherTicket { ix } => {
+                Some(&ix.accounts[1].pubkey)
+            }
+            JitoVaultProgram::EnqueueWithdrawal { ix, .. }
+            | JitoVaultProgram::ChangeWithdrawalTicketOwner { ix }
+            | JitoVaultProgram::BurnWithdrawalTicket { ix }
+            | JitoVaultProgram::AddDelegation { ix, .. }
+            | JitoVaultProgram::CooldownDelegation { ix, .. }
+            | JitoVaultProgram::SetDepositCapacity { ix, .. }
+            | JitoVaultProgram::SetFees { ix, .. }
+            | JitoVaultProgram::SetIsPaused { ix, .. }
+            | JitoVaultProgram::SetAdmin { ix }
+            | JitoVaultProgram::SetSecondaryAdmin { ix, .. }
+            | JitoVaultProgram::UpdateVaultBalance { ix }
+            | JitoVaultProgram::InitializeVaultUpdateStateTracker { ix }
+            | JitoVaultProgram::CrankVaultUpdateStateTracker { ix }
+            | JitoVaultProgram::CloseVaultUpdateStateTracker { ix, .. } => {
+                Some(&ix.accounts[1].pubkey)
+            }
+            _ => None,
+        }
+    }
+
+    /// Withdrawal ticket account of a withdrawal instruction
+    pub fn withdrawal_ticket(&self) -> Option<&Pubkey> {
+        match self {
+            JitoVaultProgram::EnqueueWithdrawal { ix, .. }
+            | JitoVaultProgram::ChangeWithdrawalTicketOwner { ix } => Some(&ix.accounts[2].pubkey),
+            JitoVaultProgram::BurnWithdrawalTicket { ix } => Some(&ix.accounts[6].pubkey),
+            _ => None,
+        }
+    }
+
+    /// Operator account of a delegation, slasher operator ticket or crank instruction
+    pub fn operator(&self) -> Option<&Pubkey> {
+        match self {
+            JitoVaultProgram::InitializeVaultOperatorDelegation { ix }
+            | JitoVaultProgram::AddDelegation { ix, .. }
+            | JitoVaultProgram::CooldownDelegation { ix, .. }
+            | JitoVaultProgram::CrankVaultUpdateStateTracker { ix } => Some(&ix.accounts[2].pubkey),
+            JitoVaultProgram::InitializeVaultNcnSlasherOperatorTicket { ix } => {
+                Some(&ix.accounts[4].pubkey)
+            }
+            _ => None,
+        }
+    }
+
+    /// NCN account of an NCN or slasher ticket instruction
+    pub fn ncn(&self) -> Option<&Pubkey> {
+        match self {
+            JitoVaultProgram::InitializeVaultNcnTicket { ix }
+            | JitoVaultProgram::InitializeVaultNcnSlasherOperatorTicket { ix }
+            | JitoVaultProgram::InitializeVaultNcnSlasherTicket { ix }
+            | JitoVaultProgram::WarmupVaultNcnTicket { ix }
+            | JitoVaultProgram::CooldownVaultNcnTicket { ix }
+            | JitoVaultProgram::WarmupVaultNcnSlasherTicket { ix }
+            | JitoVaultProgram::CooldownVaultNcnSlasherTicket { ix } => {
+                Some(&ix.accounts[2].pubkey)
+            }
+            _ => None,
+        }
+    }
+
+    /// Slasher account of a slasher ticket instruction
+    pub fn slasher(&self) -> Option<&Pubkey> {
+        match self {
+            JitoVaultProgram::InitializeVaultNcnSlasherOperatorTicket { ix }
+            | JitoVaultProgram::InitializeVaultNcnSlasherTicket { ix }
+            | JitoVaultProgram::WarmupVaultNcnSlasherTicket { ix }
+            | JitoVaultProgram::CooldownVaultNcnSlasherTicket { ix } => {
+                Some(&ix.accounts[3].pubkey)
+            }
+            _ => None,
+        }
+    }
+
+    /// Lifecycle event of an operator, NCN or slasher ticket, naming accounts with `label`
+    ///
+    /// - e.g. "Vault X started warming up to NCN Y"
+    pub fn ticket_event(&self, label: impl Fn(&Pubkey) -> String) -> Option<String> {
+        let vault = label(self.vault()?);
+        let ncn = self.ncn().map(&label).unwrap_or_default();
+        let slasher = self.slasher().map(&label).unwrap_or_default();
+        let operator = self.operator().map(&label).unwrap_or_default();
+
+        let event = match self {
+            JitoVaultProgram::InitializeVaultOperatorDelegation { .. } => {
+                format!("Vault {vault} added operator {operator}")
+            }
+            JitoVaultProgram::InitializeVaultNcnTicket { .. } => {
+                format!("Vault {vault} added NCN {ncn}")
+            }
+            JitoVaultProgram::WarmupVaultNcnTicket { .. } => {
+                format!("Vault {vault} started warming up to NCN {ncn}")
+            }
+            JitoVaultProgram::CooldownVaultNcnTicket { .. } => {
+                format!("Vault {vault} started cooling down from NCN {ncn}")
+            }
+            JitoVaultProgram::InitializeVaultNcnSlasherTicket { .. } => {
+                format!("Vault {vault} added slasher {slasher} of NCN {ncn}")
+            }
+            JitoVaultProgram::WarmupVaultNcnSlasherTicket { .. } => {
+                format!("Vault {vault} started warming up slasher {slasher} of NCN {ncn}")
+            }
+            JitoVaultProgram::CooldownVaultNcnSlasherTicket { .. } => {
+                format!("Vault {vault} started cooling down slasher {slasher} of NCN {ncn}")
+            }
+            JitoVaultProgram::InitializeVaultNcnSlasherOperatorTicket { .. } => format!(
+                "Vault {vault} started tracking slashing of operator {operator} by slasher {slasher} of NCN {ncn}"
+            ),
+            _ => return None,
+        };
+        Some(event)
+    }
+
+    /// Signing admin of an admin or ticket instruction
+    pub fn admin(&self) -> Option<&Pubkey> {
+        match self {
+            JitoVaultProgram::SetProgramFee { ix, .. }
+            | JitoVaultProgram::SetProgramFeeWallet { ix }
+            | JitoVaultProgram::SetConfigAdmin { ix } => Some(&ix.accounts[1].pubkey),
+            JitoVaultProgram::WarmupVaultNcnTicket { ix }
+            | JitoVaultProgram::CooldownVaultNcnTicket { ix } => Some(&ix.accounts[4].pubkey),
+            JitoVaultProgram::InitializeVaultOperatorDelegation { ix }
+            | JitoVaultProgram::InitializeVaultNcnTicket { ix }
+            | JitoVaultProgram::WarmupVaultNcnSlasherTicket { ix }
+            | JitoVaultProgram::CooldownVaultNcnSlasherTicket { ix } => {
+                Some(&ix.accounts[5].pubkey)
+            }
+            JitoVaultProgram::InitializeVaultNcnSlasherTicket { ix } => {
+                Some(&ix.accounts[6].pubkey)
+            }
+            _ => self.admin_ix().map(|ix| &ix.accounts[2].pubkey),
+        }
+    }
+
+    /// Instruction of the admin and configuration variants
+    fn admin_ix(&self) -> Option<&Instruction> {
+        match self {
+            JitoVaultProgram::SetDepositCapacity { ix, .. }
+            | JitoVaultProgram::SetFees { ix, .. }
+            | JitoVaultProgram::SetProgramFee { ix, .. }
+            | JitoVaultProgram::SetProgramFeeWallet { ix }
+            | JitoVaultProgram::SetIsPaused { ix, .. }
+            | JitoVaultProgram::SetAdmin { ix }
+            | JitoVaultProgram::SetSecondaryAdmin { ix, .. }
+            | JitoVaultProgram::SetConfigAdmin { ix } => Some(ix),
+            _ => None,
+        }
+    }
+
     /// Parse Jito Vault Program
     pub fn parse_jito_vault_program<T: ParsableInstruction>(
         instruction: &T,
@@ -166,6 +402,38 @@ impl JitoVaultProgram {
         };
 
         match vault_ix {
+            VaultInstruction::InitializeVaultOperatorDelegation => {
+                let ix = Self::parse_initialize_ticket_ix(instruction, account_keys, 8);
+                Some(Self::InitializeVaultOperatorDelegation { ix })
+            }
+            VaultInstruction::InitializeVaultNcnTicket => {
+                let ix = Self::parse_initialize_ticket_ix(instruction, account_keys, 8);
+                Some(Self::InitializeVaultNcnTicket { ix })
+            }
+            VaultInstruction::InitializeVaultNcnSlasherOperatorTicket => {
+                let ix = Self::parse_initialize_ticket_ix(instruction, account_keys, 9);
+                Some(Self::InitializeVaultNcnSlasherOperatorTicket { ix })
+            }
+            VaultInstruction::InitializeVaultNcnSlasherTicket => {
+                let ix = Self::parse_initialize_ticket_ix(instruction, account_keys, 9);
+                Some(Self::InitializeVaultNcnSlasherTicket { ix })
+            }
+            VaultInstruction::WarmupVaultNcnTicket => {
+                let ix = Self::parse_ncn_ticket_ix(instruction, account_keys);
+                Some(Self::WarmupVaultNcnTicket { ix })
+            }
+            VaultInstruction::CooldownVaultNcnTicket => {
+                let ix = Self::parse_ncn_ticket_ix(instruction, account_keys);
+                Some(Self::CooldownVaultNcnTicket { ix })
+            }
+            VaultInstruction::WarmupVaultNcnSlasherTicket => {
+                let ix = Self::parse_ncn_slasher_ticket_ix(instruction, account_keys);
+                Some(Self::WarmupVaultNcnSlasherTicket { ix })
+            }
+            VaultInstruction::CooldownVaultNcnSlasherTicket => {
+                let ix = Self::parse_ncn_slasher_ticket_ix(instruction, account_keys);
+                Some(Self::CooldownVaultNcnSlasherTicket { ix })
+            }
             VaultInstruction::MintTo {
                 amount_in: _,
                 min_amount_out,
@@ -177,6 +445,78 @@ impl JitoVaultProgram {
             VaultInstruction::EnqueueWithdrawal { amount } => Some(
                 Self::parse_enqueue_withdrawal_ix(instruction, account_keys, amount),
             ),
+            VaultInstruction::ChangeWithdrawalTicketOwner => Some(
+                Self::parse_change_withdrawal_ticket_owner_ix(instruction, account_keys),
+            ),
+            VaultInstruction::BurnWithdrawalTicket => Some(Self::parse_burn_withdrawal_ticket_ix(
+                instruction,
+                account_keys,
+            )),
+            VaultInstruction::AddDelegation { amount } => {
+                let ix = Self::parse_delegation_ix(instruction, account_keys);
+                Some(Self::AddDelegation { ix, amount })
+            }
+            VaultInstruction::CooldownDelegation { amount } => {
+                let ix = Self::parse_delegation_ix(instruction, account_keys);
+                Some(Self::CooldownDelegation { ix, amount })
+            }
+            VaultInstruction::SetDepositCapacity { amount } => {
+                let ix = Self::parse_vault_admin_ix(instruction, account_keys);
+                Some(Self::SetDepositCapacity { ix, amount })
+            }
+            VaultInstruction::SetFees {
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+            } => {
+                let ix = Self::parse_vault_admin_ix(instruction, account_keys);
+                Some(Self::SetFees {
+                    ix,
+                    deposit_fee_bps,
+                    withdrawal_fee_bps,
+                    reward_fee_bps,
+                })
+            }
+            VaultInstruction::SetProgramFee { new_fee_bps } => {
+                let ix = Self::parse_config_admin_ix(instruction, account_keys);
+                Some(Self::SetProgramFee { ix, new_fee_bps })
+            }
+            VaultInstruction::SetProgramFeeWallet => {
+                let ix = Self::parse_config_admin_ix(instruction, account_keys);
+                Some(Self::SetProgramFeeWallet { ix })
+            }
+            VaultInstruction::SetIsPaused { is_paused } => {
+                let ix = Self::parse_vault_admin_ix(instruction, account_keys);
+                Some(Self::SetIsPaused { ix, is_paused })
+            }
+            VaultInstruction::SetAdmin => {
+                let ix = Self::parse_set_admin_ix(instruction, account_keys);
+                Some(Self::SetAdmin { ix })
+            }
+            VaultInstruction::SetSecondaryAdmin(role) => {
+                let ix = Self::parse_set_admin_ix(instruction, account_keys);
+                Some(Self::SetSecondaryAdmin { ix, role })
+            }
+            VaultInstruction::SetConfigAdmin => {
+                let ix = Self::parse_config_admin_ix(instruction, account_keys);
+                Some(Self::SetConfigAdmin { ix })
+            }
+            VaultInstruction::UpdateVaultBalance => {
+                let ix = Self::parse_update_ix(instruction, account_keys, 6);
+                Some(Self::UpdateVaultBalance { ix })
+            }
+            VaultInstruction::InitializeVaultUpdateStateTracker { .. } => {
+                let ix = Self::parse_update_ix(instruction, account_keys, 5);
+                Some(Self::InitializeVaultUpdateStateTracker { ix })
+            }
+            VaultInstruction::CrankVaultUpdateStateTracker => {
+                let ix = Self::parse_update_ix(instruction, account_keys, 5);
+                Some(Self::CrankVaultUpdateStateTracker { ix })
+            }
+            VaultInstruction::CloseVaultUpdateStateTracker { ncn_epoch } => {
+                let ix = Self::parse_update_ix(instruction, account_keys, 4);
+                Some(Self::CloseVaultUpdateStateTracker { ix, ncn_epoch })
+            }
             _ => None,
         }
     }
@@ -270,10 +610,259 @@ impl JitoVaultProgram {
 
         Self::EnqueueWithdrawal { ix, amount }
     }
+
+    /// #[account(0, name = "config")]
+    /// #[account(1, name = "vault")]
+    /// #[account(2, writable, name = "vault_staker_withdrawal_ticket")]
+    /// #[account(3, signer, name = "old_owner")]
+    /// #[account(4, name = "new_owner")]
+    pub fn parse_change_withdrawal_ticket_owner_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Self {
+        let account_metas = vec![
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ];
+
+        let ix = Self::build_ix(instruction, account_keys, account_metas);
+        Self::ChangeWithdrawalTicketOwner { ix }
+    }
+
+    /// #[account(0, name = "config")]
+    /// #[account(1, writable, name = "vault")]
+    /// #[account(2, writable, name = "vault_token_account")]
+    /// #[account(3, writable, name = "vrt_mint")]
+    /// #[account(4, writable, name = "staker")]
+    /// #[account(5, writable, name = "staker_token_account")]
+    /// #[account(6, writable, name = "vault_staker_withdrawal_ticket")]
+    /// #[account(7, writable, name = "vault_staker_withdrawal_ticket_token_account")]
+    /// #[account(8, writable, name = "vault_fee_token_account")]
+    /// #[account(9, writable, name = "program_fee_token_account")]
+    /// #[account(10, name = "token_program")]
+    /// #[account(11, name = "system_program")]
+    /// #[account(12, signer, optional, name = "burn_signer", description = "Signer for burning")]
+    pub fn parse_burn_withdrawal_ticket_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Self {
+        let account_metas = vec![
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ];
+
+        let ix = Self::build_ix(instruction, account_keys, account_metas);
+        Self::BurnWithdrawalTicket { ix }
+    }
+
+    /// Parse the Initialize* ticket instructions, which only differ in their named accounts
+    ///
+    /// - InitializeVaultOperatorDelegation: config, vault, operator, operator_vault_ticket,
+    ///   vault_operator_delegation, admin, payer, system_program
+    /// - InitializeVaultNcnTicket: config, vault, ncn, ncn_vault_ticket, vault_ncn_ticket, admin,
+    ///   payer, system_program
+    /// - InitializeVaultNcnSlasherOperatorTicket: config, vault, ncn, slasher, operator,
+    ///   vault_ncn_slasher_ticket, vault_ncn_slasher_operator_ticket, payer, system_program
+    /// - InitializeVaultNcnSlasherTicket: config, vault, ncn, slasher, ncn_slasher_ticket,
+    ///   vault_slasher_ticket, admin, payer, system_program
+    fn parse_initialize_ticket_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+        num_accounts: usize,
+    ) -> Instruction {
+        let account_metas = (0..num_accounts)
+            .map(|_| AccountMeta::new_readonly(Pubkey::new_unique(), false))
+            .collect();
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Parse WarmupVaultNcnTicket and CooldownVaultNcnTicket
+    ///
+    /// #[account(0, name = "config")]
+    /// #[account(1, writable, name = "vault")] (read-only for CooldownVaultNcnTicket)
+    /// #[account(2, name = "ncn")]
+    /// #[account(3, writable, name = "vault_ncn_ticket")]
+    /// #[account(4, signer, name = "admin")]
+    fn parse_ncn_ticket_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Parse WarmupVaultNcnSlasherTicket and CooldownVaultNcnSlasherTicket
+    ///
+    /// #[account(0, name = "config")]
+    /// #[account(1, name = "vault")]
+    /// #[account(2, name = "ncn")]
+    /// #[account(3, name = "slasher")]
+    /// #[account(4, writable, name = "vault_ncn_slasher_ticket")]
+    /// #[account(5, signer, name = "admin")]
+    fn parse_ncn_slasher_ticket_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Parse AddDelegation and CooldownDelegation
+    ///
+    /// #[account(0, name = "config")]
+    /// #[account(1, writable, name = "vault")]
+    /// #[account(2, name = "operator")]
+    /// #[account(3, writable, name = "vault_operator_delegation")]
+    /// #[account(4, signer, name = "admin")]
+    fn parse_delegation_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Parse SetDepositCapacity, SetFees and SetIsPaused
+    ///
+    /// #[account(0, name = "config")]
+    /// #[account(1, writable, name = "vault")]
+    /// #[account(2, signer, name = "admin")]
+    fn parse_vault_admin_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Parse SetAdmin and SetSecondaryAdmin
+    ///
+    /// #[account(0, name = "config")]
+    /// #[account(1, writable, name = "vault")]
+    /// #[account(2, signer, name = "admin")]
+    /// #[account(3, name = "new_admin")] (signer for SetAdmin)
+    fn parse_set_admin_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ];
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Parse SetProgramFee, SetProgramFeeWallet and SetConfigAdmin
+    ///
+    /// #[account(0, writable, name = "config")]
+    /// #[account(1, signer, name = "admin")]
+    /// #[account(2, name = "new_fee_wallet" or "new_admin")] (not on SetProgramFee)
+    fn parse_config_admin_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Instruction {
+        let mut account_metas = vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ];
+        if instruction.accounts().len() > 2 {
+            account_metas.push(AccountMeta::new_readonly(Pubkey::new_unique(), false));
+        }
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Parse the vault update cycle instructions, which only differ in their named accounts
+    ///
+    /// - UpdateVaultBalance: config, vault, vault_token_account, vrt_mint,
+    ///   vault_fee_token_account, token_program
+    /// - InitializeVaultUpdateStateTracker: config, vault, vault_update_state_tracker, payer,
+    ///   system_program
+    /// - CrankVaultUpdateStateTracker: config, vault, operator, vault_operator_delegation,
+    ///   vault_update_state_tracker
+    /// - CloseVaultUpdateStateTracker: config, vault, vault_update_state_tracker, payer
+    fn parse_update_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+        num_accounts: usize,
+    ) -> Instruction {
+        let account_metas = (0..num_accounts)
+            .map(|_| AccountMeta::new_readonly(Pubkey::new_unique(), false))
+            .collect();
+
+        Self::build_ix(instruction, account_keys, account_metas)
+    }
+
+    /// Resolve `account_metas` from the instruction's account indexes
+    fn build_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+        mut account_metas: Vec<AccountMeta>,
+    ) -> Instruction {
+        for (index, account) in instruction.accounts().iter().enumerate() {
+            if let Some(account_meta) = account_metas.get_mut(index) {
+                if let Some(account) = account_keys.get(*account as usize) {
+                    account_meta.pubkey = *account;
+                }
+            }
+        }
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts: account_metas,
+            data: instruction.data().to_vec(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use jito_vault_sdk::instruction::VaultInstruction;
     use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
     use yellowstone_grpc_proto::prelude::CompiledInstruction;
 
@@ -352,4 +941,131 @@ mod tests {
             panic!("Expected MintTo variant");
         }
     }
+
+    #[test]
+    fn test_set_fees() {
+        let account_keys = create_test_pubkeys(3);
+        let data = borsh::to_vec(&VaultInstruction::SetFees {
+            deposit_fee_bps: Some(10),
+            withdrawal_fee_bps: None,
+            reward_fee_bps: Some(1_000),
+        })
+        .unwrap();
+
+        let instruction = create_compiled_instruction(1, vec![0, 1, 2], data);
+        let parsed = JitoVaultProgram::parse_jito_vault_program(&instruction, &account_keys)
+            .expect("SetFees should parse");
+
+        assert_eq!(parsed.vault(), Some(&account_keys[1]));
+        assert_eq!(parsed.admin(), Some(&account_keys[2]));
+        if let JitoVaultProgram::SetFees {
+            deposit_fee_bps,
+            withdrawal_fee_bps,
+            reward_fee_bps,
+            ..
+        } = parsed
+        {
+            assert_eq!(deposit_fee_bps, Some(10));
+            assert_eq!(withdrawal_fee_bps, None);
+            assert_eq!(reward_fee_bps, Some(1_000));
+        } else {
+            panic!("Expected SetFees variant");
+        }
+    }
+
+    #[test]
+    fn test_warmup_vault_ncn_ticket_event() {
+        let account_keys = create_test_pubkeys(5);
+        let data = borsh::to_vec(&VaultInstruction::WarmupVaultNcnTicket).unwrap();
+
+        let instruction = create_compiled_instruction(1, vec![0, 1, 2, 3, 4], data);
+        let parsed = JitoVaultProgram::parse_jito_vault_program(&instruction, &account_keys)
+            .expect("WarmupVaultNcnTicket should parse");
+
+        assert_eq!(parsed.ncn(), Some(&account_keys[2]));
+        assert_eq!(parsed.admin(), Some(&account_keys[4]));
+        assert_eq!(
+            parsed.ticket_event(|address| address.to_string()),
+            Some(format!(
+                "Vault {} started warming up to NCN {}",
+                account_keys[1], account_keys[2]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_burn_withdrawal_ticket() {
+        let account_keys = create_test_pubkeys(12);
+        let data = borsh::to_vec(&VaultInstruction::BurnWithdrawalTicket).unwrap();
+
+        let instruction = create_compiled_instruction(1, (0..12).collect(), data);
+        let parsed = JitoVaultProgram::parse_jito_vault_program(&instruction, &account_keys)
+            .expect("BurnWithdrawalTicket should parse");
+
+        assert_eq!(parsed.vault(), Some(&account_keys[1]));
+        assert_eq!(parsed.withdrawal_ticket(), Some(&account_keys[6]));
+        if let JitoVaultProgram::BurnWithdrawalTicket { ix } = parsed {
+            assert_eq!(ix.accounts[5].pubkey, account_keys[5]);
+        } else {
+            panic!("Expected BurnWithdrawalTicket variant");
+        }
+    }
+
+    #[test]
+    fn test_cooldown_delegation() {
+        let account_keys = create_test_pubkeys(5);
+        let data = borsh::to_vec(&VaultInstruction::CooldownDelegation { amount: 42 }).unwrap();
+
+        let instruction = create_compiled_instruction(1, vec![0, 1, 2, 3, 4], data);
+        let parsed = JitoVaultProgram::parse_jito_vault_program(&instruction, &account_keys)
+            .expect("CooldownDelegation should parse");
+
+        assert_eq!(parsed.vault(), Some(&account_keys[1]));
+        assert_eq!(parsed.operator(), Some(&account_keys[2]));
+        if let JitoVaultProgram::CooldownDelegation { ix, amount } = parsed {
+            assert_eq!(amount, 42);
+            assert_eq!(ix.accounts[3].pubkey, account_keys[3]);
+        } else {
+            panic!("Expected CooldownDelegation variant");
+        }
+    }
+
+    #[test]
+    fn test_close_vault_update_state_tracker() {
+        let account_keys = create_test_pubkeys(4);
+        let data =
+            borsh::to_vec(&VaultInstruction::CloseVaultUpdateStateTracker { ncn_epoch: 812 })
+                .unwrap();
+
+        let instruction = create_compiled_instruction(1, vec![0, 1, 2, 3], data);
+        let parsed = JitoVaultProgram::parse_jito_vault_program(&instruction, &account_keys)
+            .expect("CloseVaultUpdateStateTracker should parse");
+
+        assert_eq!(parsed.vault(), Some(&account_keys[1]));
+        if let JitoVaultProgram::CloseVaultUpdateStateTracker { ix, ncn_epoch } = parsed {
+            assert_eq!(ncn_epoch, 812);
+            assert_eq!(ix.accounts[2].pubkey, account_keys[2]);
+        } else {
+            panic!("Expected CloseVaultUpdateStateTracker variant");
+        }
+    }
+
+    #[test]
+    fn test_set_config_admin() {
+        let account_keys = create_test_pubkeys(3);
+        let data = borsh::to_vec(&VaultInstruction::SetConfigAdmin).unwrap();
+
+        let instruction = create_compiled_instruction(1, vec![0, 1, 2], data);
+        let parsed = JitoVaultProgram::parse_jito_vault_program(&instruction, &account_keys)
+            .expect("SetConfigAdmin should parse");
+
+        assert_eq!(parsed.config(), Some(&account_keys[0]));
+        assert_eq!(parsed.vault(), None);
+        assert_eq!(parsed.admin(), Some(&account_keys[1]));
+        if let JitoVaultProgram::SetConfigAdmin { ix } = parsed {
+            assert_eq!(ix.accounts[2].pubkey, account_keys[2]);
+        } else {
+            panic!("Expected SetConfigAdmin variant");
+        }
+    }
 }
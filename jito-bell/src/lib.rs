@@ -1,4 +1,10 @@
-use std::{collections::HashMap, path::PathBuf, str::FromStr};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use borsh::BorshDeserialize;
 use defillama_rs::{
@@ -6,69 +12,180 @@ use defillama_rs::{
     DefiLlamaClient,
 };
 use error::JitoBellError;
-use futures::{sink::SinkExt, stream::StreamExt};
 use ix_parser::{
-    stake_pool::SplStakePoolProgram, token_2022::SplToken2022Program, vault::JitoVaultProgram,
+    spl_token::SplTokenProgram, stake_pool::SplStakePoolProgram, token_2022::SplToken2022Program,
+    vault::JitoVaultProgram,
+};
+use jito_vault_client::{
+    accounts::{Config as VaultConfig, Vault},
+    log::metadata::Metadata,
 };
-use jito_vault_client::accounts::Vault;
-use log::{debug, error};
-use maplit::hashmap;
-use metrics::EpochMetrics;
+use log::{debug, error, info, warn};
+use metrics::{EndpointMetrics, EpochMetrics};
 use solana_metrics::datapoint_info;
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{
-    clock::DEFAULT_SLOTS_PER_EPOCH, commitment_config::CommitmentConfig, program_pack::Pack,
-    pubkey::Pubkey,
+    commitment_config::CommitmentConfig, epoch_schedule::EpochSchedule, pubkey::Pubkey,
+    signature::Signature, stake::state::StakeStateV2,
 };
-use spl_token::state::Mint;
+use spl_stake_pool::state::StakePool;
+use spl_token_2022::{extension::StateWithExtensions, state::Account as TokenAccount};
 use subscribe_option::SubscribeOption;
 use threshold_config::ThresholdConfig;
-use twitterust::{TwitterClient, TwitterCredentials};
-use yellowstone_grpc_client::GeyserGrpcClient;
-use yellowstone_grpc_proto::{
-    geyser::SubscribeRequestFilterSlots,
-    prelude::{
-        subscribe_update::UpdateOneof, SubscribeRequest, SubscribeRequestFilterTransactions,
-    },
-    tonic::transport::ClientTlsConfig,
-};
+use tokio::sync::mpsc;
+use yellowstone_grpc_proto::geyser::SubscribeUpdateAccount;
 
 use crate::{
+    account_config::WatchedAccount,
+    account_snapshot::AccountSnapshots,
+    account_state::AccountState,
+    backfill::{BackfillFetcher, BackfillOption, Checkpoint},
+    backoff::Backoff,
+    block_time::BlockTimeCache,
+    cli_args::ArgsTransactionSource,
     config::JitoBellConfig,
+    delivery::{DeliveryQueue, Notification, Notifier},
+    epoch::EpochProgress,
     event_parser::{jito_steward::JitoStewardEvent, EventParser},
-    ix_parser::{jito_steward::JitoStewardInstruction, InstructionParser},
-    notification_info::Destination,
-    program::{EventConfig, Instruction, ProgramName},
+    governance::{STAKE_POOL_GOVERNANCE_INSTRUCTIONS, VAULT_GOVERNANCE_INSTRUCTIONS},
+    ix_parser::{jito_steward::JitoStewardInstruction, InstructionParser, ParsedInstruction},
+    mint_info::MintInfoCache,
+    notification_info::{Destination, NotificationInfo},
+    operator_delegation::{DelegationChange, DelegationTotals, OperatorDelegations},
+    pipeline::{
+        spawn_parse_workers, spawn_reorder_stage, PipelineEvent, PipelineMetrics, StreamReader,
+    },
+    program::{AlertConfig, EventConfig, Instruction, ProgramName},
+    recording::Recorder,
+    transaction_source::{
+        geyser::GeyserSubscription, replay::ReplaySource, rpc::RpcPollingSource, TransactionSource,
+    },
     tx_parser::JitoTransactionParser,
+    vault_update::{UpdateCycle, UpdateStep, VaultUpdates},
+    withdrawal_ticket::{TicketEvent, WithdrawalTicket, WithdrawalTickets},
 };
 
+pub mod account_config;
+mod account_snapshot;
+pub mod account_state;
+pub mod backfill;
+mod backoff;
+mod block_time;
 pub mod cli_args;
 pub mod config;
+mod dedup;
+mod delivery;
+pub mod epoch;
 mod error;
 pub mod event_parser;
 pub mod events;
+pub mod governance;
 pub mod ix_parser;
 mod metrics;
+mod mint_info;
 pub mod multi_writer;
 pub mod notification_info;
+mod operator_delegation;
+mod pipeline;
 pub mod program;
+pub mod recording;
 pub mod subscribe_option;
 pub mod threshold_config;
+pub mod ticket_config;
+pub mod transaction_source;
+pub mod tx_error;
 pub mod tx_parser;
+mod vault_update;
+pub mod vault_update_config;
+mod withdrawal_ticket;
 
 pub const DEFAULT_VRT_SYMBOL: &str = "VRT";
 
+/// Capacity of the channel merging events from all transaction sources
+const SOURCE_CHANNEL_CAPACITY: usize = 10_000;
+
+/// Number of recent transaction signatures remembered for deduplication
+const SIGNATURE_CACHE_CAPACITY: usize = 100_000;
+
+/// Capacity of the queue feeding the parse workers
+const PARSE_QUEUE_CAPACITY: usize = 10_000;
+
+/// Capacity of the queue feeding rule evaluation
+const RULE_QUEUE_CAPACITY: usize = 10_000;
+
+/// Events the reorder stage holds waiting for an earlier one
+///
+/// - Above the parse queue capacity, so only a lost event fills it up
+const REORDER_BUFFER_CAPACITY: usize = 2 * PARSE_QUEUE_CAPACITY;
+
+/// Capacity of each destination's delivery queue
+const DELIVERY_QUEUE_CAPACITY: usize = 1_000;
+
+/// Number of recent slots whose block time is cached
+const BLOCK_TIME_CACHE_CAPACITY: usize = 10_000;
+
+/// Interval between per-source and pipeline metrics reports
+const ENDPOINT_STATS_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Attempts at fetching the epoch schedule before a live run or backfill gives up
+const EPOCH_SCHEDULE_ATTEMPTS: u32 = 5;
+
 pub struct JitoBellHandler {
     /// Configuration for Notification
     pub config: JitoBellConfig,
 
     /// RPC Client
-    pub rpc_client: RpcClient,
+    pub rpc_client: Arc<RpcClient>,
 
     /// Epoch Metrics
     epoch_metrics: EpochMetrics,
 
-    subscribe_option: SubscribeOption,
+    /// Epoch schedule of the cluster
+    epoch_schedule: EpochSchedule,
+
+    /// Whether `epoch_schedule` was read from the cluster, rather than assumed without warmup
+    epoch_schedule_fetched: bool,
+
+    subscribe_option: Arc<SubscribeOption>,
+
+    /// Highest slot processed from the merged transaction sources
+    last_processed_slot: Option<u64>,
+
+    /// Log notifications instead of sending them
+    dry_run: bool,
+
+    /// Appends every received update to `SubscribeOption::record_file`, handed to the stream reader
+    recorder: Option<Recorder>,
+
+    /// Queue and latency metrics of the parse and rule evaluation stages
+    pipeline_metrics: Arc<PipelineMetrics>,
+
+    /// Notifications waiting to be delivered, per destination
+    delivery: DeliveryQueue,
+
+    /// Block time of recent slots, shared with the pipeline and delivery
+    block_times: Arc<BlockTimeCache>,
+
+    /// Accounts subscribed to, by address
+    watched_accounts: HashMap<Pubkey, WatchedAccount>,
+
+    /// Last decoded state and slot of each watched account
+    account_states: HashMap<Pubkey, (u64, AccountState)>,
+
+    /// Governed accounts as read before their latest change
+    account_snapshots: AccountSnapshots,
+
+    /// Running staked amounts of vault operators
+    operator_delegations: OperatorDelegations,
+
+    /// Decimals and symbols of mints, and mints of vaults
+    mint_info: MintInfoCache,
+
+    /// Vault withdrawal tickets between enqueue and burn
+    withdrawal_tickets: WithdrawalTickets,
+
+    /// Update cycles of the vaults under `vault_updates`
+    vault_updates: VaultUpdates,
 }
 
 impl JitoBellHandler {
@@ -82,17 +199,214 @@ impl JitoBellHandler {
         let config_str = std::fs::read_to_string(&config_path).map_err(JitoBellError::Io)?;
 
         let config: JitoBellConfig = serde_yaml::from_str(&config_str)?;
-        let rpc_client = RpcClient::new_with_commitment(endpoint.to_string(), commitment);
+        let rpc_client = Arc::new(RpcClient::new_with_commitment(
+            endpoint.to_string(),
+            commitment,
+        ));
+
+        // Replay runs offline, so fall back to the epoch of the first processed slot
+        let epoch = match rpc_client.get_epoch_info().await {
+            Ok(epoch_info) => epoch_info.epoch,
+            Err(e) => {
+                warn!("Failed to fetch epoch info: {e}");
+                0
+            }
+        };
+        let epoch_metrics = EpochMetrics::new(epoch);
+        // Live runs and backfills retry before starting, see `require_epoch_schedule`
+        let (epoch_schedule, epoch_schedule_fetched) = match rpc_client.get_epoch_schedule().await {
+            Ok(epoch_schedule) => (epoch_schedule, true),
+            Err(e) => {
+                warn!("Failed to fetch epoch schedule, assuming no warmup: {e}");
+                (EpochSchedule::without_warmup(), false)
+            }
+        };
+        let recorder = subscribe_option
+            .record_file
+            .as_deref()
+            .map(Recorder::create)
+            .transpose()?;
+        let watched_accounts = config
+            .accounts
+            .iter()
+            .map(|account| {
+                let address = Pubkey::from_str(&account.address).map_err(|e| {
+                    JitoBellError::Config(format!("Invalid account {}: {e}", account.address))
+                })?;
+                Ok((address, account.clone()))
+            })
+            .collect::<Result<HashMap<_, _>, JitoBellError>>()?;
+        let updated_vaults = config
+            .vault_updates
+            .keys()
+            .map(|address| {
+                Pubkey::from_str(address)
+                    .map_err(|e| JitoBellError::Config(format!("Invalid vault {address}: {e}")))
+            })
+            .collect::<Result<Vec<_>, JitoBellError>>()?;
+        let subscribe_option = Arc::new(subscribe_option);
+        let block_times = Arc::new(BlockTimeCache::new(
+            rpc_client.clone(),
+            BLOCK_TIME_CACHE_CAPACITY,
+        ));
+        let delivery = DeliveryQueue::spawn(
+            Notifier::new(subscribe_option.clone(), &config, block_times.clone()),
+            DELIVERY_QUEUE_CAPACITY,
+            subscribe_option.delivery_concurrency,
+            epoch_metrics.notification.clone(),
+        );
 
-        let epoch = rpc_client.get_epoch_info().await?;
-        let epoch_metrics = EpochMetrics::new(epoch.epoch);
+        let account_snapshots = AccountSnapshots::new(rpc_client.clone());
+        let operator_delegations = OperatorDelegations::new(rpc_client.clone());
+        let mint_info = MintInfoCache::new(rpc_client.clone());
 
-        Ok(Self {
+        let mut handler = Self {
             config,
             rpc_client,
             epoch_metrics,
+            epoch_schedule,
+            epoch_schedule_fetched,
             subscribe_option,
-        })
+            last_processed_slot: None,
+            dry_run: false,
+            recorder,
+            pipeline_metrics: Arc::new(PipelineMetrics::default()),
+            delivery,
+            block_times,
+            watched_accounts,
+            account_states: HashMap::new(),
+            account_snapshots,
+            operator_delegations,
+            mint_info,
+            withdrawal_tickets: WithdrawalTickets::new(),
+            vault_updates: VaultUpdates::new(updated_vaults),
+        };
+        handler.prefetch_governed_accounts().await;
+        handler.prefetch_vault_updates().await;
+
+        Ok(handler)
+    }
+
+    /// Read the stake pools and vaults named by governance rules
+    ///
+    /// - Stake pools come with their token metadata, vault rules with the vault program config
+    /// - Without a snapshot taken before it, the first change would have no old values
+    async fn prefetch_governed_accounts(&mut self) {
+        if let Some(program_config) = self.config.programs.get(&ProgramName::JitoVault) {
+            let rules: Vec<&Instruction> = VAULT_GOVERNANCE_INSTRUCTIONS
+                .iter()
+                .filter_map(|name| program_config.instructions.get(*name))
+                .collect();
+            let mut accounts: Vec<Pubkey> = rules
+                .iter()
+                .filter_map(|instruction| instruction.vaults.as_ref())
+                .flat_map(|vaults| vaults.keys())
+                .filter_map(|address| Pubkey::from_str(address).ok())
+                .collect();
+            if !rules.is_empty() {
+                let (config, _) =
+                    Pubkey::find_program_address(&[b"config"], &JitoVaultProgram::program_id());
+                accounts.push(config);
+            }
+
+            for account in accounts {
+                self.account_snapshots.refresh(&account, 0).await;
+            }
+        }
+
+        let Some(program_config) = self.config.programs.get(&ProgramName::SplStakePool) else {
+            return;
+        };
+        let stake_pools: Vec<Pubkey> = STAKE_POOL_GOVERNANCE_INSTRUCTIONS
+            .iter()
+            .filter_map(|name| program_config.instructions.get(*name))
+            .filter_map(|instruction| instruction.stake_pools.as_ref())
+            .flat_map(|stake_pools| stake_pools.keys())
+            .filter_map(|address| Pubkey::from_str(address).ok())
+            .collect();
+
+        for stake_pool in stake_pools {
+            self.account_snapshots.refresh(&stake_pool, 0).await;
+
+            if let Some(stake_pool) = self.stake_pool_before(&stake_pool, u64::MAX).await {
+                let metadata =
+                    jito_vault_sdk::inline_mpl_token_metadata::pda::find_metadata_account(
+                        &stake_pool.pool_mint,
+                    )
+                    .0;
+                self.account_snapshots.refresh(&metadata, 0).await;
+            }
+        }
+    }
+
+    /// Seed the update cycles of the vaults under `vault_updates` from their `Vault` accounts
+    ///
+    /// - Without it, a vault already updated this epoch would be reported stale
+    async fn prefetch_vault_updates(&mut self) {
+        if self.config.vault_updates.is_empty() {
+            return;
+        }
+        let (config, _) =
+            Pubkey::find_program_address(&[b"config"], &JitoVaultProgram::program_id());
+        self.vault_epoch_length(&config).await;
+
+        for address in self.config.vault_updates.keys() {
+            let Ok(vault) = Pubkey::from_str(address) else {
+                continue;
+            };
+            let vault_state = match self.rpc_client.get_account(&vault).await {
+                Ok(account) => match Vault::from_bytes(&account.data) {
+                    Ok(vault_state) => vault_state,
+                    Err(_) => continue,
+                },
+                Err(e) => {
+                    debug!("Failed to fetch vault {vault}: {e}");
+                    continue;
+                }
+            };
+            let Some((ncn_epoch, _)) = self
+                .vault_updates
+                .epoch_position(vault_state.last_full_state_update_slot)
+            else {
+                return;
+            };
+
+            self.vault_updates.record(
+                &vault,
+                UpdateStep::Closed { ncn_epoch },
+                vault_state.last_full_state_update_slot,
+            );
+            self.vault_updates.record(
+                &vault,
+                UpdateStep::Started,
+                vault_state.last_start_state_update_slot,
+            );
+        }
+    }
+
+    /// Vault program epoch length, read from its `Config` at `config` the first time
+    async fn vault_epoch_length(&mut self, config: &Pubkey) -> Option<u64> {
+        if let Some(epoch_length) = self.vault_updates.epoch_length() {
+            return Some(epoch_length);
+        }
+
+        match self.rpc_client.get_account(config).await {
+            Ok(account) => {
+                if let Ok(config) = VaultConfig::from_bytes(&account.data) {
+                    self.vault_updates.set_epoch_length(config.epoch_length);
+                    self.withdrawal_tickets
+                        .set_epoch_length(config.epoch_length);
+                }
+            }
+            Err(e) => debug!("Failed to fetch vault config: {e}"),
+        }
+        self.vault_updates.epoch_length()
+    }
+
+    /// `StakePool` as of before `slot`
+    async fn stake_pool_before(&mut self, address: &Pubkey, slot: u64) -> Option<StakePool> {
+        let data = self.account_snapshots.before(address, slot).await?;
+        <StakePool as borsh1::BorshDeserialize>::deserialize(&mut data.as_slice()).ok()
     }
 
     /// Sort thresholds
@@ -106,124 +420,391 @@ impl JitoBellHandler {
         });
     }
 
-    /// Get divisor
+    /// Build the configured transaction sources
+    ///
+    /// - Geyser: one subscription per endpoint
+    /// - RPC: a single poller watching `account_include`, or every configured program ID
+    /// - Both also deliver updates of the configured accounts
+    fn transaction_sources(&self) -> Result<Vec<Box<dyn TransactionSource>>, JitoBellError> {
+        match self.subscribe_option.transaction_source {
+            ArgsTransactionSource::Geyser => {
+                let watched_accounts: Vec<String> = self
+                    .watched_accounts
+                    .keys()
+                    .map(|address| address.to_string())
+                    .collect();
+
+                Ok(self
+                    .subscribe_option
+                    .endpoints
+                    .iter()
+                    .map(|endpoint| {
+                        Box::new(GeyserSubscription::new(
+                            endpoint.clone(),
+                            &self.subscribe_option,
+                            &watched_accounts,
+                        )) as Box<dyn TransactionSource>
+                    })
+                    .collect())
+            }
+            ArgsTransactionSource::Rpc => Ok(vec![Box::new(RpcPollingSource::new(
+                self.rpc_client.clone(),
+                self.watched_addresses()?,
+                self.watched_accounts.keys().copied().collect(),
+                self.subscribe_option.rpc_poll_interval,
+                self.subscribe_option.failed.unwrap_or(true),
+            ))]),
+        }
+    }
+
+    /// Addresses watched over RPC
     ///
-    /// - Fetch Mint account to get decimals value, if fails return default 9
-    async fn divisor(&self, vrt: &Pubkey) -> f64 {
-        let decimals = match self.rpc_client.get_account(vrt).await {
-            Ok(mint_acc) => match Mint::unpack(&mint_acc.data) {
-                Ok(acc) => acc.decimals,
-                Err(_) => 9,
-            },
-            Err(_e) => 9,
+    /// - `account_include` if set, otherwise every configured program ID
+    fn watched_addresses(&self) -> Result<Vec<Pubkey>, JitoBellError> {
+        let addresses = if self.subscribe_option.account_include.is_empty() {
+            self.config
+                .programs
+                .values()
+                .map(|program| program.program_id.as_str())
+                .collect::<Vec<_>>()
+        } else {
+            self.subscribe_option
+                .account_include
+                .iter()
+                .map(String::as_str)
+                .collect()
         };
 
-        10_f64.powi(decimals as i32)
+        addresses
+            .into_iter()
+            .map(|address| {
+                Pubkey::from_str(address)
+                    .map_err(|e| JitoBellError::Config(format!("Invalid address {address}: {e}")))
+            })
+            .collect()
     }
 
-    /// Get VRT Symbol
+    /// Replay historical transactions through `send_notification`
     ///
-    /// - Fetch Metadata account to get symbol value, if fails return default "VRT"
-    async fn vrt_symbol(&self, vrt: &Pubkey) -> String {
-        let meta_pubkey =
-            jito_vault_sdk::inline_mpl_token_metadata::pda::find_metadata_account(vrt).0;
-        let symbol = match self.rpc_client.get_account(&meta_pubkey).await {
-            Ok(meta_acc) => {
-                match jito_vault_client::log::metadata::Metadata::deserialize(
-                    &mut meta_acc.data.as_slice(),
-                ) {
-                    Ok(meta) => meta.symbol,
-                    Err(_e) => DEFAULT_VRT_SYMBOL.to_string(),
-                }
+    /// - Notifications are only logged unless `send_notifications` is set
+    /// - Signatures recorded in the checkpoint file are skipped, so a rerun resumes
+    pub async fn backfill(&mut self, option: BackfillOption) -> Result<(), JitoBellError> {
+        self.require_epoch_schedule().await?;
+        self.dry_run = !option.send_notifications;
+
+        let mut fetcher =
+            BackfillFetcher::new(self.rpc_client.clone(), option.rpc_request_interval);
+        let mut checkpoint = Checkpoint::load(option.checkpoint_file.as_ref())?;
+        let signatures = fetcher
+            .signatures(
+                &option.target,
+                &self.watched_addresses()?,
+                self.subscribe_option.failed.unwrap_or(true),
+            )
+            .await?;
+
+        info!(
+            "Backfilling {} transactions ({} already processed)",
+            signatures.len(),
+            checkpoint.len()
+        );
+
+        for (index, signature) in signatures.iter().enumerate() {
+            if checkpoint.contains(signature) {
+                continue;
             }
-            Err(_e) => DEFAULT_VRT_SYMBOL.to_string(),
-        };
 
-        symbol
+            let (transaction, block_time) = fetcher.transaction(signature).await?;
+            let mut parser = JitoTransactionParser::new(transaction);
+            parser.block_time = block_time;
+            if let Some(block_time) = block_time {
+                self.block_times.insert(parser.slot, block_time);
+            }
+            self.epoch_metrics.increment_tx_count();
+
+            debug!("Instruction: {:?}", parser.instructions);
+
+            if let Err(e) = self.send_notification(&parser).await {
+                error!("Error: {e}");
+            }
+            checkpoint.record(*signature)?;
+
+            if (index + 1) % 100 == 0 {
+                info!("Backfilled {}/{} transactions", index + 1, signatures.len());
+            }
+        }
+
+        self.delivery.flush().await;
+        info!("Backfill complete: {} transactions", signatures.len());
+
+        Ok(())
     }
 
     /// Start heart beating
+    ///
+    /// - Run every configured transaction source concurrently
+    /// - Merge the sources and drop transactions already delivered by another source
+    /// - Return an error only when every source has given up
     pub async fn heart_beat(&mut self) -> Result<(), JitoBellError> {
-        let mut client =
-            GeyserGrpcClient::build_from_shared(self.subscribe_option.endpoint.clone())?
-                .x_token(self.subscribe_option.x_token.clone())?
-                .tls_config(ClientTlsConfig::new().with_native_roots())?
-                .connect()
-                .await?;
-        let (mut subscribe_tx, mut stream) = client.subscribe().await?;
-
-        let subscribe_request = SubscribeRequest {
-            slots: hashmap! { "".to_owned() => SubscribeRequestFilterSlots {
-                filter_by_commitment: Some(true),
-            } },
-            accounts: HashMap::new(),
-            transactions: hashmap! { "".to_owned() => SubscribeRequestFilterTransactions {
-                vote: self.subscribe_option.vote,
-                failed: self.subscribe_option.failed,
-                signature: self.subscribe_option.signature.clone(),
-                account_include: self.subscribe_option.account_include.clone(),
-                account_exclude: self.subscribe_option.account_exclude.clone(),
-                account_required: self.subscribe_option.account_required.clone(),
-            } },
-            transactions_status: HashMap::new(),
-            entry: HashMap::new(),
-            blocks: HashMap::new(),
-            blocks_meta: HashMap::new(),
-            commitment: Some(self.subscribe_option.commitment as i32),
-            accounts_data_slice: vec![],
-            ping: None,
-        };
-        if let Err(e) = subscribe_tx.send(subscribe_request).await {
-            return Err(JitoBellError::Subscription(format!(
-                "Failed to send subscription request: {}",
-                e
-            )));
+        self.require_epoch_schedule().await?;
+        let sources = self.transaction_sources()?;
+        self.run_sources(sources).await;
+
+        Err(JitoBellError::Subscription(
+            "All transaction sources gave up".to_string(),
+        ))
+    }
+
+    /// Replay a recording through the parser and notification rules
+    ///
+    /// - Notifications are only logged unless `send_notifications` is set
+    pub async fn replay(
+        &mut self,
+        path: PathBuf,
+        paced: bool,
+        send_notifications: bool,
+    ) -> Result<(), JitoBellError> {
+        self.dry_run = !send_notifications;
+        self.run_sources(vec![Box::new(ReplaySource::new(path, paced))])
+            .await;
+
+        info!(
+            "Replay complete: {} transactions, {} notifications sent, {} failed",
+            self.epoch_metrics.tx,
+            self.epoch_metrics.notification.success(),
+            self.epoch_metrics.notification.fail()
+        );
+
+        Ok(())
+    }
+
+    /// Fetch the cluster's epoch schedule if startup could not, retrying with backoff
+    ///
+    /// - Epoch progress rules need the real schedule, so only replay, which may run offline,
+    ///   keeps the schedule without warmup
+    async fn require_epoch_schedule(&mut self) -> Result<(), JitoBellError> {
+        if self.epoch_schedule_fetched {
+            return Ok(());
         }
 
-        while let Some(message) = stream.next().await {
-            match message {
-                Ok(msg) => match msg.update_oneof {
-                    Some(UpdateOneof::Slot(update_slot)) => {
-                        let current_epoch = update_slot.slot / DEFAULT_SLOTS_PER_EPOCH;
-                        if current_epoch != self.epoch_metrics.epoch {
-                            datapoint_info!(
-                                "jito-bell-stats",
-                                ("epoch", self.epoch_metrics.epoch, i64),
-                                ("transaction", self.epoch_metrics.tx, i64),
-                                (
-                                    "success_notification",
-                                    self.epoch_metrics.notification.success,
-                                    i64
-                                ),
-                                (
-                                    "fail_notification",
-                                    self.epoch_metrics.notification.fail,
-                                    i64
-                                ),
-                            );
-                            self.epoch_metrics = EpochMetrics::new(current_epoch);
-                        }
-                    }
-                    Some(UpdateOneof::Transaction(transaction)) => {
-                        let parser = JitoTransactionParser::new(transaction);
-                        self.epoch_metrics.increment_tx_count();
+        let mut backoff = Backoff::new(Duration::from_millis(500), Duration::from_secs(30));
+        loop {
+            match self.rpc_client.get_epoch_schedule().await {
+                Ok(epoch_schedule) => {
+                    self.epoch_schedule = epoch_schedule;
+                    self.epoch_schedule_fetched = true;
+                    return Ok(());
+                }
+                Err(e) if backoff.attempt() + 1 < EPOCH_SCHEDULE_ATTEMPTS => {
+                    let delay = backoff.next_delay();
+                    warn!("Failed to fetch epoch schedule, retrying in {delay:?}: {e}");
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    /// Run `sources` concurrently through the pipeline until every source has returned
+    ///
+    /// - A stream reader drains the sources, parse workers decode transactions, a reorder stage
+    ///   restores stream order and this handler evaluates the rules, each stage connected by a
+    ///   bounded queue
+    /// - Notifications are delivered in the background, flushed before returning
+    async fn run_sources(&mut self, sources: Vec<Box<dyn TransactionSource>>) {
+        let (source_tx, source_rx) = mpsc::channel(SOURCE_CHANNEL_CAPACITY);
+        let endpoint_metrics = sources
+            .iter()
+            .map(|source| EndpointMetrics::new(source.name()))
+            .collect();
+
+        for (index, source) in sources.into_iter().enumerate() {
+            info!("Starting transaction source [{index}]: {}", source.name());
+
+            let tx = source_tx.clone();
+            tokio::spawn(async move {
+                if let Err(e) = source.run(index, tx).await {
+                    error!("{e}");
+                }
+            });
+        }
+        drop(source_tx);
+
+        let (parse_tx, parse_rx) = mpsc::channel(PARSE_QUEUE_CAPACITY);
+        let (sequenced_tx, sequenced_rx) = mpsc::channel(RULE_QUEUE_CAPACITY);
+        let (event_tx, mut event_rx) = mpsc::channel(RULE_QUEUE_CAPACITY);
+
+        let reader = StreamReader::new(
+            endpoint_metrics,
+            SIGNATURE_CACHE_CAPACITY,
+            self.recorder.take(),
+            self.block_times.clone(),
+            self.pipeline_metrics.clone(),
+        );
+        tokio::spawn(reader.run(
+            source_rx,
+            parse_tx,
+            sequenced_tx.clone(),
+            ENDPOINT_STATS_INTERVAL,
+        ));
+        spawn_parse_workers(
+            self.subscribe_option.parse_workers,
+            parse_rx,
+            sequenced_tx,
+            self.block_times.clone(),
+            self.pipeline_metrics.clone(),
+        );
+        spawn_reorder_stage(sequenced_rx, event_tx, REORDER_BUFFER_CAPACITY);
 
-                        debug!("Instruction: {:?}", parser.instructions);
+        let mut pipeline_stats_interval = tokio::time::interval(ENDPOINT_STATS_INTERVAL);
 
-                        if let Err(e) = self.send_notification(&parser).await {
-                            error!("Error: {e}");
-                        }
-                    }
-                    _ => continue,
+        loop {
+            tokio::select! {
+                event = event_rx.recv() => match event {
+                    Some(event) => self.handle_pipeline_event(event).await,
+                    None => break,
                 },
-                Err(error) => {
-                    error!("Stream error: {error:?}");
-                    break;
+                _ = pipeline_stats_interval.tick() => {
+                    self.pipeline_metrics.report();
+                    self.delivery.report();
                 }
             }
         }
 
-        Ok(())
+        self.delivery.flush().await;
+        self.pipeline_metrics.report();
+        self.delivery.report();
+    }
+
+    /// Handle an event from the reorder stage, in stream order
+    async fn handle_pipeline_event(&mut self, event: PipelineEvent) {
+        match event {
+            PipelineEvent::Slot(slot) => {
+                self.record_slot(slot);
+                self.check_withdrawal_tickets(slot);
+                self.check_vault_updates(slot);
+            }
+            PipelineEvent::Transaction { parser, parsed_at } => {
+                self.pipeline_metrics.rules.dequeued();
+                self.epoch_metrics.increment_tx_count();
+
+                debug!("Instruction: {:?}", parser.instructions);
+
+                if let Err(e) = self.send_notification(&parser).await {
+                    error!("Error: {e}");
+                }
+                self.check_withdrawal_tickets(parser.slot);
+                self.check_vault_updates(parser.slot);
+                self.pipeline_metrics.rules.record(parsed_at.elapsed());
+            }
+            PipelineEvent::Account(account) => self.handle_account_update(*account),
+            PipelineEvent::Reconnect => self.epoch_metrics.increment_reconnect_count(),
+            PipelineEvent::MissedSlots(missed) => self.epoch_metrics.add_missed_slots(missed),
+        }
+    }
+
+    /// Evaluate the rules of a watched account against its previous state
+    ///
+    /// - The first update of an account only records its state
+    /// - Updates older than the recorded state, such as from a lagging endpoint, are ignored
+    fn handle_account_update(&mut self, update: SubscribeUpdateAccount) {
+        let Some(info) = update.account else {
+            return;
+        };
+        let Ok(address) = Pubkey::try_from(info.pubkey.as_slice()) else {
+            return;
+        };
+        let Some(watched) = self.watched_accounts.get(&address) else {
+            return;
+        };
+        if self
+            .account_states
+            .get(&address)
+            .is_some_and(|(slot, _)| update.slot < *slot)
+        {
+            return;
+        }
+
+        let state = match AccountState::decode(watched.account_type, &info.data) {
+            Ok(state) => state,
+            Err(e) => {
+                error!("Failed to decode {} ({address}): {e}", watched.name);
+                return;
+            }
+        };
+        let Some((_, previous)) = self
+            .account_states
+            .insert(address, (update.slot, state.clone()))
+        else {
+            return;
+        };
+
+        let transaction_signature = info
+            .txn_signature
+            .and_then(|signature| Signature::try_from(signature.as_slice()).ok())
+            .map(|signature| signature.to_string());
+
+        for rule in watched.rules.iter() {
+            let Some((old, new)) = rule.evaluate(&previous, &state) else {
+                continue;
+            };
+
+            let description = format!(
+                "{}\n\n{}: `{}` {old} → {new} (slot {})",
+                rule.notification.description, watched.name, rule.field, update.slot
+            );
+            self.queue_notifications(
+                &rule.notification.destinations,
+                &description,
+                new.as_f64(),
+                new.as_f64().map(|_| rule.field.as_str()),
+                transaction_signature.as_deref(),
+                update.slot,
+            );
+        }
+    }
+
+    /// Progress through the epoch of `slot`
+    ///
+    /// - Taken from the slot being evaluated rather than the stream head, so backfill, replay
+    ///   and a lagging pipeline see the epoch the transaction landed in
+    pub fn epoch_progress(&self, slot: u64) -> EpochProgress {
+        EpochProgress::new(&self.epoch_schedule, slot)
+    }
+
+    /// Record a slot from the merged stream and roll over epoch metrics
+    fn record_slot(&mut self, slot: u64) {
+        if self.last_processed_slot.is_some_and(|last| slot <= last) {
+            return;
+        }
+        let first_slot = self.last_processed_slot.is_none();
+        self.last_processed_slot = Some(slot);
+
+        let current_epoch = self.epoch_schedule.get_epoch(slot);
+        if first_slot {
+            self.epoch_metrics.epoch = current_epoch;
+            return;
+        }
+        if current_epoch != self.epoch_metrics.epoch {
+            datapoint_info!(
+                "jito-bell-stats",
+                ("epoch", self.epoch_metrics.epoch, i64),
+                ("transaction", self.epoch_metrics.tx, i64),
+                (
+                    "success_notification",
+                    self.epoch_metrics.notification.success(),
+                    i64
+                ),
+                (
+                    "fail_notification",
+                    self.epoch_metrics.notification.fail(),
+                    i64
+                ),
+                ("reconnect", self.epoch_metrics.reconnect, i64),
+                ("missed_slots", self.epoch_metrics.missed_slots, i64),
+            );
+            self.epoch_metrics.reset(current_epoch);
+        }
     }
 
     /// Send notification
@@ -231,10 +812,68 @@ impl JitoBellHandler {
         &mut self,
         parser: &JitoTransactionParser,
     ) -> Result<(), JitoBellError> {
-        for program in &parser.instructions {
-            match program {
-                InstructionParser::SplToken2022(_) => {
+        let failed = parser.failure.is_some();
+        let epoch_progress = self.epoch_progress(parser.slot);
+
+        for parsed in &parser.instructions {
+            match &parsed.instruction {
+                InstructionParser::SplToken(spl_token_program) => {
+                    let spl_token_program_str = spl_token_program.to_string();
+
+                    let instruction_opt = self
+                        .config
+                        .programs
+                        .get(&ProgramName::SplToken)
+                        .and_then(|program_config| {
+                            program_config
+                                .instructions
+                                .get(&spl_token_program_str)
+                                .cloned()
+                        })
+                        .filter(|instruction| {
+                            instruction.matches_status(failed)
+                                && instruction.matches_epoch_progress(&epoch_progress)
+                        });
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_spl_token_program(
+                            parser,
+                            parsed,
+                            spl_token_program,
+                            &instruction,
+                        )
+                        .await;
+                    }
+                }
+                InstructionParser::SplToken2022(spl_token_2022_program) => {
                     debug!("Token 2022");
+
+                    let spl_token_2022_program_str = spl_token_2022_program.to_string();
+
+                    let instruction_opt = self
+                        .config
+                        .programs
+                        .get(&ProgramName::SplToken2022)
+                        .and_then(|program_config| {
+                            program_config
+                                .instructions
+                                .get(&spl_token_2022_program_str)
+                                .cloned()
+                        })
+                        .filter(|instruction| {
+                            instruction.matches_status(failed)
+                                && instruction.matches_epoch_progress(&epoch_progress)
+                        });
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_spl_token_2022_program(
+                            parser,
+                            parsed,
+                            spl_token_2022_program,
+                            &instruction,
+                        )
+                        .await?;
+                    }
                 }
                 InstructionParser::SplStakePool(spl_stake_program) => {
                     debug!("SPL Stake Pool");
@@ -247,11 +886,20 @@ impl JitoBellHandler {
                         .get(&ProgramName::SplStakePool)
                         .and_then(|program_config| {
                             program_config.instructions.get(&spl_program_str).cloned()
+                        })
+                        .filter(|instruction| {
+                            instruction.matches_status(failed)
+                                && instruction.matches_epoch_progress(&epoch_progress)
                         });
 
                     if let Some(instruction) = instruction_opt {
-                        self.handle_spl_stake_pool_program(parser, spl_stake_program, &instruction)
-                            .await?;
+                        self.handle_spl_stake_pool_program(
+                            parser,
+                            parsed,
+                            spl_stake_program,
+                            &instruction,
+                        )
+                        .await?;
                     }
                 }
                 InstructionParser::JitoVault(jito_vault_program) => {
@@ -259,20 +907,45 @@ impl JitoBellHandler {
 
                     let jito_vault_program_str = jito_vault_program.to_string();
 
-                    let instruction_opt =
-                        self.config.programs.get(&ProgramName::JitoVault).and_then(
-                            |program_config| {
-                                program_config
-                                    .instructions
-                                    .get(&jito_vault_program_str)
-                                    .cloned()
-                            },
-                        );
+                    let instruction_opt = self
+                        .config
+                        .programs
+                        .get(&ProgramName::JitoVault)
+                        .and_then(|program_config| {
+                            program_config
+                                .instructions
+                                .get(&jito_vault_program_str)
+                                .cloned()
+                        })
+                        .filter(|instruction| {
+                            instruction.matches_status(failed)
+                                && instruction.matches_epoch_progress(&epoch_progress)
+                        });
 
-                    if let Some(instruction) = instruction_opt {
-                        self.handle_jito_vault_program(parser, jito_vault_program, &instruction)
-                            .await?;
-                    }
+                    // Delegations are tracked before the rules, which show the totals after the
+                    // change
+                    let delegation_totals = self
+                        .track_operator_delegation(parser, jito_vault_program)
+                        .await;
+                    let result = match instruction_opt {
+                        Some(instruction) => {
+                            self.handle_jito_vault_program(
+                                parser,
+                                parsed,
+                                jito_vault_program,
+                                &instruction,
+                                delegation_totals,
+                            )
+                            .await
+                        }
+                        None => Ok(()),
+                    };
+                    // Tickets and update cycles are tracked after the rules, which show them as
+                    // before the change
+                    self.track_withdrawal_ticket(parser, jito_vault_program)
+                        .await;
+                    self.track_vault_update(parser, jito_vault_program).await;
+                    result?;
                 }
                 InstructionParser::JitoSteward(jito_steward_instruction) => {
                     debug!("Jito Steward");
@@ -288,11 +961,16 @@ impl JitoBellHandler {
                                 .instructions
                                 .get(&jito_steward_program_str)
                                 .cloned()
+                        })
+                        .filter(|instruction| {
+                            instruction.matches_status(failed)
+                                && instruction.matches_epoch_progress(&epoch_progress)
                         });
 
                     if let Some(instruction) = instruction_opt {
                         self.handle_jito_steward_program(
                             parser,
+                            parsed,
                             jito_steward_instruction,
                             &instruction,
                         )
@@ -302,8 +980,8 @@ impl JitoBellHandler {
             }
         }
 
-        for event in &parser.events {
-            match event {
+        for parsed_event in &parser.events {
+            match &parsed_event.event {
                 EventParser::JitoSteward(jito_steward_event) => {
                     let jito_steward_event_str = jito_steward_event.to_string();
 
@@ -430,9 +1108,9 @@ impl JitoBellHandler {
                                             &final_desc,
                                             Some(amt),
                                             unit,
-                                            &parser.transaction_signature,
-                                        )
-                                        .await?;
+                                            parser,
+                                            None,
+                                        );
                                     }
                                 }
                             }
@@ -452,9 +1130,9 @@ impl JitoBellHandler {
                                     &final_desc,
                                     amount,
                                     unit,
-                                    &parser.transaction_signature,
-                                )
-                                .await?;
+                                    parser,
+                                    None,
+                                );
                             }
                         }
                     }
@@ -471,6 +1149,7 @@ impl JitoBellHandler {
     async fn handle_spl_stake_pool_program(
         &mut self,
         parser: &JitoTransactionParser,
+        parsed: &ParsedInstruction,
         spl_stake_program: &SplStakePoolProgram,
         instruction: &Instruction,
     ) -> Result<(), JitoBellError> {
@@ -505,16 +1184,17 @@ impl JitoBellHandler {
                                     &threshold.notification.description,
                                     Some(*amount),
                                     Some("SOL"),
-                                    &parser.transaction_signature,
-                                )
-                                .await?;
+                                    parser,
+                                    Some(parsed),
+                                );
                                 break;
                             }
                         }
                     }
                 }
             }
-            SplStakePoolProgram::DepositStake { ix } => {
+            SplStakePoolProgram::DepositStake { ix }
+            | SplStakePoolProgram::DepositStakeWithSlippage { ix, .. } => {
                 let _stake_pool_info = &ix.accounts[0];
                 let _validator_list_info = &ix.accounts[1];
                 let _stake_deposit_authority_info = &ix.accounts[2];
@@ -529,48 +1209,52 @@ impl JitoBellHandler {
 
                 if let Some(mut lsts) = instruction.lsts.clone() {
                     if let Some(alert_config) = lsts.get_mut(&pool_mint_info.pubkey.to_string()) {
-                        for program in &parser.instructions {
-                            if let InstructionParser::SplToken2022(program) = program {
-                                match program {
-                                    SplToken2022Program::MintTo { ix, amount } => {
-                                        let mint_info = &ix.accounts[0];
-                                        let destination_account_info = &ix.accounts[1];
-                                        let owner_info = &ix.accounts[2];
-
-                                        if mint_info.pubkey.eq(&pool_mint_info.pubkey)
-                                            && destination_account_info
-                                                .pubkey
-                                                .eq(&dest_user_pool_info.pubkey)
-                                            && owner_info.pubkey.eq(&withdraw_authority_info.pubkey)
-                                        {
-                                            self.sort_thresholds(alert_config.thresholds.as_mut());
-                                            for threshold in alert_config.thresholds.iter() {
-                                                if *amount as f64 > threshold.value {
-                                                    self.dispatch_platform_notifications(
-                                                        &threshold.notification.destinations,
-                                                        &threshold.notification.description,
-                                                        Some(*amount as f64),
-                                                        Some("SOL"),
-                                                        &parser.transaction_signature,
-                                                    )
-                                                    .await?;
-                                                    break;
-                                                }
-                                            }
-
-                                            break;
-                                        }
+                        // Pool tokens minted by this deposit, not by another instruction
+                        for cpi in parser.cpis(parsed) {
+                            let (ix, amount) = match &cpi.instruction {
+                                InstructionParser::SplToken(SplTokenProgram::MintTo {
+                                    ix,
+                                    amount,
+                                })
+                                | InstructionParser::SplToken2022(SplToken2022Program::MintTo {
+                                    ix,
+                                    amount,
+                                }) => (ix, amount),
+                                _ => continue,
+                            };
+                            let mint_info = &ix.accounts[0];
+                            let destination_account_info = &ix.accounts[1];
+                            let owner_info = &ix.accounts[2];
+
+                            if mint_info.pubkey.eq(&pool_mint_info.pubkey)
+                                && destination_account_info
+                                    .pubkey
+                                    .eq(&dest_user_pool_info.pubkey)
+                                && owner_info.pubkey.eq(&withdraw_authority_info.pubkey)
+                            {
+                                self.sort_thresholds(alert_config.thresholds.as_mut());
+                                for threshold in alert_config.thresholds.iter() {
+                                    if *amount as f64 > threshold.value {
+                                        self.dispatch_platform_notifications(
+                                            &threshold.notification.destinations,
+                                            &threshold.notification.description,
+                                            Some(*amount as f64),
+                                            Some("SOL"),
+                                            parser,
+                                            Some(parsed),
+                                        );
+                                        break;
                                     }
                                 }
+
+                                break;
                             }
                         }
                     }
                 }
             }
-            SplStakePoolProgram::WithdrawStake {
-                ix,
-                minimum_lamports_out,
-            } => {
+            SplStakePoolProgram::WithdrawStake { ix, .. }
+            | SplStakePoolProgram::WithdrawStakeWithSlippage { ix, .. } => {
                 let _stake_pool_info = &ix.accounts[0];
                 let _validator_list_info = &ix.accounts[1];
                 let _withdraw_authority_info = &ix.accounts[2];
@@ -581,27 +1265,38 @@ impl JitoBellHandler {
                 let _burn_from_pool_info = &ix.accounts[7];
                 let _manager_fee_info = &ix.accounts[8];
                 let pool_mint_info = &ix.accounts[9];
+                let pool_tokens_in = spl_stake_program
+                    .withdrawn_pool_tokens()
+                    .unwrap_or_default();
+                let description_suffix = spl_stake_program
+                    .minimum_out_text()
+                    .map(|minimum_out| format!("\n\n{minimum_out}"))
+                    .unwrap_or_default();
 
                 if let Some(mut lsts) = instruction.lsts.clone() {
                     if let Some(alert_config) = lsts.get_mut(&pool_mint_info.pubkey.to_string()) {
                         self.sort_thresholds(alert_config.thresholds.as_mut());
                         for threshold in alert_config.thresholds.iter() {
-                            if *minimum_lamports_out >= threshold.value {
+                            if pool_tokens_in >= threshold.value {
                                 self.dispatch_platform_notifications(
                                     &threshold.notification.destinations,
-                                    &threshold.notification.description,
-                                    Some(*minimum_lamports_out),
+                                    &format!(
+                                        "{}{description_suffix}",
+                                        threshold.notification.description
+                                    ),
+                                    Some(pool_tokens_in),
                                     Some("SOL"),
-                                    &parser.transaction_signature,
-                                )
-                                .await?;
+                                    parser,
+                                    Some(parsed),
+                                );
                                 break;
                             }
                         }
                     }
                 }
             }
-            SplStakePoolProgram::DepositSol { ix, amount } => {
+            SplStakePoolProgram::DepositSol { ix, amount }
+            | SplStakePoolProgram::DepositSolWithSlippage { ix, amount, .. } => {
                 let _stake_pool_info = &ix.accounts[0];
                 let _withdraw_authority_info = &ix.accounts[1];
                 let _reserve_stake_account_info = &ix.accounts[2];
@@ -621,16 +1316,17 @@ impl JitoBellHandler {
                                     &threshold.notification.description,
                                     Some(*amount),
                                     Some("SOL"),
-                                    &parser.transaction_signature,
-                                )
-                                .await?;
+                                    parser,
+                                    Some(parsed),
+                                );
                                 break;
                             }
                         }
                     }
                 }
             }
-            SplStakePoolProgram::WithdrawSol { ix, amount } => {
+            SplStakePoolProgram::WithdrawSol { ix, amount }
+            | SplStakePoolProgram::WithdrawSolWithSlippage { ix, amount, .. } => {
                 let _stake_pool_info = &ix.accounts[0];
                 let _withdraw_authority_info = &ix.accounts[1];
                 let _user_transfer_authority_info = &ix.accounts[2];
@@ -650,9 +1346,9 @@ impl JitoBellHandler {
                                     &threshold.notification.description,
                                     Some(*amount),
                                     Some("SOL"),
-                                    &parser.transaction_signature,
-                                )
-                                .await?;
+                                    parser,
+                                    Some(parsed),
+                                );
                                 break;
                             }
                         }
@@ -684,51 +1380,813 @@ impl JitoBellHandler {
                                     &threshold.notification.description,
                                     Some(*amount),
                                     Some("SOL"),
-                                    &parser.transaction_signature,
-                                )
-                                .await?;
+                                    parser,
+                                    Some(parsed),
+                                );
                                 break;
                             }
                         }
                     }
                 }
             }
-            SplStakePoolProgram::Initialize
-            | SplStakePoolProgram::AddValidatorToPool
-            | SplStakePoolProgram::RemoveValidatorFromPool
-            | SplStakePoolProgram::DecreaseValidatorStake
-            | SplStakePoolProgram::SetPreferredValidator
-            | SplStakePoolProgram::UpdateValidatorListBalance
-            | SplStakePoolProgram::UpdateStakePoolBalance
-            | SplStakePoolProgram::CleanupRemovedValidatorEntries
-            | SplStakePoolProgram::SetManager
-            | SplStakePoolProgram::SetFee
-            | SplStakePoolProgram::SetStaker
-            | SplStakePoolProgram::SetFundingAuthority
-            | SplStakePoolProgram::CreateTokenMetadata
-            | SplStakePoolProgram::UpdateTokenMetadata
-            | SplStakePoolProgram::IncreaseAdditionalValidatorStake
-            | SplStakePoolProgram::DecreaseAdditionalValidatorStake
-            | SplStakePoolProgram::Redelegate
-            | SplStakePoolProgram::DepositStakeWithSlippage
-            | SplStakePoolProgram::WithdrawStakeWithSlippage
-            | SplStakePoolProgram::DepositSolWithSlippage
-            | SplStakePoolProgram::WithdrawSolWithSlippage => {
-                unreachable!()
-            }
-        }
+            SplStakePoolProgram::SetManager { ix }
+            | SplStakePoolProgram::SetFee { ix, .. }
+            | SplStakePoolProgram::SetStaker { ix }
+            | SplStakePoolProgram::SetFundingAuthority { ix, .. }
+            | SplStakePoolProgram::SetPreferredValidator { ix, .. }
+            | SplStakePoolProgram::CreateTokenMetadata { ix, .. }
+            | SplStakePoolProgram::UpdateTokenMetadata { ix, .. } => {
+                let stake_pool_info = &ix.accounts[0];
 
-        Ok(())
-    }
+                if let Some(ref notification_info) = instruction.notification_info {
+                    if Self::lists_stake_pool(instruction, &stake_pool_info.pubkey) {
+                        self.dispatch_stake_pool_governance_notifications(
+                            parser,
+                            parsed,
+                            spl_stake_program,
+                            &stake_pool_info.pubkey,
+                            notification_info,
+                        )
+                        .await;
+                    }
+                }
+            }
+            SplStakePoolProgram::AddValidatorToPool {
+                ix,
+                stake_account,
+                vote_account,
+            } => {
+                let stake_pool_info = &ix.accounts[0];
 
-    /// Handle Jito Vault Program
+                if let Some(ref notification_info) = instruction.notification_info {
+                    if Self::lists_stake_pool(instruction, &stake_pool_info.pubkey) {
+                        let description = format!(
+                            "{}\n\nStake pool: {}\nValidator: {vote_account}\nStake account: {stake_account}",
+                            notification_info.description, stake_pool_info.pubkey
+                        );
+                        self.dispatch_platform_notifications(
+                            &notification_info.destinations,
+                            &description,
+                            None,
+                            None,
+                            parser,
+                            Some(parsed),
+                        );
+                    }
+                }
+            }
+            SplStakePoolProgram::RemoveValidatorFromPool { ix, stake_account } => {
+                let stake_pool_info = &ix.accounts[0];
+
+                if let Some(ref notification_info) = instruction.notification_info {
+                    if Self::lists_stake_pool(instruction, &stake_pool_info.pubkey) {
+                        let vote_account = self.delegated_vote_account(stake_account).await;
+                        let description = format!(
+                            "{}\n\nStake pool: {}\nValidator: {}\nStake account: {stake_account}",
+                            notification_info.description,
+                            stake_pool_info.pubkey,
+                            Self::vote_account_text(vote_account)
+                        );
+                        self.dispatch_platform_notifications(
+                            &notification_info.destinations,
+                            &description,
+                            None,
+                            None,
+                            parser,
+                            Some(parsed),
+                        );
+                    }
+                }
+            }
+            SplStakePoolProgram::IncreaseAdditionalValidatorStake {
+                ix,
+                amount,
+                vote_account,
+                ..
+            } => {
+                let stake_pool_info = &ix.accounts[0];
+
+                self.dispatch_stake_pool_amount_notifications(
+                    parser,
+                    parsed,
+                    instruction,
+                    &stake_pool_info.pubkey,
+                    *amount,
+                    &format!("Validator: {vote_account}"),
+                );
+            }
+            SplStakePoolProgram::DecreaseAdditionalValidatorStake {
+                ix,
+                amount,
+                stake_account,
+            } => {
+                let stake_pool_info = &ix.accounts[0];
+
+                if Self::lists_stake_pool_thresholds(instruction, &stake_pool_info.pubkey) {
+                    let vote_account = self.delegated_vote_account(stake_account).await;
+                    self.dispatch_stake_pool_amount_notifications(
+                        parser,
+                        parsed,
+                        instruction,
+                        &stake_pool_info.pubkey,
+                        *amount,
+                        &format!("Validator: {}", Self::vote_account_text(vote_account)),
+                    );
+                }
+            }
+            SplStakePoolProgram::Redelegate {
+                ix,
+                amount,
+                source_stake_account,
+                destination_vote_account,
+                ..
+            } => {
+                let stake_pool_info = &ix.accounts[0];
+
+                if Self::lists_stake_pool_thresholds(instruction, &stake_pool_info.pubkey) {
+                    let source_vote_account =
+                        self.delegated_vote_account(source_stake_account).await;
+                    self.dispatch_stake_pool_amount_notifications(
+                        parser,
+                        parsed,
+                        instruction,
+                        &stake_pool_info.pubkey,
+                        *amount,
+                        &format!(
+                            "From validator: {}\nTo validator: {destination_vote_account}",
+                            Self::vote_account_text(source_vote_account)
+                        ),
+                    );
+                }
+            }
+            SplStakePoolProgram::Initialize
+            | SplStakePoolProgram::DecreaseValidatorStake
+            | SplStakePoolProgram::UpdateValidatorListBalance
+            | SplStakePoolProgram::UpdateStakePoolBalance
+            | SplStakePoolProgram::CleanupRemovedValidatorEntries => {
+                unreachable!()
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether `stake_pool` is listed under the instruction's `stake_pools`
+    ///
+    /// - Without the list every stake pool is matched
+    fn lists_stake_pool(instruction: &Instruction, stake_pool: &Pubkey) -> bool {
+        instruction
+            .stake_pools
+            .as_ref()
+            .is_none_or(|stake_pools| stake_pools.contains_key(&stake_pool.to_string()))
+    }
+
+    /// Whether `stake_pool` has thresholds under the instruction's `stake_pools`
+    fn lists_stake_pool_thresholds(instruction: &Instruction, stake_pool: &Pubkey) -> bool {
+        instruction
+            .stake_pools
+            .as_ref()
+            .and_then(|stake_pools| stake_pools.get(&stake_pool.to_string()))
+            .is_some_and(|alert_config| !alert_config.thresholds.is_empty())
+    }
+
+    /// Vote account a stake account is delegated to
+    async fn delegated_vote_account(&self, stake_account: &Pubkey) -> Option<Pubkey> {
+        let account = match self.rpc_client.get_account(stake_account).await {
+            Ok(account) => account,
+            Err(e) => {
+                debug!("Failed to fetch stake account {stake_account}: {e}");
+                return None;
+            }
+        };
+        let stake_state: StakeStateV2 = bincode::deserialize(&account.data).ok()?;
+
+        stake_state
+            .delegation()
+            .map(|delegation| delegation.voter_pubkey)
+    }
+
+    /// Vote account for notifications, `unknown` if it could not be read
+    fn vote_account_text(vote_account: Option<Pubkey>) -> String {
+        vote_account.map_or_else(|| "unknown".to_string(), |vote| vote.to_string())
+    }
+
+    /// Notify the first threshold of `stake_pool` under `stake_pools` that `amount` exceeds
+    ///
+    /// - `details` is appended to the threshold's description
+    fn dispatch_stake_pool_amount_notifications(
+        &self,
+        parser: &JitoTransactionParser,
+        parsed: &ParsedInstruction,
+        instruction: &Instruction,
+        stake_pool: &Pubkey,
+        amount: f64,
+        details: &str,
+    ) {
+        let Some(alert_config) = instruction
+            .stake_pools
+            .as_ref()
+            .and_then(|stake_pools| stake_pools.get(&stake_pool.to_string()))
+        else {
+            return;
+        };
+
+        let mut thresholds = alert_config.thresholds.clone();
+        self.sort_thresholds(&mut thresholds);
+        for threshold in thresholds.iter() {
+            if amount > threshold.value {
+                let description = format!("{}\n\n{details}", threshold.notification.description);
+                self.dispatch_platform_notifications(
+                    &threshold.notification.destinations,
+                    &description,
+                    Some(amount),
+                    Some("SOL"),
+                    parser,
+                    Some(parsed),
+                );
+                break;
+            }
+        }
+    }
+
+    /// Notify a stake pool governance change with the old and new values
+    ///
+    /// - Old values come from the `StakePool` (or token metadata) read before the transaction
+    /// - Snapshots are read again after a successful change
+    async fn dispatch_stake_pool_governance_notifications(
+        &mut self,
+        parser: &JitoTransactionParser,
+        parsed: &ParsedInstruction,
+        spl_stake_program: &SplStakePoolProgram,
+        stake_pool_address: &Pubkey,
+        notification_info: &NotificationInfo,
+    ) {
+        let metadata_address = match spl_stake_program {
+            SplStakePoolProgram::UpdateTokenMetadata { ix, .. } => Some(ix.accounts[3].pubkey),
+            _ => None,
+        };
+        let stake_pool = self
+            .stake_pool_before(stake_pool_address, parser.slot)
+            .await;
+        let metadata = match metadata_address {
+            Some(address) => self
+                .account_snapshots
+                .before(&address, parser.slot)
+                .await
+                .and_then(|data| Metadata::deserialize(&mut data.as_slice()).ok()),
+            None => None,
+        };
+
+        let changes = governance::stake_pool_changes(
+            spl_stake_program,
+            stake_pool.as_ref(),
+            metadata.as_ref(),
+        );
+        let mut description = format!(
+            "{}\n\nStake pool: {stake_pool_address}",
+            notification_info.description
+        );
+        for change in changes.iter() {
+            description.push_str(&format!("\n{change}"));
+        }
+
+        self.dispatch_platform_notifications(
+            &notification_info.destinations,
+            &description,
+            None,
+            None,
+            parser,
+            Some(parsed),
+        );
+
+        if parser.failure.is_none() {
+            self.account_snapshots
+                .refresh(stake_pool_address, parser.slot)
+                .await;
+            if let Some(address) = metadata_address {
+                self.account_snapshots.refresh(&address, parser.slot).await;
+            }
+        }
+    }
+
+    /// Address with its configured label, if any
+    fn label(&self, address: &Pubkey) -> String {
+        match self.config.labels.get(&address.to_string()) {
+            Some(label) => format!("{label} ({address})"),
+            None => address.to_string(),
+        }
+    }
+
+    /// Notify the first threshold that `amount` reaches
+    ///
+    /// - `details` is appended to the threshold's description
+    fn dispatch_amount_notifications(
+        &self,
+        parser: &JitoTransactionParser,
+        parsed: &ParsedInstruction,
+        thresholds: &[ThresholdConfig],
+        amount: f64,
+        symbol: &str,
+        details: &str,
+    ) {
+        let mut thresholds = thresholds.to_vec();
+        self.sort_thresholds(&mut thresholds);
+        for threshold in thresholds.iter() {
+            if amount >= threshold.value {
+                let description = format!("{}\n\n{details}", threshold.notification.description);
+                self.dispatch_platform_notifications(
+                    &threshold.notification.destinations,
+                    &description,
+                    Some(amount),
+                    Some(symbol),
+                    parser,
+                    Some(parsed),
+                );
+                break;
+            }
+        }
+    }
+
+    /// Notify the highest share threshold an operator's share of its vault crossed
+    ///
+    /// - Shares cross upward on `AddDelegation` and downward on `CooldownDelegation`
+    fn dispatch_operator_share_notifications(
+        &self,
+        parser: &JitoTransactionParser,
+        parsed: &ParsedInstruction,
+        thresholds: &[ThresholdConfig],
+        totals: &DelegationTotals,
+        details: &str,
+    ) {
+        let previous_share = totals.previous_share();
+        let share = totals.share();
+
+        let mut thresholds = thresholds.to_vec();
+        self.sort_thresholds(&mut thresholds);
+        let crossed = thresholds
+            .iter()
+            .find(|threshold| (previous_share < threshold.value) != (share < threshold.value));
+
+        if let Some(threshold) = crossed {
+            let description = format!(
+                "{}\n\n{details}\nOperator share: {previous_share:.2}% → {share:.2}%",
+                threshold.notification.description
+            );
+            self.dispatch_platform_notifications(
+                &threshold.notification.destinations,
+                &description,
+                None,
+                None,
+                parser,
+                Some(parsed),
+            );
+        }
+    }
+
+    /// Whether the vault, NCN and operator of a ticket instruction are listed under the
+    /// instruction's `vaults`, `ncns` and `operators`
+    ///
+    /// - Without a list, or without such an account, every account is matched
+    fn lists_restaking_accounts(
+        instruction: &Instruction,
+        jito_vault_program: &JitoVaultProgram,
+    ) -> bool {
+        let listed = |list: &Option<HashMap<String, AlertConfig>>, address: Option<&Pubkey>| match (
+            list, address,
+        ) {
+            (Some(list), Some(address)) => list.contains_key(&address.to_string()),
+            _ => true,
+        };
+
+        listed(&instruction.vaults, jito_vault_program.vault())
+            && listed(&instruction.ncns, jito_vault_program.ncn())
+            && listed(&instruction.operators, jito_vault_program.operator())
+    }
+
+    /// Keep the staked amounts of a vault and operator up to date
+    ///
+    /// - Every `AddDelegation` and `CooldownDelegation` of a successful transaction, whether
+    ///   or not a rule matches it
+    /// - `None` for other instructions, or if the accounts could not be read
+    async fn track_operator_delegation(
+        &mut self,
+        parser: &JitoTransactionParser,
+        jito_vault_program: &JitoVaultProgram,
+    ) -> Option<DelegationTotals> {
+        if parser.failure.is_some() {
+            return None;
+        }
+
+        let (ix, change) = match jito_vault_program {
+            JitoVaultProgram::AddDelegation { ix, amount } => {
+                (ix, DelegationChange::Added(*amount))
+            }
+            JitoVaultProgram::CooldownDelegation { ix, amount } => {
+                (ix, DelegationChange::CooledDown(*amount))
+            }
+            _ => return None,
+        };
+        let vault_info = &ix.accounts[1];
+        let operator_info = &ix.accounts[2];
+        let vault_operator_delegation_info = &ix.accounts[3];
+
+        self.operator_delegations
+            .apply(
+                &vault_info.pubkey,
+                &operator_info.pubkey,
+                &vault_operator_delegation_info.pubkey,
+                change,
+                parser.slot,
+            )
+            .await
+    }
+
+    /// Follow a vault withdrawal ticket from enqueue to burn
+    ///
+    /// - Only tickets of vaults under `withdrawal_tickets`, from successful transactions
+    async fn track_withdrawal_ticket(
+        &mut self,
+        parser: &JitoTransactionParser,
+        jito_vault_program: &JitoVaultProgram,
+    ) {
+        let (Some(vault), Some(ticket_address)) = (
+            jito_vault_program.vault(),
+            jito_vault_program.withdrawal_ticket(),
+        ) else {
+            return;
+        };
+        if parser.failure.is_some()
+            || !self
+                .config
+                .withdrawal_tickets
+                .contains_key(&vault.to_string())
+        {
+            return;
+        }
+
+        match jito_vault_program {
+            JitoVaultProgram::EnqueueWithdrawal { ix, amount } => {
+                let config_info = &ix.accounts[0];
+                let staker_info = &ix.accounts[4];
+
+                self.vault_epoch_length(&config_info.pubkey).await;
+
+                let (divisor, symbol) = match self.withdrawal_tickets.vrt(vault) {
+                    Some(vrt) => vrt.clone(),
+                    None => {
+                        let Some(vault_mints) = self.mint_info.vault_mints(vault).await else {
+                            return;
+                        };
+                        let divisor = self.mint_info.divisor(&vault_mints.vrt_mint).await;
+                        let symbol = self.mint_info.symbol(&vault_mints.vrt_mint).await;
+                        self.withdrawal_tickets
+                            .set_vrt(*vault, divisor, symbol.clone());
+                        (divisor, symbol)
+                    }
+                };
+
+                self.withdrawal_tickets.enqueue(
+                    *ticket_address,
+                    WithdrawalTicket::new(
+                        *vault,
+                        staker_info.pubkey,
+                        *amount as f64 / divisor,
+                        symbol,
+                        parser.slot,
+                        parser.transaction_signature.clone(),
+                    ),
+                );
+            }
+            JitoVaultProgram::ChangeWithdrawalTicketOwner { ix } => {
+                let new_owner_info = &ix.accounts[4];
+                self.withdrawal_tickets
+                    .change_owner(ticket_address, new_owner_info.pubkey);
+            }
+            JitoVaultProgram::BurnWithdrawalTicket { .. } => {
+                self.withdrawal_tickets.burn(ticket_address);
+            }
+            _ => {}
+        }
+    }
+
+    /// Notify tracked withdrawal tickets that became claimable or were left unclaimed by `slot`
+    ///
+    /// - Notifications refer to the transaction that enqueued the ticket
+    fn check_withdrawal_tickets(&mut self, slot: u64) {
+        let configs = &self.config.withdrawal_tickets;
+        let events = self.withdrawal_tickets.due(slot, |vault| {
+            configs
+                .get(&vault.to_string())
+                .and_then(|config| config.unclaimed_epochs)
+        });
+
+        for event in events {
+            let (address, ticket) = match &event {
+                TicketEvent::Claimable { address, ticket }
+                | TicketEvent::Unclaimed {
+                    address, ticket, ..
+                } => (address, ticket),
+            };
+            let Some(config) = configs.get(&ticket.vault.to_string()) else {
+                continue;
+            };
+            let details = format!(
+                "Vault: {}\nTicket: {address}\nStaker: {}\nEnqueued in slot: {}",
+                self.label(&ticket.vault),
+                ticket.staker,
+                ticket.slot_unstaked
+            );
+
+            match event {
+                TicketEvent::Claimable { ref ticket, .. } => {
+                    let mut thresholds = config.claimable_thresholds.clone();
+                    self.sort_thresholds(&mut thresholds);
+                    if let Some(threshold) = thresholds
+                        .iter()
+                        .find(|threshold| ticket.vrt_amount >= threshold.value)
+                    {
+                        self.queue_notifications(
+                            &threshold.notification.destinations,
+                            &format!("{}\n\n{details}", threshold.notification.description),
+                            Some(ticket.vrt_amount),
+                            Some(&ticket.symbol),
+                            Some(&ticket.signature),
+                            slot,
+                        );
+                    }
+                }
+                TicketEvent::Unclaimed {
+                    ref ticket, epochs, ..
+                } => {
+                    if let Some(ref notification) = config.unclaimed_notification {
+                        self.queue_notifications(
+                            &notification.destinations,
+                            &format!(
+                                "{}\n\n{details}\nUnclaimed for {epochs} epochs after becoming claimable",
+                                notification.description
+                            ),
+                            Some(ticket.vrt_amount),
+                            Some(&ticket.symbol),
+                            Some(&ticket.signature),
+                            slot,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Follow the update cycle of a vault through its state tracker instructions
+    ///
+    /// - Only vaults under `vault_updates`, from successful transactions
+    /// - A vault reported stale is notified again once its update completes
+    async fn track_vault_update(
+        &mut self,
+        parser: &JitoTransactionParser,
+        jito_vault_program: &JitoVaultProgram,
+    ) {
+        let step = match jito_vault_program {
+            JitoVaultProgram::InitializeVaultUpdateStateTracker { .. } => UpdateStep::Started,
+            JitoVaultProgram::CrankVaultUpdateStateTracker { .. } => UpdateStep::Cranked,
+            JitoVaultProgram::CloseVaultUpdateStateTracker { ncn_epoch, .. } => {
+                UpdateStep::Closed {
+                    ncn_epoch: *ncn_epoch,
+                }
+            }
+            JitoVaultProgram::UpdateVaultBalance { .. } => UpdateStep::BalanceUpdated,
+            _ => return,
+        };
+        let (Some(config), Some(vault)) = (jito_vault_program.config(), jito_vault_program.vault())
+        else {
+            return;
+        };
+        if parser.failure.is_some() || self.vault_updates.get(vault).is_none() {
+            return;
+        }
+
+        self.vault_epoch_length(config).await;
+        let Some(cycle) = self.vault_updates.record(vault, step, parser.slot) else {
+            return;
+        };
+        if !cycle.stale_seen || cycle.completed_slot != Some(parser.slot) {
+            return;
+        }
+
+        if let Some(config) = self.config.vault_updates.get(&vault.to_string()) {
+            let description = format!(
+                "{}\n\nVault {} is updated again, {}",
+                config.notification.description,
+                self.label(vault),
+                self.vault_epoch_position(parser.slot)
+            );
+            self.dispatch_platform_notifications(
+                &config.notification.destinations,
+                &description,
+                None,
+                None,
+                parser,
+                None,
+            );
+        }
+    }
+
+    /// Notify vaults under `vault_updates` still not updated `stale_after_slots` into the
+    /// vault epoch of `slot`
+    fn check_vault_updates(&mut self, slot: u64) {
+        let configs = &self.config.vault_updates;
+        let stale = self.vault_updates.stale(slot, |vault| {
+            configs
+                .get(&vault.to_string())
+                .map(|config| config.stale_after_slots)
+        });
+
+        for (vault, cycle) in stale {
+            let Some(config) = configs.get(&vault.to_string()) else {
+                continue;
+            };
+            let description = format!(
+                "{}\n\nVault {} is not updated {}\n{}",
+                config.notification.description,
+                self.label(&vault),
+                self.vault_epoch_position(slot),
+                self.update_cycle_text(&cycle)
+            );
+            self.queue_notifications(
+                &config.notification.destinations,
+                &description,
+                None,
+                None,
+                None,
+                slot,
+            );
+        }
+    }
+
+    /// How far into its vault epoch `slot` is, e.g. "1200 slots (27.78%) into vault epoch 812"
+    ///
+    /// - Falls back to the slot while the vault program epoch length is unknown
+    fn vault_epoch_position(&self, slot: u64) -> String {
+        match (
+            self.vault_updates.epoch_length(),
+            self.vault_updates.epoch_position(slot),
+        ) {
+            (Some(epoch_length), Some((epoch, offset))) => format!(
+                "{offset} slots ({:.2}%) into vault epoch {epoch}",
+                offset as f64 / epoch_length as f64 * 100.0
+            ),
+            _ => format!("at slot {slot}"),
+        }
+    }
+
+    /// Progress of an update cycle, e.g. when it started and how many operators were cranked
+    fn update_cycle_text(&self, cycle: &UpdateCycle) -> String {
+        let started = match cycle.started_slot {
+            Some(slot) => format!("Started {}", self.vault_epoch_position(slot)),
+            None => "Not started".to_string(),
+        };
+        let balance_updated = match cycle.balance_updated_slot {
+            Some(slot) => format!("Balance updated {}", self.vault_epoch_position(slot)),
+            None => "Balance not updated".to_string(),
+        };
+        format!(
+            "{started}\nOperators cranked: {}\n{balance_updated}",
+            cycle.cranked_operators
+        )
+    }
+
+    /// Update cycle step of a state tracker or balance instruction in `slot`, naming accounts
+    /// with their label
+    ///
+    /// - e.g. "Vault X completed its update 1200 slots (27.78%) into vault epoch 812"
+    fn vault_update_event(&self, jito_vault_program: &JitoVaultProgram, slot: u64) -> String {
+        let vault = jito_vault_program
+            .vault()
+            .map(|vault| self.label(vault))
+            .unwrap_or_default();
+        let position = self.vault_epoch_position(slot);
+
+        match jito_vault_program {
+            JitoVaultProgram::InitializeVaultUpdateStateTracker { .. } => {
+                format!("Vault {vault} started its update {position}")
+            }
+            JitoVaultProgram::CrankVaultUpdateStateTracker { .. } => {
+                let operator = jito_vault_program
+                    .operator()
+                    .map(|operator| self.label(operator))
+                    .unwrap_or_default();
+                format!("Vault {vault} cranked operator {operator} {position}")
+            }
+            JitoVaultProgram::CloseVaultUpdateStateTracker { ncn_epoch, .. } => {
+                let current = self
+                    .vault_updates
+                    .epoch_position(slot)
+                    .is_none_or(|(epoch, _)| epoch == *ncn_epoch);
+                if !current {
+                    return format!(
+                        "Vault {vault} closed its stale update of vault epoch {ncn_epoch} {position}"
+                    );
+                }
+
+                let mut event = format!("Vault {vault} completed its update {position}");
+                if let Some(cycle) = jito_vault_program
+                    .vault()
+                    .and_then(|vault| self.vault_updates.get(vault))
+                    .filter(|cycle| cycle.epoch == *ncn_epoch)
+                {
+                    event.push_str(&format!("\n{}", self.update_cycle_text(cycle)));
+                }
+                event
+            }
+            _ => format!("Vault {vault} updated its balance {position}"),
+        }
+    }
+
+    /// Whether `vault` is listed under the instruction's `vaults`
+    ///
+    /// - Without the list every vault is matched
+    fn lists_vault(instruction: &Instruction, vault: &Pubkey) -> bool {
+        instruction
+            .vaults
+            .as_ref()
+            .is_none_or(|vaults| vaults.contains_key(&vault.to_string()))
+    }
+
+    /// Notify a vault program admin change with the old and new values
+    ///
+    /// - Old values come from the `Vault`, or the program `Config`, read before the transaction
+    /// - Snapshots are read again after a successful change
+    async fn dispatch_vault_governance_notifications(
+        &mut self,
+        parser: &JitoTransactionParser,
+        parsed: &ParsedInstruction,
+        jito_vault_program: &JitoVaultProgram,
+        notification_info: &NotificationInfo,
+    ) {
+        let (Some(config_address), Some(admin)) =
+            (jito_vault_program.config(), jito_vault_program.admin())
+        else {
+            return;
+        };
+        let vault_address = jito_vault_program.vault();
+
+        let (vault, config, mut description) = match vault_address {
+            Some(vault_address) => {
+                let vault = self
+                    .account_snapshots
+                    .before(vault_address, parser.slot)
+                    .await
+                    .and_then(|data| Vault::from_bytes(&data).ok());
+                let description = format!(
+                    "{}\n\nVault: {vault_address}\nAdmin: {admin}",
+                    notification_info.description
+                );
+                (vault, None, description)
+            }
+            None => {
+                let config = self
+                    .account_snapshots
+                    .before(config_address, parser.slot)
+                    .await
+                    .and_then(|data| VaultConfig::from_bytes(&data).ok());
+                let description = format!(
+                    "{}\n\nConfig: {config_address}\nAdmin: {admin}",
+                    notification_info.description
+                );
+                (None, config, description)
+            }
+        };
+
+        let changes =
+            governance::vault_changes(jito_vault_program, vault.as_ref(), config.as_ref());
+        for change in changes.iter() {
+            description.push_str(&format!("\n{change}"));
+        }
+
+        self.dispatch_platform_notifications(
+            &notification_info.destinations,
+            &description,
+            None,
+            None,
+            parser,
+            Some(parsed),
+        );
+
+        if parser.failure.is_none() {
+            let address = vault_address.unwrap_or(config_address);
+            self.account_snapshots.refresh(address, parser.slot).await;
+        }
+    }
+
+    /// Handle Jito Vault Program
     ///
     /// - Notify only once for the first matching threshold.
     async fn handle_jito_vault_program(
         &mut self,
         parser: &JitoTransactionParser,
+        parsed: &ParsedInstruction,
         jito_vault_program: &JitoVaultProgram,
         instruction: &Instruction,
+        delegation_totals: Option<DelegationTotals>,
     ) -> Result<(), JitoBellError> {
         debug!("Jito Vault Program: {}", jito_vault_program);
 
@@ -748,8 +2206,8 @@ impl JitoBellHandler {
                         vrts.get_key_value(&vrt_mint_info.pubkey.to_string())
                     {
                         let vrt = Pubkey::from_str(address).unwrap();
-                        let divisor = self.divisor(&vrt).await;
-                        let symbol = self.vrt_symbol(&vrt).await;
+                        let divisor = self.mint_info.divisor(&vrt).await;
+                        let symbol = self.mint_info.symbol(&vrt).await;
 
                         let mut thresholds = vrt_config.thresholds.clone();
                         self.sort_thresholds(&mut thresholds);
@@ -761,9 +2219,9 @@ impl JitoBellHandler {
                                     &threshold.notification.description,
                                     Some(min_amount_out),
                                     Some(&symbol),
-                                    &parser.transaction_signature,
-                                )
-                                .await?;
+                                    parser,
+                                    Some(parsed),
+                                );
                                 break;
                             }
                         }
@@ -779,8 +2237,9 @@ impl JitoBellHandler {
                 let _staker_vrt_token_account_info = &ix.accounts[5];
                 let _base_info = &ix.accounts[6];
 
-                let vault_acc = self.rpc_client.get_account(&vault_info.pubkey).await?;
-                let vault = Vault::deserialize(&mut vault_acc.data.as_slice())?;
+                let Some(vault) = self.mint_info.vault_mints(&vault_info.pubkey).await else {
+                    return Ok(());
+                };
 
                 // VRT amount
                 if let Some(ref vrts) = instruction.vrts {
@@ -788,8 +2247,8 @@ impl JitoBellHandler {
                         vrts.get_key_value(&vault.vrt_mint.to_string())
                     {
                         let vrt = Pubkey::from_str(address).unwrap();
-                        let divisor = self.divisor(&vrt).await;
-                        let symbol = self.vrt_symbol(&vrt).await;
+                        let divisor = self.mint_info.divisor(&vrt).await;
+                        let symbol = self.mint_info.symbol(&vrt).await;
 
                         let mut thresholds = vrt_config.thresholds.clone();
                         self.sort_thresholds(&mut thresholds);
@@ -801,9 +2260,9 @@ impl JitoBellHandler {
                                     &threshold.notification.description,
                                     Some(amount),
                                     Some(&symbol),
-                                    &parser.transaction_signature,
-                                )
-                                .await?;
+                                    parser,
+                                    Some(parsed),
+                                );
                                 break;
                             }
                         }
@@ -832,9 +2291,9 @@ impl JitoBellHandler {
                                             &usd_threshold.notification.description,
                                             Some(amount as f64),
                                             Some("USD"),
-                                            &parser.transaction_signature,
-                                        )
-                                        .await?;
+                                            parser,
+                                            Some(parsed),
+                                        );
                                         break;
                                     }
                                 }
@@ -843,291 +2302,373 @@ impl JitoBellHandler {
                     }
                 }
             }
-            JitoVaultProgram::InitializeConfig
-            | JitoVaultProgram::InitializeVault
-            | JitoVaultProgram::InitializeVaultWithMint
-            | JitoVaultProgram::InitializeVaultOperatorDelegation
-            | JitoVaultProgram::InitializeVaultNcnTicket
-            | JitoVaultProgram::InitializeVaultNcnSlasherOperatorTicket
-            | JitoVaultProgram::InitializeVaultNcnSlasherTicket
-            | JitoVaultProgram::WarmupVaultNcnTicket
-            | JitoVaultProgram::CooldownVaultNcnTicket
-            | JitoVaultProgram::WarmupVaultNcnSlasherTicket
-            | JitoVaultProgram::CooldownVaultNcnSlasherTicket
-            | JitoVaultProgram::ChangeWithdrawalTicketOwner
-            | JitoVaultProgram::BurnWithdrawalTicket
-            | JitoVaultProgram::SetDepositCapacity
-            | JitoVaultProgram::SetFees
-            | JitoVaultProgram::SetProgramFee
-            | JitoVaultProgram::SetProgramFeeWallet
-            | JitoVaultProgram::SetIsPaused
-            | JitoVaultProgram::DelegateTokenAccount
-            | JitoVaultProgram::SetAdmin
-            | JitoVaultProgram::SetSecondaryAdmin
-            | JitoVaultProgram::AddDelegation
-            | JitoVaultProgram::CooldownDelegation
-            | JitoVaultProgram::UpdateVaultBalance
-            | JitoVaultProgram::InitializeVaultUpdateStateTracker
-            | JitoVaultProgram::CrankVaultUpdateStateTracker
-            | JitoVaultProgram::CloseVaultUpdateStateTracker
-            | JitoVaultProgram::CreateTokenMetadata
-            | JitoVaultProgram::UpdateTokenMetadata
-            | JitoVaultProgram::SetConfigAdmin => {
-                unreachable!()
+            JitoVaultProgram::ChangeWithdrawalTicketOwner { ix } => {
+                let vault_info = &ix.accounts[1];
+                let ticket_info = &ix.accounts[2];
+                let old_owner_info = &ix.accounts[3];
+                let new_owner_info = &ix.accounts[4];
+
+                if let Some(ref notification_info) = instruction.notification_info {
+                    if Self::lists_vault(instruction, &vault_info.pubkey) {
+                        let mut description = format!(
+                            "{}\n\nVault: {}\nTicket: {}\nOwner: {} → {}",
+                            notification_info.description,
+                            self.label(&vault_info.pubkey),
+                            ticket_info.pubkey,
+                            old_owner_info.pubkey,
+                            new_owner_info.pubkey
+                        );
+                        if let Some(ticket) = self.withdrawal_tickets.get(&ticket_info.pubkey) {
+                            description.push_str(&format!(
+                                "\nAmount: {:.2} {}",
+                                ticket.vrt_amount, ticket.symbol
+                            ));
+                        }
+                        self.dispatch_platform_notifications(
+                            &notification_info.destinations,
+                            &description,
+                            None,
+                            None,
+                            parser,
+                            Some(parsed),
+                        );
+                    }
+                }
             }
-        }
-
-        Ok(())
-    }
+            JitoVaultProgram::BurnWithdrawalTicket { ix } => {
+                let vault_info = &ix.accounts[1];
+                let staker_info = &ix.accounts[4];
+                let staker_token_account_info = &ix.accounts[5];
+                let ticket_info = &ix.accounts[6];
+
+                let vault_config = instruction
+                    .vaults
+                    .as_ref()
+                    .and_then(|vaults| vaults.get(&vault_info.pubkey.to_string()));
+
+                // Tokens paid out are what the staker's token account received
+                if let (Some(vault_config), Some(token_balance)) = (
+                    vault_config,
+                    parser.token_balances.get(&staker_token_account_info.pubkey),
+                ) {
+                    let paid_out = token_balance.delta().max(0) as f64
+                        / 10_f64.powi(token_balance.decimals as i32);
+                    let symbol = self.mint_info.symbol(&token_balance.mint).await;
+
+                    let mut details = format!(
+                        "Vault: {}\nTicket: {}\nStaker: {}",
+                        self.label(&vault_info.pubkey),
+                        ticket_info.pubkey,
+                        staker_info.pubkey
+                    );
+                    if let Some(ticket) = self.withdrawal_tickets.get(&ticket_info.pubkey) {
+                        details.push_str(&format!(
+                            "\nBurned: {:.2} {} enqueued in slot {}",
+                            ticket.vrt_amount, ticket.symbol, ticket.slot_unstaked
+                        ));
+                    }
 
-    /// Sends a notification for each matching `CopyDirectedStakeTargets` instruction
-    /// that includes notification metadata.
-    async fn handle_jito_steward_program(
-        &mut self,
-        parser: &JitoTransactionParser,
-        jito_steward_instruction: &JitoStewardInstruction,
-        instruction: &Instruction,
-    ) -> Result<(), JitoBellError> {
-        debug!("Jito Steward Instruction: {jito_steward_instruction}");
-
-        if let JitoStewardInstruction::CopyDirectedStakeTargets {
-            ix: _,
-            vote_pubkey: _,
-            total_target_lamports,
-            validator_list_index: _,
-        } = jito_steward_instruction
-        {
-            if let Some(ref notification_info) = instruction.notification_info {
-                self.dispatch_platform_notifications(
-                    &notification_info.destinations,
-                    &notification_info.description,
-                    Some(*total_target_lamports as f64),
-                    Some("lamports"),
-                    &parser.transaction_signature,
-                )
-                .await?;
+                    self.dispatch_amount_notifications(
+                        parser,
+                        parsed,
+                        &vault_config.thresholds,
+                        paid_out,
+                        &symbol,
+                        &details,
+                    );
+                }
             }
-        }
-
-        Ok(())
-    }
+            JitoVaultProgram::AddDelegation { ix, amount }
+            | JitoVaultProgram::CooldownDelegation { ix, amount } => {
+                let _config_info = &ix.accounts[0];
+                let vault_info = &ix.accounts[1];
+                let operator_info = &ix.accounts[2];
+                let _vault_operator_delegation_info = &ix.accounts[3];
+
+                let vault_config = instruction
+                    .vaults
+                    .as_ref()
+                    .and_then(|vaults| vaults.get(&vault_info.pubkey.to_string()));
+                let operator_config = instruction
+                    .operators
+                    .as_ref()
+                    .and_then(|operators| operators.get(&operator_info.pubkey.to_string()));
+
+                if vault_config.is_some() || operator_config.is_some() {
+                    let Some(vault) = self.mint_info.vault_mints(&vault_info.pubkey).await else {
+                        return Ok(());
+                    };
+                    let divisor = self.mint_info.divisor(&vault.supported_mint).await;
+                    let symbol = self.mint_info.symbol(&vault.supported_mint).await;
+
+                    let mut details = format!(
+                        "Vault: {}\nOperator: {}",
+                        self.label(&vault_info.pubkey),
+                        self.label(&operator_info.pubkey)
+                    );
+                    if let Some(ref totals) = delegation_totals {
+                        details.push_str(&format!(
+                            "\nOperator total: {:.2} {symbol} ({:.2}% of vault)",
+                            totals.operator as f64 / divisor,
+                            totals.share()
+                        ));
+                    }
 
-    /// Dispatch platform notifications
-    ///
-    /// - Return error only if ALL platforms failed, or handle as needed
-    async fn dispatch_platform_notifications(
-        &mut self,
-        destinations: &[Destination],
-        description: &str,
-        amount: Option<f64>,
-        unit: Option<&str>,
-        transaction_signature: &str,
-    ) -> Result<(), JitoBellError> {
-        let mut errors = Vec::new();
+                    let amount = *amount as f64 / divisor;
+                    for alert_config in [vault_config, operator_config].into_iter().flatten() {
+                        self.dispatch_amount_notifications(
+                            parser,
+                            parsed,
+                            &alert_config.thresholds,
+                            amount,
+                            &symbol,
+                            &details,
+                        );
+                    }
 
-        for destination in destinations {
-            let result = match destination {
-                Destination::Telegram => {
-                    debug!("Will Send Telegram Notification");
-                    match (amount, unit) {
-                        (Some(amt), Some(u)) => {
-                            self.send_telegram_message(description, amt, u, transaction_signature)
-                                .await
-                        }
-                        _ => {
-                            debug!("Skipping Telegram - missing amount or unit");
-                            continue;
-                        }
+                    if let (Some(vault_config), Some(totals)) = (vault_config, delegation_totals) {
+                        self.dispatch_operator_share_notifications(
+                            parser,
+                            parsed,
+                            &vault_config.share_thresholds,
+                            &totals,
+                            &details,
+                        );
                     }
                 }
-                Destination::JitoBellSlack => {
-                    debug!("Will Send Slack Notification to Jito Bell");
-                    match (amount, unit) {
-                        (Some(amt), Some(u)) => {
-                            self.send_slack_message_to_jito_bell(
-                                description,
-                                amt,
-                                u,
-                                transaction_signature,
-                            )
-                            .await
-                        }
-                        _ => {
-                            debug!("Skipping JitoBellSlack - missing amount or unit");
-                            continue;
-                        }
+            }
+            JitoVaultProgram::SetDepositCapacity { .. }
+            | JitoVaultProgram::SetFees { .. }
+            | JitoVaultProgram::SetProgramFee { .. }
+            | JitoVaultProgram::SetProgramFeeWallet { .. }
+            | JitoVaultProgram::SetIsPaused { .. }
+            | JitoVaultProgram::SetAdmin { .. }
+            | JitoVaultProgram::SetSecondaryAdmin { .. }
+            | JitoVaultProgram::SetConfigAdmin { .. } => {
+                if let Some(ref notification_info) = instruction.notification_info {
+                    // Program config changes apply to every vault
+                    let listed = jito_vault_program
+                        .vault()
+                        .is_none_or(|vault| Self::lists_vault(instruction, vault));
+                    if listed {
+                        self.dispatch_vault_governance_notifications(
+                            parser,
+                            parsed,
+                            jito_vault_program,
+                            notification_info,
+                        )
+                        .await;
                     }
                 }
-                Destination::StakePoolAlertsSlack => {
-                    debug!("Will Send Slack Notification to Stake Pool Alerts");
-                    self.send_slack_message_to_stake_pool_alerts(description, transaction_signature)
-                        .await
-                }
-                Destination::StakenetEventAlertsSlack => {
-                    debug!("Will Send Slack Notification to Stakenet Event Alerts");
-                    self.send_slack_message_to_stakenet_event_alerts(
-                        description,
-                        transaction_signature,
-                    )
-                    .await
-                }
-                Destination::Discord => {
-                    debug!("Will Send Discord Notification");
-                    match (amount, unit) {
-                        (Some(amt), Some(u)) => {
-                            self.send_discord_message(description, amt, u, transaction_signature)
-                                .await
-                        }
-                        _ => {
-                            debug!("Skipping Discord - missing amount or unit");
-                            continue;
+            }
+            JitoVaultProgram::InitializeVaultOperatorDelegation { .. }
+            | JitoVaultProgram::InitializeVaultNcnTicket { .. }
+            | JitoVaultProgram::InitializeVaultNcnSlasherOperatorTicket { .. }
+            | JitoVaultProgram::InitializeVaultNcnSlasherTicket { .. }
+            | JitoVaultProgram::WarmupVaultNcnTicket { .. }
+            | JitoVaultProgram::CooldownVaultNcnTicket { .. }
+            | JitoVaultProgram::WarmupVaultNcnSlasherTicket { .. }
+            | JitoVaultProgram::CooldownVaultNcnSlasherTicket { .. } => {
+                if let Some(ref notification_info) = instruction.notification_info {
+                    if Self::lists_restaking_accounts(instruction, jito_vault_program) {
+                        if let Some(event) =
+                            jito_vault_program.ticket_event(|address| self.label(address))
+                        {
+                            let mut description =
+                                format!("{}\n\n{event}", notification_info.description);
+                            if let Some(admin) = jito_vault_program.admin() {
+                                description.push_str(&format!("\nAdmin: {admin}"));
+                            }
+                            self.dispatch_platform_notifications(
+                                &notification_info.destinations,
+                                &description,
+                                None,
+                                None,
+                                parser,
+                                Some(parsed),
+                            );
                         }
                     }
                 }
-                Destination::Twitter => {
-                    debug!("Will Send Twitter Notification");
-                    match (amount, unit) {
-                        (Some(amt), Some(u)) => {
-                            self.send_twitter_message(description, amt, u, transaction_signature)
-                                .await
-                        }
-                        _ => {
-                            debug!("Skipping Twitter - missing amount or unit");
-                            continue;
+            }
+            JitoVaultProgram::UpdateVaultBalance { .. }
+            | JitoVaultProgram::InitializeVaultUpdateStateTracker { .. }
+            | JitoVaultProgram::CrankVaultUpdateStateTracker { .. }
+            | JitoVaultProgram::CloseVaultUpdateStateTracker { .. } => {
+                if let Some(ref notification_info) = instruction.notification_info {
+                    if Self::lists_restaking_accounts(instruction, jito_vault_program) {
+                        if let Some(config) = jito_vault_program.config() {
+                            self.vault_epoch_length(config).await;
                         }
+                        let description = format!(
+                            "{}\n\n{}",
+                            notification_info.description,
+                            self.vault_update_event(jito_vault_program, parser.slot)
+                        );
+                        self.dispatch_platform_notifications(
+                            &notification_info.destinations,
+                            &description,
+                            None,
+                            None,
+                            parser,
+                            Some(parsed),
+                        );
                     }
                 }
-            };
-
-            if let Err(e) = result {
-                error!("Failed to send to {}: {:?}", destination, e);
-                errors.push((destination.clone(), e));
+            }
+            JitoVaultProgram::InitializeConfig
+            | JitoVaultProgram::InitializeVault
+            | JitoVaultProgram::InitializeVaultWithMint
+            | JitoVaultProgram::DelegateTokenAccount
+            | JitoVaultProgram::CreateTokenMetadata
+            | JitoVaultProgram::UpdateTokenMetadata => {
+                unreachable!()
             }
         }
 
-        if !errors.is_empty() && errors.len() == destinations.len() {
-            Err(JitoBellError::Notification(
-                "All platforms failed".to_string(),
-            ))
-        } else {
-            Ok(())
-        }
+        Ok(())
     }
 
-    /// Send message to Telegram
-    async fn send_telegram_message(
+    /// Handle SPL Token 2022 Program
+    ///
+    /// - Amounts are matched against the `vrts` or `lsts` thresholds of the mint
+    /// - Transfer fee instructions notify `notification_info` for mints listed in `vrts` or `lsts`
+    async fn handle_spl_token_2022_program(
         &mut self,
-        description: &str,
-        amount: f64,
-        unit: &str,
-        sig: &str,
+        parser: &JitoTransactionParser,
+        parsed: &ParsedInstruction,
+        spl_token_2022_program: &SplToken2022Program,
+        instruction: &Instruction,
     ) -> Result<(), JitoBellError> {
-        if let Some(bot_token) = &self.subscribe_option.telegram_bot_token {
-            if let Some(chat_id) = &self.subscribe_option.telegram_chat_id {
-                let template = self
-                    .config
-                    .message_templates
-                    .get("telegram")
-                    .unwrap_or(self.config.message_templates.get("default").unwrap());
-                let message = template
-                    .replace("{{description}}", description)
-                    .replace("{{amount}}", &format!("{:.2}", amount))
-                    .replace("{{currency_unit}}", unit)
-                    .replace("{{tx_hash}}", sig);
-
-                let url = format!("https://api.telegram.org/bot{}/sendMessage", bot_token);
-
-                let client = reqwest::Client::new();
-                let response = client
-                    .post(&url)
-                    .form(&[("chat_id", chat_id), ("text", &message)])
-                    .send()
+        debug!("SPL Token 2022 Program: {}", spl_token_2022_program);
+
+        match spl_token_2022_program {
+            SplToken2022Program::MintTo { amount, .. }
+            | SplToken2022Program::Burn { amount, .. } => {
+                if let Some(mint) = spl_token_2022_program.mint() {
+                    self.dispatch_token_amount_notifications(
+                        parser,
+                        parsed,
+                        instruction,
+                        mint,
+                        *amount,
+                        None,
+                    )
                     .await;
-
-                match response {
-                    Ok(res) => {
-                        if res.status().is_success() {
-                            self.epoch_metrics.increment_success_notification_count();
-                            return Ok(());
-                        } else {
-                            self.epoch_metrics.increment_fail_notification_count();
-                            return Err(JitoBellError::Notification(format!(
-                                "Failed to send Telegram message: {}",
-                                res.status(),
-                            )));
-                        }
-                    }
-                    Err(e) => {
-                        self.epoch_metrics.increment_fail_notification_count();
-                        return Err(JitoBellError::Notification(format!(
-                            "Failed to send Telegram message: {}",
-                            e
-                        )));
+                }
+            }
+            SplToken2022Program::MintToChecked {
+                amount, decimals, ..
+            }
+            | SplToken2022Program::BurnChecked {
+                amount, decimals, ..
+            }
+            | SplToken2022Program::TransferChecked {
+                amount, decimals, ..
+            }
+            | SplToken2022Program::TransferCheckedWithFee {
+                amount, decimals, ..
+            } => {
+                if let Some(mint) = spl_token_2022_program.mint() {
+                    self.dispatch_token_amount_notifications(
+                        parser,
+                        parsed,
+                        instruction,
+                        mint,
+                        *amount,
+                        Some(*decimals),
+                    )
+                    .await;
+                }
+            }
+            SplToken2022Program::Transfer { ix, amount } => {
+                let source_info = &ix.accounts[0];
+                let _destination_info = &ix.accounts[1];
+                let _authority_info = &ix.accounts[2];
+
+                if let Some(mint) = self.transfer_mint(&source_info.pubkey).await {
+                    self.dispatch_token_amount_notifications(
+                        parser,
+                        parsed,
+                        instruction,
+                        &mint,
+                        *amount,
+                        None,
+                    )
+                    .await;
+                }
+            }
+            SplToken2022Program::WithdrawWithheldTokensFromMint { ix }
+            | SplToken2022Program::WithdrawWithheldTokensFromAccounts { ix, .. } => {
+                let mint_info = &ix.accounts[0];
+                let destination_info = &ix.accounts[1];
+                let _withdraw_withheld_authority_info = &ix.accounts[2];
+
+                if let Some(ref notification_info) = instruction.notification_info {
+                    if Self::lists_mint(instruction, &mint_info.pubkey) {
+                        let description = format!(
+                            "{}\n\nMint: {}\nDestination: {}",
+                            notification_info.description,
+                            mint_info.pubkey,
+                            destination_info.pubkey
+                        );
+                        self.dispatch_platform_notifications(
+                            &notification_info.destinations,
+                            &description,
+                            None,
+                            None,
+                            parser,
+                            Some(parsed),
+                        );
                     }
                 }
             }
-        }
-
-        Ok(())
-    }
-
-    /// Send message to Discord
-    async fn send_discord_message(
-        &mut self,
-        description: &str,
-        amount: f64,
-        unit: &str,
-        sig: &str,
-    ) -> Result<(), JitoBellError> {
-        if let Some(webhook_url) = &self.subscribe_option.discord_webhook_url {
-            let payload = serde_json::json!({
-                "embeds": [{
-                    "title": "New Transaction Detected",
-                    "description": description,
-                    "color": 3447003, // Blue color
-                    "fields": [
-                        {
-                            "name": "Amount",
-                            "value": format!("{:.2} {unit}", amount),
-                            "inline": true
-                        },
-                        {
-                            "name": "Transaction",
-                            "value": format!("[View on Explorer]({}/tx/{})", self.config.explorer_url, sig),
-                            "inline": true
-                        }
-                    ],
-                    "timestamp": chrono::Utc::now().to_rfc3339()
-                }]
-            });
-
-            let client = reqwest::Client::new();
-            let response = client
-                .post(webhook_url)
-                .header("Content-Type", "application/json")
-                .json(&payload)
-                .send()
-                .await;
-
-            match response {
-                Ok(res) => {
-                    if res.status().is_success() {
-                        self.epoch_metrics.increment_success_notification_count();
-                        return Ok(());
-                    } else {
-                        self.epoch_metrics.increment_fail_notification_count();
-                        return Err(JitoBellError::Notification(format!(
-                            "Failed to send Discord message: {:?}",
-                            res.status(),
-                        )));
+            SplToken2022Program::HarvestWithheldTokensToMint { ix } => {
+                let mint_info = &ix.accounts[0];
+
+                if let Some(ref notification_info) = instruction.notification_info {
+                    if Self::lists_mint(instruction, &mint_info.pubkey) {
+                        let description = format!(
+                            "{}\n\nMint: {}",
+                            notification_info.description, mint_info.pubkey
+                        );
+                        self.dispatch_platform_notifications(
+                            &notification_info.destinations,
+                            &description,
+                            None,
+                            None,
+                            parser,
+                            Some(parsed),
+                        );
                     }
                 }
-                Err(e) => {
-                    self.epoch_metrics.increment_fail_notification_count();
-                    return Err(JitoBellError::Notification(format!(
-                        "Error sending Discord message: {:?}",
-                        e
-                    )));
+            }
+            SplToken2022Program::SetTransferFee {
+                ix,
+                transfer_fee_basis_points,
+                maximum_fee,
+            } => {
+                let mint_info = &ix.accounts[0];
+                let _transfer_fee_config_authority_info = &ix.accounts[1];
+
+                if let Some(ref notification_info) = instruction.notification_info {
+                    if Self::lists_mint(instruction, &mint_info.pubkey) {
+                        let description = format!(
+                            "{}\n\nMint: {}\nTransfer fee: {} bps\nMaximum fee: {}",
+                            notification_info.description,
+                            mint_info.pubkey,
+                            transfer_fee_basis_points,
+                            maximum_fee
+                        );
+                        self.dispatch_platform_notifications(
+                            &notification_info.destinations,
+                            &description,
+                            None,
+                            None,
+                            parser,
+                            Some(parsed),
+                        );
+                    }
                 }
             }
         }
@@ -1135,281 +2676,232 @@ impl JitoBellHandler {
         Ok(())
     }
 
-    /// Send message to Slack to Jito Bell Channel
-    async fn send_slack_message_to_jito_bell(
-        &mut self,
-        description: &str,
-        amount: f64,
-        unit: &str,
-        sig: &str,
-    ) -> Result<(), JitoBellError> {
-        // Build a Slack message with blocks for better formatting
-        if let Some(webhook_url) = &self.subscribe_option.jito_bell_slack_webhook_url {
-            let payload = serde_json::json!({
-                "blocks": [
-                    {
-                        "type": "header",
-                        "text": {
-                            "type": "plain_text",
-                            "text": "New Transaction Detected"
-                        }
-                    },
-                    {
-                        "type": "section",
-                        "text": {
-                            "type": "mrkdwn",
-                            "text": format!("*Description:* {}", description)
-                        }
-                    },
-                    {
-                        "type": "section",
-                        "fields": [
-                            {
-                                "type": "mrkdwn",
-                                "text": format!("*Amount:* {:.2} {unit}", amount)
-                            },
-                            {
-                                "type": "mrkdwn",
-                                "text": format!("*Transaction:* <{}/tx/{}|View on Explorer>", self.config.explorer_url, sig)
-                            }
-                        ]
-                    }
-                ]
-            });
+    /// Whether `mint` is listed under the instruction's `vrts` or `lsts`
+    ///
+    /// - Without either list every mint is matched
+    fn lists_mint(instruction: &Instruction, mint: &Pubkey) -> bool {
+        let mint = mint.to_string();
+        match (&instruction.vrts, &instruction.lsts) {
+            (None, None) => true,
+            (vrts, lsts) => [vrts, lsts]
+                .into_iter()
+                .flatten()
+                .any(|mints| mints.contains_key(&mint)),
+        }
+    }
 
-            let client = reqwest::Client::new();
-            let response = client
-                .post(webhook_url)
-                .header("Content-Type", "application/json")
-                .json(&payload)
-                .send()
-                .await;
+    /// Handle SPL Token program
+    ///
+    /// - Alert on the `vrts` or `lsts` thresholds of the mint, like SPL Token 2022
+    async fn handle_spl_token_program(
+        &self,
+        parser: &JitoTransactionParser,
+        parsed: &ParsedInstruction,
+        spl_token_program: &SplTokenProgram,
+        instruction: &Instruction,
+    ) {
+        debug!("SPL Token Program: {}", spl_token_program);
 
-            match response {
-                Ok(res) => {
-                    if res.status().is_success() {
-                        self.epoch_metrics.increment_success_notification_count();
-                        return Ok(());
-                    } else {
-                        self.epoch_metrics.increment_fail_notification_count();
-                        return Err(JitoBellError::Notification(format!(
-                            "Failed to send Slack message: Status {}",
-                            res.status()
-                        )));
-                    }
-                }
-                Err(e) => {
-                    self.epoch_metrics.increment_fail_notification_count();
-                    return Err(JitoBellError::Notification(format!(
-                        "Slack request error: {}",
-                        e
-                    )));
-                }
+        let (mint, amount, decimals) = match spl_token_program {
+            SplTokenProgram::MintTo { amount, .. } | SplTokenProgram::Burn { amount, .. } => {
+                (spl_token_program.mint().copied(), *amount, None)
+            }
+            SplTokenProgram::MintToChecked {
+                amount, decimals, ..
+            }
+            | SplTokenProgram::BurnChecked {
+                amount, decimals, ..
             }
+            | SplTokenProgram::TransferChecked {
+                amount, decimals, ..
+            } => (spl_token_program.mint().copied(), *amount, Some(*decimals)),
+            SplTokenProgram::Transfer { ix, amount } => {
+                let source_info = &ix.accounts[0];
+                (self.transfer_mint(&source_info.pubkey).await, *amount, None)
+            }
+        };
+
+        if let Some(mint) = mint {
+            self.dispatch_token_amount_notifications(
+                parser,
+                parsed,
+                instruction,
+                &mint,
+                amount,
+                decimals,
+            )
+            .await;
         }
+    }
 
-        Ok(())
+    /// Mint of the source token account of a `Transfer`
+    ///
+    /// - The source may already be closed by a later instruction
+    async fn transfer_mint(&self, source: &Pubkey) -> Option<Pubkey> {
+        match self.rpc_client.get_account(source).await {
+            Ok(source_acc) => StateWithExtensions::<TokenAccount>::unpack(&source_acc.data)
+                .map(|source| source.base.mint)
+                .ok(),
+            Err(e) => {
+                debug!("Failed to fetch source of transfer: {e}");
+                None
+            }
+        }
     }
 
-    /// Send message to Slack to Stake Pool Alerts Channel
-    async fn send_slack_message_to_stake_pool_alerts(
-        &mut self,
-        description: &str,
-        sig: &str,
-    ) -> Result<(), JitoBellError> {
-        // Build a Slack message with blocks for better formatting
-        if let Some(webhook_url) = &self.subscribe_option.stake_pool_alerts_slack_webhook_url {
-            let payload = serde_json::json!({
-                "blocks": [
-                    {
-                        "type": "header",
-                        "text": {
-                            "type": "plain_text",
-                            "text": "New Transaction Detected"
-                        }
-                    },
-                    {
-                        "type": "section",
-                        "text": {
-                            "type": "mrkdwn",
-                            "text": format!("*Description:* {}", description)
-                        }
-                    },
-                    {
-                        "type": "section",
-                        "fields": [
-                            {
-                                "type": "mrkdwn",
-                                "text": format!("*Transaction:* <{}/tx/{}|View on Explorer>", self.config.explorer_url, sig)
-                            }
-                        ]
-                    }
-                ]
-            });
+    /// Notify the first threshold of `mint` under `vrts` or `lsts` that `amount` reaches
+    ///
+    /// - `decimals` comes from checked instructions, otherwise it is read from the mint
+    async fn dispatch_token_amount_notifications(
+        &self,
+        parser: &JitoTransactionParser,
+        parsed: &ParsedInstruction,
+        instruction: &Instruction,
+        mint: &Pubkey,
+        amount: u64,
+        decimals: Option<u8>,
+    ) {
+        let mint_str = mint.to_string();
+        let Some(alert_config) = [&instruction.vrts, &instruction.lsts]
+            .into_iter()
+            .flatten()
+            .find_map(|mints| mints.get(&mint_str))
+        else {
+            return;
+        };
 
-            let client = reqwest::Client::new();
-            let response = client
-                .post(webhook_url)
-                .header("Content-Type", "application/json")
-                .json(&payload)
-                .send()
-                .await;
+        let divisor = match decimals {
+            Some(decimals) => 10_f64.powi(decimals as i32),
+            None => self.mint_info.divisor(mint).await,
+        };
+        let symbol = self.mint_info.symbol(mint).await;
+        let amount = amount as f64 / divisor;
 
-            match response {
-                Ok(res) => {
-                    if res.status().is_success() {
-                        self.epoch_metrics.increment_success_notification_count();
-                        return Ok(());
-                    } else {
-                        self.epoch_metrics.increment_fail_notification_count();
-                        return Err(JitoBellError::Notification(format!(
-                            "Failed to send Slack message: Status {}",
-                            res.status()
-                        )));
-                    }
-                }
-                Err(e) => {
-                    self.epoch_metrics.increment_fail_notification_count();
-                    return Err(JitoBellError::Notification(format!(
-                        "Slack request error: {}",
-                        e
-                    )));
-                }
+        let mut thresholds = alert_config.thresholds.clone();
+        self.sort_thresholds(&mut thresholds);
+        for threshold in thresholds.iter() {
+            if amount >= threshold.value {
+                self.dispatch_platform_notifications(
+                    &threshold.notification.destinations,
+                    &threshold.notification.description,
+                    Some(amount),
+                    Some(&symbol),
+                    parser,
+                    Some(parsed),
+                );
+                break;
             }
         }
-
-        Ok(())
     }
 
-    /// Send message to Slack to Stakenet Event Alerts Channel
-    async fn send_slack_message_to_stakenet_event_alerts(
+    /// Sends a notification for each matching `CopyDirectedStakeTargets` instruction
+    /// that includes notification metadata.
+    async fn handle_jito_steward_program(
         &mut self,
-        description: &str,
-        sig: &str,
+        parser: &JitoTransactionParser,
+        parsed: &ParsedInstruction,
+        jito_steward_instruction: &JitoStewardInstruction,
+        instruction: &Instruction,
     ) -> Result<(), JitoBellError> {
-        // Build a Slack message with blocks for better formatting
-        if let Some(webhook_url) = &self
-            .subscribe_option
-            .stakenet_event_alerts_slack_webhook_url
-        {
-            let payload = serde_json::json!({
-                "blocks": [
-                    {
-                        "type": "header",
-                        "text": {
-                            "type": "plain_text",
-                            "text": "New Transaction Detected"
-                        }
-                    },
-                    {
-                        "type": "section",
-                        "text": {
-                            "type": "mrkdwn",
-                            "text": format!("*Description:* {}", description)
-                        }
-                    },
-                    {
-                        "type": "section",
-                        "fields": [
-                            {
-                                "type": "mrkdwn",
-                                "text": format!("*Transaction:* <{}/tx/{}|View on Explorer>", self.config.explorer_url, sig)
-                            }
-                        ]
-                    }
-                ]
-            });
-
-            let client = reqwest::Client::new();
-            let response = client
-                .post(webhook_url)
-                .header("Content-Type", "application/json")
-                .json(&payload)
-                .send()
-                .await;
+        debug!("Jito Steward Instruction: {jito_steward_instruction}");
 
-            match response {
-                Ok(res) => {
-                    if res.status().is_success() {
-                        self.epoch_metrics.increment_success_notification_count();
-                        return Ok(());
-                    } else {
-                        self.epoch_metrics.increment_fail_notification_count();
-                        return Err(JitoBellError::Notification(format!(
-                            "Failed to send Slack message: Status {}",
-                            res.status()
-                        )));
-                    }
-                }
-                Err(e) => {
-                    self.epoch_metrics.increment_fail_notification_count();
-                    return Err(JitoBellError::Notification(format!(
-                        "Slack request error: {}",
-                        e
-                    )));
-                }
+        if let JitoStewardInstruction::CopyDirectedStakeTargets {
+            ix: _,
+            vote_pubkey: _,
+            total_target_lamports,
+            validator_list_index: _,
+        } = jito_steward_instruction
+        {
+            if let Some(ref notification_info) = instruction.notification_info {
+                self.dispatch_platform_notifications(
+                    &notification_info.destinations,
+                    &notification_info.description,
+                    Some(*total_target_lamports as f64),
+                    Some("lamports"),
+                    parser,
+                    Some(parsed),
+                );
             }
         }
 
         Ok(())
     }
 
-    /// Send message to Twitter
-    async fn send_twitter_message(
-        &mut self,
+    /// Dispatch platform notifications for a parsed transaction
+    ///
+    /// - `instruction` is the instruction that triggered the notification, if any
+    fn dispatch_platform_notifications(
+        &self,
+        destinations: &[Destination],
         description: &str,
-        amount: f64,
-        unit: &str,
-        sig: &str,
-    ) -> Result<(), JitoBellError> {
-        let (api_key, api_secret, access_token, access_token_secret) = match (
-            &self.subscribe_option.twitter_api_key,
-            &self.subscribe_option.twitter_api_secret,
-            &self.subscribe_option.twitter_access_token,
-            &self.subscribe_option.twitter_access_token_secret,
-        ) {
-            (Some(key), Some(secret), Some(token), Some(token_secret)) => {
-                (key, secret, token, token_secret)
-            }
-            _ => return Ok(()),
-        };
-
-        let credentials =
-            TwitterCredentials::new(api_key, api_secret, access_token, access_token_secret);
-
-        let client = TwitterClient::new(credentials);
+        amount: Option<f64>,
+        unit: Option<&str>,
+        parser: &JitoTransactionParser,
+        instruction: Option<&ParsedInstruction>,
+    ) {
+        let mut description = description.to_string();
+        if let Some(instruction) = instruction.filter(|instruction| instruction.is_cpi()) {
+            description.push_str(&format!(
+                "\n\nInvoked via CPI by {}",
+                instruction.top_level_program_id
+            ));
+        }
+        if let Some(failure) = &parser.failure {
+            description.push_str(&format!("\n\nTransaction failed: {failure}"));
+        }
 
-        let mut tweet_text = format!(
-            "Jito Bell\n\n🚨 {}\n\n💰 Amount: {:.2} {}\n🔗 Transaction: {}/tx/{}\n\n",
-            description, amount, unit, self.config.explorer_url, sig,
+        self.queue_notifications(
+            destinations,
+            &description,
+            amount,
+            unit,
+            Some(&parser.transaction_signature),
+            parser.slot,
         );
+    }
 
-        // Check Twitter's 280 character limit
-        if tweet_text.len() > 280 {
-            // Create a shorter version
-            let short_text = format!(
-                "Jito Bell\n\n🚨 {}\n💰 {:.2} {}\n🔗 {}/tx/{}\n",
-                description,
-                amount,
-                unit,
-                self.config.explorer_url,
-                &sig[..8], // Truncate hash
+    /// Queue a notification per destination without waiting for delivery
+    ///
+    /// - Block time is attached if cached, otherwise resolved at delivery
+    fn queue_notifications(
+        &self,
+        destinations: &[Destination],
+        description: &str,
+        amount: Option<f64>,
+        unit: Option<&str>,
+        transaction_signature: Option<&str>,
+        slot: u64,
+    ) {
+        if self.dry_run {
+            let destinations = destinations
+                .iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<String>>()
+                .join(", ");
+            info!(
+                "[dry run] {destinations}: {description} | amount: {amount:?} {} | tx: {} | slot: {slot}",
+                unit.unwrap_or_default(),
+                transaction_signature.unwrap_or("-")
             );
-            tweet_text = short_text;
+            return;
         }
 
-        match client.tweet(tweet_text).await {
-            Ok(_res) => {
-                self.epoch_metrics.increment_success_notification_count();
-                Ok(())
-            }
-            Err(e) => {
-                self.epoch_metrics.increment_fail_notification_count();
-                Err(JitoBellError::Notification(format!(
-                    "Error sending Twitter message: {:?}",
-                    e
-                )))
-            }
+        let epoch_progress = Some(self.epoch_progress(slot));
+        let block_time = self.block_times.get(slot);
+        let queued_at = Instant::now();
+
+        for destination in destinations {
+            self.delivery.enqueue(Notification {
+                destination: *destination,
+                description: description.to_string(),
+                amount,
+                unit: unit.map(str::to_string),
+                transaction_signature: transaction_signature.map(str::to_string),
+                slot,
+                block_time,
+                epoch_progress,
+                queued_at,
+            });
         }
     }
 }
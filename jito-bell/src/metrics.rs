@@ -1,7 +1,31 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+/// Notification delivery counters, shared with the delivery queue
 #[derive(Debug, Default)]
 pub(crate) struct NotificationMetrics {
-    pub(crate) success: u64,
-    pub(crate) fail: u64,
+    success: AtomicU64,
+    fail: AtomicU64,
+}
+
+impl NotificationMetrics {
+    pub fn increment_success(&self) {
+        self.success.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn increment_fail(&self) {
+        self.fail.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn success(&self) -> u64 {
+        self.success.load(Ordering::Relaxed)
+    }
+
+    pub fn fail(&self) -> u64 {
+        self.fail.load(Ordering::Relaxed)
+    }
 }
 
 #[derive(Debug, Default)]
@@ -13,7 +37,13 @@ pub(crate) struct EpochMetrics {
     pub(crate) tx: u64,
 
     /// Notification Metrics
-    pub(crate) notification: NotificationMetrics,
+    pub(crate) notification: Arc<NotificationMetrics>,
+
+    /// Geyser reconnect attempts
+    pub(crate) reconnect: u64,
+
+    /// Slots missed while disconnected
+    pub(crate) missed_slots: u64,
 }
 
 impl EpochMetrics {
@@ -24,15 +54,70 @@ impl EpochMetrics {
         }
     }
 
+    /// Start counting `epoch`, keeping the notification counters shared with delivery
+    pub fn reset(&mut self, epoch: u64) {
+        self.notification.success.store(0, Ordering::Relaxed);
+        self.notification.fail.store(0, Ordering::Relaxed);
+        *self = Self {
+            epoch,
+            notification: self.notification.clone(),
+            ..Default::default()
+        };
+    }
+
     pub fn increment_tx_count(&mut self) {
         self.tx += 1;
     }
 
-    pub fn increment_success_notification_count(&mut self) {
-        self.notification.success += 1;
+    pub fn increment_reconnect_count(&mut self) {
+        self.reconnect += 1;
+    }
+
+    pub fn add_missed_slots(&mut self, count: u64) {
+        self.missed_slots += count;
+    }
+}
+
+/// Delivery metrics for a single transaction source
+#[derive(Debug, Default)]
+pub(crate) struct EndpointMetrics {
+    /// Name of the source
+    pub(crate) name: String,
+
+    /// Highest slot received from this endpoint
+    pub(crate) last_slot: Option<u64>,
+
+    /// Last slot received before the endpoint disconnected
+    pub(crate) disconnected_at_slot: Option<u64>,
+
+    /// Transactions this endpoint delivered before any other endpoint
+    pub(crate) first_delivered: u64,
+
+    /// Transactions already delivered by another endpoint
+    pub(crate) duplicate: u64,
+
+    /// Total delay of duplicate deliveries behind the first delivery, in milliseconds
+    pub(crate) duplicate_delay_ms: u64,
+
+    /// Reconnect attempts
+    pub(crate) reconnect: u64,
+
+    /// Slots missed while disconnected
+    pub(crate) missed_slots: u64,
+}
+
+impl EndpointMetrics {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            ..Default::default()
+        }
     }
 
-    pub fn increment_fail_notification_count(&mut self) {
-        self.notification.fail += 1;
+    /// Reset the counters reported once per interval
+    pub fn reset_interval_counters(&mut self) {
+        self.first_delivered = 0;
+        self.duplicate = 0;
+        self.duplicate_delay_ms = 0;
     }
 }
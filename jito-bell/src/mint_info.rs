@@ -0,0 +1,146 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use borsh::BorshDeserialize;
+use jito_vault_client::{accounts::Vault, log::metadata::Metadata};
+use log::debug;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use spl_token_2022::{extension::StateWithExtensions, state::Mint};
+
+use crate::DEFAULT_VRT_SYMBOL;
+
+/// Mints of a vault, fixed at initialization
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct VaultMints {
+    /// VRT mint
+    pub(crate) vrt_mint: Pubkey,
+
+    /// Mint of the token the vault accepts
+    pub(crate) supported_mint: Pubkey,
+}
+
+/// Decimals and symbols of mints, and mints of vaults, read once over RPC
+///
+/// - Keeps rule evaluation from reading the same accounts on every matching instruction
+/// - Only successful reads are cached, a failed read is tried again next time
+pub(crate) struct MintInfoCache {
+    /// RPC Client
+    rpc_client: Arc<RpcClient>,
+
+    /// Divisor of each mint, from its decimals
+    divisors: Mutex<HashMap<Pubkey, f64>>,
+
+    /// Symbol of each mint, from its token metadata
+    symbols: Mutex<HashMap<Pubkey, String>>,
+
+    /// Mints of each vault
+    vaults: Mutex<HashMap<Pubkey, VaultMints>>,
+}
+
+impl MintInfoCache {
+    /// Initialize mint info cache
+    pub(crate) fn new(rpc_client: Arc<RpcClient>) -> Self {
+        Self {
+            rpc_client,
+            divisors: Mutex::new(HashMap::new()),
+            symbols: Mutex::new(HashMap::new()),
+            vaults: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Divisor turning base units of `mint` into tokens
+    ///
+    /// - Token-2022 mints may carry extensions after the base mint
+    /// - Assumes 9 decimals if the mint cannot be read
+    pub(crate) async fn divisor(&self, mint: &Pubkey) -> f64 {
+        if let Some(divisor) = self.divisors.lock().ok().and_then(|d| d.get(mint).copied()) {
+            return divisor;
+        }
+
+        let decimals = match self.rpc_client.get_account(mint).await {
+            Ok(mint_acc) => StateWithExtensions::<Mint>::unpack(&mint_acc.data)
+                .map(|mint| mint.base.decimals)
+                .ok(),
+            Err(e) => {
+                debug!("Failed to fetch mint {mint}: {e}");
+                None
+            }
+        };
+
+        match decimals {
+            Some(decimals) => {
+                let divisor = 10_f64.powi(decimals as i32);
+                if let Ok(mut divisors) = self.divisors.lock() {
+                    divisors.insert(*mint, divisor);
+                }
+                divisor
+            }
+            None => 10_f64.powi(9),
+        }
+    }
+
+    /// Symbol of `mint`, from its token metadata
+    ///
+    /// - `DEFAULT_VRT_SYMBOL` if the metadata cannot be read
+    pub(crate) async fn symbol(&self, mint: &Pubkey) -> String {
+        if let Some(symbol) = self.symbols.lock().ok().and_then(|s| s.get(mint).cloned()) {
+            return symbol;
+        }
+
+        let meta_pubkey =
+            jito_vault_sdk::inline_mpl_token_metadata::pda::find_metadata_account(mint).0;
+        let symbol = match self.rpc_client.get_account(&meta_pubkey).await {
+            Ok(meta_acc) => Metadata::deserialize(&mut meta_acc.data.as_slice())
+                .map(|meta| meta.symbol)
+                .ok(),
+            Err(e) => {
+                debug!("Failed to fetch metadata of {mint}: {e}");
+                None
+            }
+        };
+
+        match symbol {
+            Some(symbol) => {
+                if let Ok(mut symbols) = self.symbols.lock() {
+                    symbols.insert(*mint, symbol.clone());
+                }
+                symbol
+            }
+            None => DEFAULT_VRT_SYMBOL.to_string(),
+        }
+    }
+
+    /// Mints of `vault`, `None` if the vault could not be read
+    pub(crate) async fn vault_mints(&self, vault: &Pubkey) -> Option<VaultMints> {
+        if let Some(mints) = self.vaults.lock().ok().and_then(|v| v.get(vault).copied()) {
+            return Some(mints);
+        }
+
+        let vault_acc = match self.rpc_client.get_account(vault).await {
+            Ok(vault_acc) => vault_acc,
+            Err(e) => {
+                debug!("Failed to fetch vault {vault}: {e}");
+                return None;
+            }
+        };
+        let vault_state = match Vault::from_bytes(&vault_acc.data) {
+            Ok(vault_state) => vault_state,
+            Err(e) => {
+                debug!("Failed to decode vault {vault}: {e}");
+                return None;
+            }
+        };
+
+        let mints = VaultMints {
+            vrt_mint: vault_state.vrt_mint,
+            supported_mint: vault_state.supported_mint,
+        };
+        if let Ok(mut vaults) = self.vaults.lock() {
+            vaults.insert(*vault, mints);
+        }
+        Some(mints)
+    }
+}
@@ -0,0 +1,177 @@
+use std::{collections::HashMap, sync::Arc};
+
+use jito_vault_client::accounts::{Vault, VaultOperatorDelegation};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::account_snapshot::fetch_with_slot;
+
+/// Change of an operator's staked amount
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DelegationChange {
+    /// `AddDelegation`
+    Added(u64),
+
+    /// `CooldownDelegation`
+    CooledDown(u64),
+}
+
+impl DelegationChange {
+    /// Staked amount after the change
+    fn apply(&self, staked: u64) -> u64 {
+        match self {
+            Self::Added(amount) => staked.saturating_add(*amount),
+            Self::CooledDown(amount) => staked.saturating_sub(*amount),
+        }
+    }
+
+    /// Staked amount before the change
+    fn revert(&self, staked: u64) -> u64 {
+        match self {
+            Self::Added(amount) => staked.saturating_sub(*amount),
+            Self::CooledDown(amount) => staked.saturating_add(*amount),
+        }
+    }
+}
+
+/// Staked amounts of an operator and its vault after a change
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DelegationTotals {
+    /// Change applied
+    pub(crate) change: DelegationChange,
+
+    /// Operator's staked amount
+    pub(crate) operator: u64,
+
+    /// Vault's staked amount over all operators
+    pub(crate) vault: u64,
+}
+
+impl DelegationTotals {
+    /// Operator's share of the vault's staked amount after the change, in percent
+    pub(crate) fn share(&self) -> f64 {
+        share(self.operator, self.vault)
+    }
+
+    /// Operator's share of the vault's staked amount before the change, in percent
+    pub(crate) fn previous_share(&self) -> f64 {
+        share(
+            self.change.revert(self.operator),
+            self.change.revert(self.vault),
+        )
+    }
+}
+
+/// `part` of `total` in percent, 0 for an empty total
+fn share(part: u64, total: u64) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    part as f64 / total as f64 * 100.0
+}
+
+/// Running staked amounts of vaults and their operators
+///
+/// - Seeded from the `Vault` and `VaultOperatorDelegation` accounts the first time each is seen
+/// - Then kept up to date from observed `AddDelegation` and `CooldownDelegation` instructions
+pub(crate) struct OperatorDelegations {
+    /// RPC Client
+    rpc_client: Arc<RpcClient>,
+
+    /// Staked amount of each vault
+    vaults: HashMap<Pubkey, u64>,
+
+    /// Staked amount of each vault and operator
+    operators: HashMap<(Pubkey, Pubkey), u64>,
+}
+
+impl OperatorDelegations {
+    /// Initialize operator delegations
+    pub(crate) fn new(rpc_client: Arc<RpcClient>) -> Self {
+        Self {
+            rpc_client,
+            vaults: HashMap::new(),
+            operators: HashMap::new(),
+        }
+    }
+
+    /// Apply a successful delegation change made at `slot`
+    ///
+    /// - Accounts read at or after `slot` already include the change
+    /// - `None` if an account could not be read, leaving the totals untouched
+    pub(crate) async fn apply(
+        &mut self,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        vault_operator_delegation: &Pubkey,
+        change: DelegationChange,
+        slot: u64,
+    ) -> Option<DelegationTotals> {
+        let vault_total = match self.vaults.get(vault) {
+            Some(staked) => change.apply(*staked),
+            None => {
+                let (read_slot, data) = fetch_with_slot(&self.rpc_client, vault).await?;
+                let staked = Vault::from_bytes(&data)
+                    .ok()?
+                    .delegation_state
+                    .staked_amount;
+                Self::seed(change, staked, read_slot, slot)
+            }
+        };
+        let operator_total = match self.operators.get(&(*vault, *operator)) {
+            Some(staked) => change.apply(*staked),
+            None => {
+                let (read_slot, data) =
+                    fetch_with_slot(&self.rpc_client, vault_operator_delegation).await?;
+                let staked = VaultOperatorDelegation::from_bytes(&data)
+                    .ok()?
+                    .delegation_state
+                    .staked_amount;
+                Self::seed(change, staked, read_slot, slot)
+            }
+        };
+
+        self.vaults.insert(*vault, vault_total);
+        self.operators.insert((*vault, *operator), operator_total);
+
+        Some(DelegationTotals {
+            change,
+            operator: operator_total,
+            vault: vault_total,
+        })
+    }
+
+    /// Staked amount after `change`, from an account read at `read_slot`
+    fn seed(change: DelegationChange, staked: u64, read_slot: u64, slot: u64) -> u64 {
+        if read_slot < slot {
+            change.apply(staked)
+        } else {
+            staked
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DelegationChange, DelegationTotals, OperatorDelegations};
+
+    #[test]
+    fn test_seed_applies_change_only_to_stale_reads() {
+        let change = DelegationChange::Added(50);
+
+        assert_eq!(OperatorDelegations::seed(change, 100, 9, 10), 150);
+        assert_eq!(OperatorDelegations::seed(change, 150, 10, 10), 150);
+    }
+
+    #[test]
+    fn test_share_before_and_after_cooldown() {
+        let totals = DelegationTotals {
+            change: DelegationChange::CooledDown(100),
+            operator: 100,
+            vault: 900,
+        };
+
+        assert_eq!(totals.previous_share(), 20.0);
+        assert!((totals.share() - 100.0 / 9.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,569 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicI64, AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use log::{error, info, warn};
+use solana_metrics::datapoint_info;
+use solana_sdk::signature::Signature;
+use tokio::sync::{mpsc, Mutex};
+use yellowstone_grpc_proto::geyser::{SubscribeUpdateAccount, SubscribeUpdateTransaction};
+
+use crate::{
+    block_time::BlockTimeCache,
+    dedup::{Delivery, SignatureDeduplicator},
+    metrics::EndpointMetrics,
+    recording::Recorder,
+    transaction_source::{SourceEvent, SourceMessage},
+    tx_parser::JitoTransactionParser,
+};
+
+/// Event handed to the rule evaluation stage
+pub(crate) enum PipelineEvent {
+    /// Slot from the merged transaction sources
+    Slot(u64),
+
+    /// Parsed transaction
+    Transaction {
+        /// Parsed transaction
+        parser: Box<JitoTransactionParser>,
+
+        /// Time the parse stage finished
+        parsed_at: Instant,
+    },
+
+    /// Update of a watched account
+    Account(Box<SubscribeUpdateAccount>),
+
+    /// A transaction source is reconnecting
+    Reconnect,
+
+    /// Slots a transaction source missed while disconnected
+    MissedSlots(u64),
+}
+
+/// Pipeline event numbered in the order the stream reader received it
+pub(crate) type SequencedEvent = (u64, PipelineEvent);
+
+/// Transaction waiting for a parse worker
+pub(crate) struct ParseJob {
+    /// Position in the stream, shared with the events forwarded directly
+    sequence: u64,
+
+    /// Raw transaction
+    transaction: Box<SubscribeUpdateTransaction>,
+
+    /// Time the stream reader received the transaction
+    received_at: Instant,
+}
+
+/// Queue depth and latency of a pipeline stage
+#[derive(Debug, Default)]
+pub(crate) struct StageMetrics {
+    /// Items waiting in the stage's input queue
+    queue_depth: AtomicI64,
+
+    /// Items processed since the last report
+    processed: AtomicU64,
+
+    /// Total time processed items spent queued and in the stage, in microseconds
+    latency_us: AtomicU64,
+}
+
+impl StageMetrics {
+    /// Count an item entering the stage's queue
+    pub(crate) fn queued(&self) {
+        self.queue_depth.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count an item leaving the stage's queue
+    pub(crate) fn dequeued(&self) {
+        self.queue_depth.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    /// Record an item finishing the stage
+    pub(crate) fn record(&self, latency: Duration) {
+        self.processed.fetch_add(1, Ordering::Relaxed);
+        self.latency_us
+            .fetch_add(latency.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    /// Queue depth, items processed and average latency in microseconds since the last call
+    pub(crate) fn take(&self) -> (i64, u64, u64) {
+        let processed = self.processed.swap(0, Ordering::Relaxed);
+        let latency_us = self.latency_us.swap(0, Ordering::Relaxed);
+
+        (
+            self.queue_depth.load(Ordering::Relaxed),
+            processed,
+            latency_us.checked_div(processed).unwrap_or(0),
+        )
+    }
+}
+
+/// Metrics of the parse and rule evaluation stages
+#[derive(Debug, Default)]
+pub(crate) struct PipelineMetrics {
+    /// Parse workers
+    pub(crate) parse: StageMetrics,
+
+    /// Rule evaluation
+    pub(crate) rules: StageMetrics,
+}
+
+impl PipelineMetrics {
+    /// Export queue depth and latency of each stage
+    pub(crate) fn report(&self) {
+        let (parse_queue_depth, parsed, parse_latency_us) = self.parse.take();
+        let (rule_queue_depth, evaluated, rule_latency_us) = self.rules.take();
+
+        datapoint_info!(
+            "jito-bell-pipeline",
+            ("parse_queue_depth", parse_queue_depth, i64),
+            ("parsed", parsed, i64),
+            ("parse_latency_us", parse_latency_us, i64),
+            ("rule_queue_depth", rule_queue_depth, i64),
+            ("evaluated", evaluated, i64),
+            ("rule_latency_us", rule_latency_us, i64),
+        );
+    }
+}
+
+/// First pipeline stage, draining the transaction sources as fast as they deliver
+///
+/// - Records updates, drops duplicate transactions and tracks per-source metrics
+/// - Forwards transactions to the parse workers and everything else to the reorder stage,
+///   numbering both so rule evaluation sees them in the order they were received
+pub(crate) struct StreamReader {
+    /// Delivery metrics per transaction source, indexed by `SourceMessage::source`
+    endpoint_metrics: Vec<EndpointMetrics>,
+
+    /// Recently delivered transaction signatures across all sources
+    deduplicator: SignatureDeduplicator,
+
+    /// Appends every received update to `SubscribeOption::record_file`
+    recorder: Option<Recorder>,
+
+    /// Block times from the sources, shared with the parse workers
+    block_times: Arc<BlockTimeCache>,
+
+    /// Pipeline Metrics
+    metrics: Arc<PipelineMetrics>,
+
+    /// Sequence number of the next forwarded event
+    next_sequence: u64,
+}
+
+impl StreamReader {
+    /// Initialize stream reader
+    pub(crate) fn new(
+        endpoint_metrics: Vec<EndpointMetrics>,
+        signature_cache_capacity: usize,
+        recorder: Option<Recorder>,
+        block_times: Arc<BlockTimeCache>,
+        metrics: Arc<PipelineMetrics>,
+    ) -> Self {
+        Self {
+            endpoint_metrics,
+            deduplicator: SignatureDeduplicator::new(signature_cache_capacity),
+            recorder,
+            block_times,
+            metrics,
+            next_sequence: 0,
+        }
+    }
+
+    /// Number the next forwarded event
+    fn sequence(&mut self) -> u64 {
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        sequence
+    }
+
+    /// Read until every source has returned, reporting endpoint metrics on `stats_interval`
+    pub(crate) async fn run(
+        mut self,
+        mut source_rx: mpsc::Receiver<SourceMessage>,
+        parse_tx: mpsc::Sender<ParseJob>,
+        event_tx: mpsc::Sender<SequencedEvent>,
+        stats_interval: Duration,
+    ) {
+        let mut endpoint_stats_interval = tokio::time::interval(stats_interval);
+
+        loop {
+            tokio::select! {
+                message = source_rx.recv() => match message {
+                    Some(message) => {
+                        if !self.handle_source_message(message, &parse_tx, &event_tx).await {
+                            break;
+                        }
+                    }
+                    None => break,
+                },
+                _ = endpoint_stats_interval.tick() => self.report_endpoint_metrics(),
+            }
+        }
+
+        self.report_endpoint_metrics();
+    }
+
+    /// Handle an event from one of the transaction sources
+    ///
+    /// - Return false once the downstream stages have shut down
+    async fn handle_source_message(
+        &mut self,
+        message: SourceMessage,
+        parse_tx: &mpsc::Sender<ParseJob>,
+        event_tx: &mpsc::Sender<SequencedEvent>,
+    ) -> bool {
+        let endpoint = message.source;
+
+        if let Some(recorder) = self.recorder.as_mut() {
+            if let Err(e) = recorder.record(&message.event) {
+                error!("Failed to record update: {e}");
+            }
+        }
+
+        match message.event {
+            SourceEvent::Slot(slot) => {
+                if let Some(missed) = self.record_endpoint_slot(endpoint, slot) {
+                    if event_tx
+                        .send((self.sequence(), PipelineEvent::MissedSlots(missed)))
+                        .await
+                        .is_err()
+                    {
+                        return false;
+                    }
+                }
+                event_tx
+                    .send((self.sequence(), PipelineEvent::Slot(slot)))
+                    .await
+                    .is_ok()
+            }
+            SourceEvent::Transaction(transaction) => {
+                let received_at = Instant::now();
+                let signature = transaction
+                    .transaction
+                    .as_ref()
+                    .and_then(|tx| Signature::try_from(tx.signature.as_slice()).ok());
+                if let Some(signature) = signature {
+                    let endpoint_metrics = &mut self.endpoint_metrics[endpoint];
+                    match self.deduplicator.observe(signature) {
+                        Delivery::First => endpoint_metrics.first_delivered += 1,
+                        Delivery::Duplicate(delay) => {
+                            endpoint_metrics.duplicate += 1;
+                            endpoint_metrics.duplicate_delay_ms += delay.as_millis() as u64;
+                            return true;
+                        }
+                    }
+                }
+
+                self.metrics.parse.queued();
+                parse_tx
+                    .send(ParseJob {
+                        sequence: self.sequence(),
+                        transaction,
+                        received_at,
+                    })
+                    .await
+                    .is_ok()
+            }
+            SourceEvent::Account(account) => event_tx
+                .send((self.sequence(), PipelineEvent::Account(account)))
+                .await
+                .is_ok(),
+            SourceEvent::BlockMeta { slot, block_time } => {
+                self.block_times.insert(slot, block_time);
+                true
+            }
+            SourceEvent::Reconnecting { attempt, delay } => {
+                let endpoint_metrics = &mut self.endpoint_metrics[endpoint];
+                endpoint_metrics.reconnect += 1;
+                endpoint_metrics.disconnected_at_slot = endpoint_metrics.last_slot;
+
+                warn!(
+                    "Reconnecting to source [{endpoint}] {} in {delay:?} (attempt {attempt}, last slot: {:?})",
+                    endpoint_metrics.name, endpoint_metrics.last_slot
+                );
+                datapoint_info!(
+                    "jito-bell-reconnect",
+                    ("endpoint", endpoint_metrics.name, String),
+                    ("attempt", attempt, i64),
+                    ("delay_ms", delay.as_millis() as i64, i64),
+                    ("last_slot", endpoint_metrics.last_slot.unwrap_or(0), i64),
+                );
+
+                event_tx
+                    .send((self.sequence(), PipelineEvent::Reconnect))
+                    .await
+                    .is_ok()
+            }
+        }
+    }
+
+    /// Record a slot received from one endpoint, returning the number of slots missed
+    ///
+    /// - The pinned Geyser protocol has no `from_slot`, so slots skipped while
+    ///   disconnected cannot be replayed. Report them as a gap instead.
+    fn record_endpoint_slot(&mut self, endpoint: usize, slot: u64) -> Option<u64> {
+        let endpoint_metrics = &mut self.endpoint_metrics[endpoint];
+        let host = endpoint_metrics.name.as_str();
+        let mut missed_slots = None;
+
+        if let Some(resume_slot) = endpoint_metrics.disconnected_at_slot.take() {
+            if slot > resume_slot + 1 {
+                let first_missed = resume_slot + 1;
+                let last_missed = slot - 1;
+                let missed = last_missed - first_missed + 1;
+                endpoint_metrics.missed_slots += missed;
+                missed_slots = Some(missed);
+                warn!("Geyser endpoint [{endpoint}] {host} reconnected at slot {slot}, missed slots {first_missed}..={last_missed} ({missed} slots)");
+                datapoint_info!(
+                    "jito-bell-slot-gap",
+                    ("endpoint", host, String),
+                    ("first_missed_slot", first_missed, i64),
+                    ("last_missed_slot", last_missed, i64),
+                    ("missed_slots", missed, i64),
+                );
+            } else {
+                info!("Geyser endpoint [{endpoint}] {host} reconnected at slot {slot} without missing slots");
+            }
+        }
+
+        if endpoint_metrics.last_slot.is_none_or(|last| slot > last) {
+            endpoint_metrics.last_slot = Some(slot);
+        }
+
+        missed_slots
+    }
+
+    /// Export per-endpoint lag and delivery metrics
+    ///
+    /// - Lag is measured in slots behind the most advanced endpoint
+    fn report_endpoint_metrics(&mut self) {
+        let tip = self
+            .endpoint_metrics
+            .iter()
+            .filter_map(|endpoint| endpoint.last_slot)
+            .max();
+
+        for endpoint_metrics in self.endpoint_metrics.iter_mut() {
+            let lag = match (tip, endpoint_metrics.last_slot) {
+                (Some(tip), Some(last_slot)) => tip - last_slot,
+                _ => 0,
+            };
+
+            datapoint_info!(
+                "jito-bell-endpoint",
+                ("endpoint", endpoint_metrics.name, String),
+                ("last_slot", endpoint_metrics.last_slot.unwrap_or(0), i64),
+                ("slot_lag", lag, i64),
+                ("first_delivered", endpoint_metrics.first_delivered, i64),
+                ("duplicate", endpoint_metrics.duplicate, i64),
+                (
+                    "duplicate_delay_ms",
+                    endpoint_metrics.duplicate_delay_ms,
+                    i64
+                ),
+                ("reconnect", endpoint_metrics.reconnect, i64),
+                ("missed_slots", endpoint_metrics.missed_slots, i64),
+            );
+
+            endpoint_metrics.reset_interval_counters();
+        }
+    }
+}
+
+/// Spawn `count` workers parsing transactions from `parse_rx` into `event_tx`
+///
+/// - Workers share the queue and finish out of order, the reorder stage restores the order
+/// - Block time is attached when already known, Geyser usually sends it after the transactions
+pub(crate) fn spawn_parse_workers(
+    count: usize,
+    parse_rx: mpsc::Receiver<ParseJob>,
+    event_tx: mpsc::Sender<SequencedEvent>,
+    block_times: Arc<BlockTimeCache>,
+    metrics: Arc<PipelineMetrics>,
+) {
+    let parse_rx = Arc::new(Mutex::new(parse_rx));
+
+    for _ in 0..count.max(1) {
+        let parse_rx = parse_rx.clone();
+        let event_tx = event_tx.clone();
+        let block_times = block_times.clone();
+        let metrics = metrics.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let Some(job) = parse_rx.lock().await.recv().await else {
+                    return;
+                };
+                metrics.parse.dequeued();
+
+                let mut parser = JitoTransactionParser::new(*job.transaction);
+                parser.block_time = block_times.get(parser.slot);
+                metrics.parse.record(job.received_at.elapsed());
+
+                metrics.rules.queued();
+                let event = PipelineEvent::Transaction {
+                    parser: Box::new(parser),
+                    parsed_at: Instant::now(),
+                };
+                if event_tx.send((job.sequence, event)).await.is_err() {
+                    return;
+                }
+            }
+        });
+    }
+}
+
+/// Events held until every event received before them has arrived
+///
+/// - Holds at most `capacity` events: beyond that, the missing events are given up on and
+///   the held events released, so a lost event cannot stall the stream
+struct ReorderBuffer {
+    /// Maximum number of events to hold
+    capacity: usize,
+
+    /// Sequence number of the next event to release
+    next_sequence: u64,
+
+    /// Events that arrived ahead of `next_sequence`
+    pending: BTreeMap<u64, PipelineEvent>,
+}
+
+impl ReorderBuffer {
+    /// Initialize an empty buffer holding at most `capacity` events
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            next_sequence: 0,
+            pending: BTreeMap::new(),
+        }
+    }
+
+    /// Add an event, returning the events now released in order
+    ///
+    /// - An event arriving after it was given up on is released at once
+    fn push(&mut self, sequence: u64, event: PipelineEvent) -> Vec<PipelineEvent> {
+        if sequence < self.next_sequence {
+            warn!("Event {sequence} arrived after it was given up on, releasing it out of order");
+            return vec![event];
+        }
+        self.pending.insert(sequence, event);
+
+        if self.pending.len() > self.capacity {
+            if let Some(first_pending) = self.pending.keys().next().copied() {
+                warn!(
+                    "Reorder buffer full, giving up on events {}..{first_pending}",
+                    self.next_sequence
+                );
+                self.next_sequence = first_pending;
+            }
+        }
+
+        let mut released = Vec::new();
+        while let Some(event) = self.pending.remove(&self.next_sequence) {
+            released.push(event);
+            self.next_sequence += 1;
+        }
+        released
+    }
+}
+
+/// Spawn the stage releasing events from the stream reader and parse workers to `event_tx`
+/// in the order they were received
+///
+/// - Stateful rules, such as delegation totals, withdrawal tickets and vault update cycles,
+///   rely on seeing transactions and slots in stream order
+/// - At most `capacity` events are held waiting for an earlier one
+pub(crate) fn spawn_reorder_stage(
+    mut sequenced_rx: mpsc::Receiver<SequencedEvent>,
+    event_tx: mpsc::Sender<PipelineEvent>,
+    capacity: usize,
+) {
+    tokio::spawn(async move {
+        let mut buffer = ReorderBuffer::new(capacity);
+
+        while let Some((sequence, event)) = sequenced_rx.recv().await {
+            for event in buffer.push(sequence, event) {
+                if event_tx.send(event).await.is_err() {
+                    return;
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::sync::mpsc;
+
+    use super::{spawn_reorder_stage, PipelineEvent, ReorderBuffer, StageMetrics};
+
+    #[test]
+    fn test_stage_metrics_take() {
+        let metrics = StageMetrics::default();
+        metrics.queued();
+        metrics.queued();
+        metrics.dequeued();
+        metrics.record(Duration::from_micros(100));
+        metrics.record(Duration::from_micros(300));
+
+        assert_eq!(metrics.take(), (1, 2, 200));
+        assert_eq!(metrics.take(), (1, 0, 0));
+    }
+
+    #[tokio::test]
+    async fn test_reorder_stage_preserves_stream_order() {
+        let (sequenced_tx, sequenced_rx) = mpsc::channel(8);
+        let (event_tx, mut event_rx) = mpsc::channel(8);
+        spawn_reorder_stage(sequenced_rx, event_tx, 8);
+
+        // Slots 3 and 4 skip the parse workers and overtake the events before them
+        for (sequence, slot) in [(2, 3), (3, 4), (0, 1), (1, 2)] {
+            sequenced_tx
+                .send((sequence, PipelineEvent::Slot(slot)))
+                .await
+                .unwrap();
+        }
+        drop(sequenced_tx);
+
+        let mut slots = Vec::new();
+        while let Some(event) = event_rx.recv().await {
+            if let PipelineEvent::Slot(slot) = event {
+                slots.push(slot);
+            }
+        }
+        assert_eq!(slots, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_reorder_buffer_gives_up_on_lost_events() {
+        let slots = |events: Vec<PipelineEvent>| -> Vec<u64> {
+            events
+                .into_iter()
+                .filter_map(|event| match event {
+                    PipelineEvent::Slot(slot) => Some(slot),
+                    _ => None,
+                })
+                .collect()
+        };
+        let mut buffer = ReorderBuffer::new(2);
+
+        // Event 0 is lost, the buffer fills up waiting for it
+        assert!(slots(buffer.push(1, PipelineEvent::Slot(2))).is_empty());
+        assert!(slots(buffer.push(2, PipelineEvent::Slot(3))).is_empty());
+        assert_eq!(slots(buffer.push(3, PipelineEvent::Slot(4))), vec![2, 3, 4]);
+        assert_eq!(slots(buffer.push(4, PipelineEvent::Slot(5))), vec![5]);
+
+        // Released at once if it turns up after all
+        assert_eq!(slots(buffer.push(0, PipelineEvent::Slot(1))), vec![1]);
+    }
+}
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use serde::Deserialize;
 
 use crate::{
+    epoch::EpochProgress,
     notification_info::{Destination, NotificationInfo},
     threshold_config::{ThresholdConfig, UsdThresholdConfig},
 };
@@ -11,6 +12,7 @@ use crate::{
 #[serde(rename_all = "snake_case")]
 pub enum ProgramName {
     JitoSteward,
+    SplToken,
     SplToken2022,
     SplStakePool,
     JitoVault,
@@ -39,6 +41,10 @@ pub struct AlertConfig {
     /// Thresholds (replaces the single threshold)
     #[serde(default)]
     pub usd_thresholds: Vec<UsdThresholdConfig>,
+
+    /// Thresholds on an operator's share of the vault's delegated stake, in percent
+    #[serde(default)]
+    pub share_thresholds: Vec<ThresholdConfig>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -52,8 +58,53 @@ pub struct Instruction {
     /// Vault receipt token (VRT)
     pub vrts: Option<HashMap<String, AlertConfig>>,
 
+    /// Vault
+    pub vaults: Option<HashMap<String, AlertConfig>>,
+
+    /// Restaking operator
+    pub operators: Option<HashMap<String, AlertConfig>>,
+
+    /// Restaking NCN
+    pub ncns: Option<HashMap<String, AlertConfig>>,
+
     /// Notification info
     pub notification_info: Option<NotificationInfo>,
+
+    /// Also alert on failed transactions
+    #[serde(default)]
+    pub include_failed: bool,
+
+    /// Alert only on failed transactions
+    #[serde(default)]
+    pub only_failed: bool,
+
+    /// Alert only from this share of the epoch onward, in percent
+    pub min_epoch_progress: Option<f64>,
+
+    /// Alert only up to this share of the epoch, in percent
+    pub max_epoch_progress: Option<f64>,
+}
+
+impl Instruction {
+    /// Whether this rule applies to a transaction that failed or succeeded
+    pub fn matches_status(&self, failed: bool) -> bool {
+        if failed {
+            self.include_failed || self.only_failed
+        } else {
+            !self.only_failed
+        }
+    }
+
+    /// Whether the epoch progress of the evaluated transaction is within this rule's window
+    pub fn matches_epoch_progress(&self, epoch_progress: &EpochProgress) -> bool {
+        if self.min_epoch_progress.is_none() && self.max_epoch_progress.is_none() {
+            return true;
+        }
+
+        let percent = epoch_progress.percent_complete();
+        self.min_epoch_progress.is_none_or(|min| percent >= min)
+            && self.max_epoch_progress.is_none_or(|max| percent <= max)
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -0,0 +1,202 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use prost::Message;
+use yellowstone_grpc_proto::{
+    geyser::{CommitmentLevel, SubscribeUpdateBlockMeta, SubscribeUpdateSlot},
+    prelude::{subscribe_update::UpdateOneof, SubscribeUpdate, UnixTimestamp},
+};
+
+use crate::{error::JitoBellError, transaction_source::SourceEvent};
+
+/// Update received from a transaction source, as stored in a recording
+///
+/// - A recording is a sequence of length-delimited `RecordedUpdate` messages
+#[derive(Clone, PartialEq, Message)]
+pub struct RecordedUpdate {
+    /// Wall-clock time the update was received, in milliseconds since the Unix epoch
+    #[prost(uint64, tag = "1")]
+    pub received_at_ms: u64,
+
+    /// Slot, transaction, account or block meta update
+    #[prost(message, optional, tag = "2")]
+    pub update: Option<SubscribeUpdate>,
+}
+
+impl RecordedUpdate {
+    /// Convert a source event, skipping events that carry no update
+    pub fn from_event(event: &SourceEvent, received_at_ms: u64) -> Option<Self> {
+        let update_oneof = match event {
+            SourceEvent::Slot(slot) => UpdateOneof::Slot(SubscribeUpdateSlot {
+                slot: *slot,
+                parent: None,
+                status: CommitmentLevel::Processed as i32,
+            }),
+            SourceEvent::Transaction(transaction) => {
+                UpdateOneof::Transaction(transaction.as_ref().clone())
+            }
+            SourceEvent::Account(account) => UpdateOneof::Account(account.as_ref().clone()),
+            SourceEvent::BlockMeta { slot, block_time } => {
+                UpdateOneof::BlockMeta(SubscribeUpdateBlockMeta {
+                    slot: *slot,
+                    block_time: Some(UnixTimestamp {
+                        timestamp: *block_time,
+                    }),
+                    ..Default::default()
+                })
+            }
+            SourceEvent::Reconnecting { .. } => return None,
+        };
+
+        Some(Self {
+            received_at_ms,
+            update: Some(SubscribeUpdate {
+                filters: Vec::new(),
+                update_oneof: Some(update_oneof),
+            }),
+        })
+    }
+
+    /// Convert back into a source event
+    pub fn into_event(self) -> Option<SourceEvent> {
+        match self.update?.update_oneof? {
+            UpdateOneof::Slot(update_slot) => Some(SourceEvent::Slot(update_slot.slot)),
+            UpdateOneof::Transaction(transaction) => {
+                Some(SourceEvent::Transaction(Box::new(transaction)))
+            }
+            UpdateOneof::Account(account) => Some(SourceEvent::Account(Box::new(account))),
+            UpdateOneof::BlockMeta(block_meta) => Some(SourceEvent::BlockMeta {
+                slot: block_meta.slot,
+                block_time: block_meta.block_time?.timestamp,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Appends received updates to a recording file
+pub struct Recorder {
+    /// Recording file, opened for append
+    file: File,
+}
+
+impl Recorder {
+    /// Open `path` for append, creating it if missing
+    pub fn create(path: &Path) -> Result<Self, JitoBellError> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        Ok(Self { file })
+    }
+
+    /// Append an event, stamped with the current time
+    pub fn record(&mut self, event: &SourceEvent) -> Result<(), JitoBellError> {
+        let received_at_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis() as u64);
+
+        if let Some(recorded) = RecordedUpdate::from_event(event, received_at_ms) {
+            self.file
+                .write_all(&recorded.encode_length_delimited_to_vec())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Decode every update of a recording
+pub fn read_recording(data: &[u8]) -> Result<Vec<RecordedUpdate>, JitoBellError> {
+    let mut buf = data;
+    let mut updates = Vec::new();
+
+    while !buf.is_empty() {
+        let update = RecordedUpdate::decode_length_delimited(&mut buf).map_err(|e| {
+            JitoBellError::TransactionParse(format!(
+                "Corrupt recording at byte {}: {e}",
+                data.len() - buf.len()
+            ))
+        })?;
+        updates.push(update);
+    }
+
+    Ok(updates)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use yellowstone_grpc_proto::geyser::{
+        SubscribeUpdateTransaction, SubscribeUpdateTransactionInfo,
+    };
+
+    use super::{read_recording, RecordedUpdate, Recorder};
+    use crate::transaction_source::SourceEvent;
+
+    #[test]
+    fn test_record_and_read() {
+        let path = std::env::temp_dir().join(format!(
+            "jito-bell-recording-{}",
+            solana_sdk::signature::Signature::new_unique()
+        ));
+        let transaction = SubscribeUpdateTransaction {
+            transaction: Some(SubscribeUpdateTransactionInfo {
+                signature: vec![7; 64],
+                ..Default::default()
+            }),
+            slot: 42,
+        };
+
+        let mut recorder = Recorder::create(&path).unwrap();
+        recorder.record(&SourceEvent::Slot(41)).unwrap();
+        recorder
+            .record(&SourceEvent::Reconnecting {
+                attempt: 1,
+                delay: Duration::from_millis(1),
+            })
+            .unwrap();
+        recorder
+            .record(&SourceEvent::Transaction(Box::new(transaction.clone())))
+            .unwrap();
+        recorder
+            .record(&SourceEvent::BlockMeta {
+                slot: 42,
+                block_time: 1_700_000_000,
+            })
+            .unwrap();
+        drop(recorder);
+
+        let updates = read_recording(&std::fs::read(&path).unwrap()).unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(updates.len(), 3);
+
+        let events: Vec<SourceEvent> = updates
+            .into_iter()
+            .filter_map(RecordedUpdate::into_event)
+            .collect();
+        assert!(matches!(events[0], SourceEvent::Slot(41)));
+        match &events[1] {
+            SourceEvent::Transaction(recorded) => assert_eq!(**recorded, transaction),
+            _ => panic!("expected a transaction"),
+        }
+        assert!(matches!(
+            events[2],
+            SourceEvent::BlockMeta {
+                slot: 42,
+                block_time: 1_700_000_000
+            }
+        ));
+    }
+
+    #[test]
+    fn test_read_truncated_recording() {
+        let recorded = RecordedUpdate::from_event(&SourceEvent::Slot(1), 0).unwrap();
+        let mut data = prost::Message::encode_length_delimited_to_vec(&recorded);
+        data.pop();
+
+        assert!(read_recording(&data).is_err());
+    }
+}
@@ -1,13 +1,40 @@
+use std::{path::PathBuf, time::Duration};
+
 use yellowstone_grpc_proto::geyser::CommitmentLevel;
 
-use crate::cli_args::Args;
+use crate::cli_args::{Args, ArgsTransactionSource};
 
-pub struct SubscribeOption {
+/// Geyser endpoint with its own X-Token
+#[derive(Clone)]
+pub struct GeyserEndpoint {
     /// Endpoint
     pub endpoint: String,
 
     /// X-Token
     pub x_token: Option<String>,
+}
+
+impl GeyserEndpoint {
+    /// Host of the endpoint, safe to log and export as a metric
+    ///
+    /// - Some providers embed the token in the URL path, so only the host is kept
+    pub fn host(&self) -> &str {
+        url_host(&self.endpoint)
+    }
+}
+
+/// Host of a URL, dropping the scheme, path and query
+pub fn url_host(url: &str) -> &str {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    without_scheme
+        .split(['/', '?'])
+        .next()
+        .unwrap_or(without_scheme)
+}
+
+pub struct SubscribeOption {
+    /// Geyser endpoints, the primary endpoint first
+    pub endpoints: Vec<GeyserEndpoint>,
 
     /// Commitment
     pub commitment: CommitmentLevel,
@@ -30,6 +57,30 @@ pub struct SubscribeOption {
     /// Account required
     pub account_required: Vec<String>,
 
+    /// Initial reconnect backoff
+    pub reconnect_initial_backoff: Duration,
+
+    /// Maximum reconnect backoff
+    pub reconnect_max_backoff: Duration,
+
+    /// Maximum consecutive reconnect attempts
+    pub max_reconnect_attempts: Option<u32>,
+
+    /// Transaction source
+    pub transaction_source: ArgsTransactionSource,
+
+    /// Interval between RPC polls
+    pub rpc_poll_interval: Duration,
+
+    /// File every received update is appended to
+    pub record_file: Option<PathBuf>,
+
+    /// Number of parse workers
+    pub parse_workers: usize,
+
+    /// Maximum notifications in flight per destination
+    pub delivery_concurrency: usize,
+
     /// Slack webhook url for Jito Bell
     pub jito_bell_slack_webhook_url: Option<String>,
 
@@ -66,9 +117,25 @@ pub struct SubscribeOption {
 
 impl SubscribeOption {
     pub fn new(arg: Args, commitment: CommitmentLevel) -> Self {
-        Self {
+        let mut endpoints = vec![GeyserEndpoint {
             endpoint: arg.endpoint,
             x_token: arg.x_token,
+        }];
+        endpoints.extend(arg.additional_endpoints.into_iter().enumerate().map(
+            |(index, endpoint)| {
+                GeyserEndpoint {
+                    endpoint,
+                    x_token: arg
+                        .additional_x_tokens
+                        .get(index)
+                        .filter(|token| !token.is_empty())
+                        .cloned(),
+                }
+            },
+        ));
+
+        Self {
+            endpoints,
             commitment,
             vote: arg.vote,
             failed: arg.failed,
@@ -76,6 +143,14 @@ impl SubscribeOption {
             account_include: arg.account_include,
             account_exclude: arg.account_exclude,
             account_required: arg.account_required,
+            reconnect_initial_backoff: Duration::from_millis(arg.reconnect_initial_backoff_ms),
+            reconnect_max_backoff: Duration::from_millis(arg.reconnect_max_backoff_ms),
+            max_reconnect_attempts: arg.max_reconnect_attempts,
+            transaction_source: arg.transaction_source,
+            rpc_poll_interval: Duration::from_millis(arg.rpc_poll_interval_ms),
+            record_file: arg.record,
+            parse_workers: arg.parse_workers,
+            delivery_concurrency: arg.delivery_concurrency,
             jito_bell_slack_webhook_url: arg.slack_webhook_url,
             stake_pool_alerts_slack_webhook_url: arg.stake_pool_alerts_slack_webhook_url,
             stakenet_event_alerts_slack_webhook_url: arg.stakenet_event_alerts_slack_webhook_url,
@@ -94,12 +169,22 @@ impl SubscribeOption {
 impl std::fmt::Display for SubscribeOption {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Subscribe Options:")?;
-        writeln!(f, "  Endpoint: {}", self.endpoint)?;
+        match self.transaction_source {
+            ArgsTransactionSource::Geyser => writeln!(f, "  Transaction Source: Geyser")?,
+            ArgsTransactionSource::Rpc => writeln!(
+                f,
+                "  Transaction Source: RPC (poll every {:?})",
+                self.rpc_poll_interval
+            )?,
+        }
+        for endpoint in &self.endpoints {
+            writeln!(f, "  Endpoint: {}", endpoint.host())?;
 
-        // Handle x_token securely - don't print actual token
-        match &self.x_token {
-            Some(_) => writeln!(f, "  X-Token: [REDACTED]")?,
-            None => writeln!(f, "  X-Token: None")?,
+            // Handle x_token securely - don't print actual token
+            match &endpoint.x_token {
+                Some(_) => writeln!(f, "    X-Token: [REDACTED]")?,
+                None => writeln!(f, "    X-Token: None")?,
+            }
         }
 
         // Display commitment level
@@ -145,6 +230,27 @@ impl std::fmt::Display for SubscribeOption {
             }
         }
 
+        writeln!(
+            f,
+            "  Reconnect Backoff: {:?} - {:?}",
+            self.reconnect_initial_backoff, self.reconnect_max_backoff
+        )?;
+        match self.max_reconnect_attempts {
+            Some(max) => writeln!(f, "  Max Reconnect Attempts: {}", max)?,
+            None => writeln!(f, "  Max Reconnect Attempts: Unlimited")?,
+        }
+
+        if let Some(record_file) = &self.record_file {
+            writeln!(f, "  Record File: {}", record_file.display())?;
+        }
+
+        writeln!(f, "  Parse Workers: {}", self.parse_workers)?;
+        writeln!(
+            f,
+            "  Delivery Concurrency: {} per destination",
+            self.delivery_concurrency
+        )?;
+
         Ok(())
     }
 }
@@ -156,3 +262,23 @@ impl std::fmt::Debug for SubscribeOption {
         write!(f, "{}", self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::GeyserEndpoint;
+
+    #[test]
+    fn test_endpoint_host() {
+        let endpoint = GeyserEndpoint {
+            endpoint: "https://example.rpcpool.com/secret-token".to_string(),
+            x_token: None,
+        };
+        assert_eq!(endpoint.host(), "example.rpcpool.com");
+
+        let endpoint = GeyserEndpoint {
+            endpoint: "http://127.0.0.1:10000".to_string(),
+            x_token: None,
+        };
+        assert_eq!(endpoint.host(), "127.0.0.1:10000");
+    }
+}
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+use crate::{notification_info::NotificationInfo, threshold_config::ThresholdConfig};
+
+/// Withdrawal ticket alerts of a vault
+#[derive(Debug, Clone, Deserialize)]
+pub struct WithdrawalTicketConfig {
+    /// Thresholds on the VRT amount of a ticket becoming claimable
+    #[serde(default)]
+    pub claimable_thresholds: Vec<ThresholdConfig>,
+
+    /// Vault epochs a claimable ticket may stay unburned before alerting
+    pub unclaimed_epochs: Option<u64>,
+
+    /// Notification for tickets left unclaimed for `unclaimed_epochs`
+    pub unclaimed_notification: Option<NotificationInfo>,
+}
@@ -0,0 +1,371 @@
+use std::str::FromStr;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use solana_sdk::{
+    account::Account, message::VersionedMessage, pubkey::Pubkey, transaction::VersionedTransaction,
+};
+use solana_transaction_status_client_types::{
+    option_serializer::OptionSerializer, EncodedConfirmedTransactionWithStatusMeta,
+    UiInnerInstructions, UiInstruction, UiTransactionStatusMeta, UiTransactionTokenBalance,
+};
+use yellowstone_grpc_proto::{
+    geyser::{
+        SubscribeUpdateAccount, SubscribeUpdateAccountInfo, SubscribeUpdateTransaction,
+        SubscribeUpdateTransactionInfo,
+    },
+    prelude as proto,
+};
+
+use crate::error::JitoBellError;
+
+/// Convert a transaction fetched via RPC into the Geyser shape consumed by `JitoTransactionParser`
+///
+/// - The transaction must be fetched with `UiTransactionEncoding::Base64` (or Base58)
+/// - Rewards are not converted since no parser reads them
+pub fn subscribe_update_transaction_from_rpc(
+    transaction: EncodedConfirmedTransactionWithStatusMeta,
+) -> Result<SubscribeUpdateTransaction, JitoBellError> {
+    let versioned_tx = transaction
+        .transaction
+        .transaction
+        .decode()
+        .ok_or_else(|| {
+            JitoBellError::TransactionParse("Unsupported transaction encoding".to_string())
+        })?;
+
+    let meta = transaction
+        .transaction
+        .meta
+        .map(create_transaction_meta)
+        .transpose()?;
+
+    let signature = versioned_tx
+        .signatures
+        .first()
+        .map(|signature| signature.as_ref().to_vec())
+        .unwrap_or_default();
+
+    Ok(SubscribeUpdateTransaction {
+        transaction: Some(SubscribeUpdateTransactionInfo {
+            signature,
+            is_vote: false,
+            transaction: Some(create_transaction(&versioned_tx)),
+            meta,
+            index: 0,
+        }),
+        slot: transaction.slot,
+    })
+}
+
+/// Convert a decoded transaction
+/// Convert an account fetched via RPC into the Geyser account update shape
+///
+/// - RPC does not report the write version or the transaction that last wrote the account
+pub fn subscribe_update_account_from_rpc(
+    pubkey: &Pubkey,
+    account: Account,
+    slot: u64,
+) -> SubscribeUpdateAccount {
+    SubscribeUpdateAccount {
+        account: Some(SubscribeUpdateAccountInfo {
+            pubkey: pubkey.to_bytes().to_vec(),
+            lamports: account.lamports,
+            owner: account.owner.to_bytes().to_vec(),
+            executable: account.executable,
+            rent_epoch: account.rent_epoch,
+            data: account.data,
+            write_version: 0,
+            txn_signature: None,
+        }),
+        slot,
+        is_startup: false,
+    }
+}
+
+fn create_transaction(tx: &VersionedTransaction) -> proto::Transaction {
+    proto::Transaction {
+        signatures: tx
+            .signatures
+            .iter()
+            .map(|signature| signature.as_ref().to_vec())
+            .collect(),
+        message: Some(create_message(&tx.message)),
+    }
+}
+
+/// Convert a legacy or v0 message
+fn create_message(message: &VersionedMessage) -> proto::Message {
+    let header = message.header();
+
+    proto::Message {
+        header: Some(proto::MessageHeader {
+            num_required_signatures: header.num_required_signatures as u32,
+            num_readonly_signed_accounts: header.num_readonly_signed_accounts as u32,
+            num_readonly_unsigned_accounts: header.num_readonly_unsigned_accounts as u32,
+        }),
+        account_keys: message
+            .static_account_keys()
+            .iter()
+            .map(|pubkey| pubkey.to_bytes().to_vec())
+            .collect(),
+        recent_blockhash: message.recent_blockhash().to_bytes().to_vec(),
+        instructions: message
+            .instructions()
+            .iter()
+            .map(|ix| proto::CompiledInstruction {
+                program_id_index: ix.program_id_index as u32,
+                accounts: ix.accounts.clone(),
+                data: ix.data.clone(),
+            })
+            .collect(),
+        versioned: matches!(message, VersionedMessage::V0(_)),
+        address_table_lookups: message
+            .address_table_lookups()
+            .unwrap_or_default()
+            .iter()
+            .map(|lookup| proto::MessageAddressTableLookup {
+                account_key: lookup.account_key.to_bytes().to_vec(),
+                writable_indexes: lookup.writable_indexes.clone(),
+                readonly_indexes: lookup.readonly_indexes.clone(),
+            })
+            .collect(),
+    }
+}
+
+/// Convert transaction status meta
+fn create_transaction_meta(
+    meta: UiTransactionStatusMeta,
+) -> Result<proto::TransactionStatusMeta, JitoBellError> {
+    let err = meta
+        .err
+        .map(|err| {
+            bincode::serialize(&err)
+                .map(|err| proto::TransactionError { err })
+                .map_err(|e| JitoBellError::TransactionParse(e.to_string()))
+        })
+        .transpose()?;
+
+    let inner_instructions_none = !meta.inner_instructions.is_some();
+    let inner_instructions = Option::<Vec<UiInnerInstructions>>::from(meta.inner_instructions)
+        .unwrap_or_default()
+        .into_iter()
+        .map(create_inner_instructions)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let log_messages_none = !meta.log_messages.is_some();
+    let log_messages = Option::from(meta.log_messages).unwrap_or_default();
+
+    let (loaded_writable_addresses, loaded_readonly_addresses) = match meta.loaded_addresses {
+        OptionSerializer::Some(loaded_addresses) => (
+            create_pubkeys(&loaded_addresses.writable)?,
+            create_pubkeys(&loaded_addresses.readonly)?,
+        ),
+        _ => (Vec::new(), Vec::new()),
+    };
+
+    let return_data = match meta.return_data {
+        OptionSerializer::Some(return_data) => Some(proto::ReturnData {
+            program_id: create_pubkey(&return_data.program_id)?,
+            data: STANDARD
+                .decode(&return_data.data.0)
+                .map_err(|e| JitoBellError::TransactionParse(e.to_string()))?,
+        }),
+        _ => None,
+    };
+
+    Ok(proto::TransactionStatusMeta {
+        err,
+        fee: meta.fee,
+        pre_balances: meta.pre_balances,
+        post_balances: meta.post_balances,
+        inner_instructions,
+        inner_instructions_none,
+        log_messages,
+        log_messages_none,
+        pre_token_balances: create_token_balances(meta.pre_token_balances),
+        post_token_balances: create_token_balances(meta.post_token_balances),
+        rewards: Vec::new(),
+        loaded_writable_addresses,
+        loaded_readonly_addresses,
+        return_data_none: return_data.is_none(),
+        return_data,
+        compute_units_consumed: meta.compute_units_consumed.into(),
+    })
+}
+
+/// Convert the inner instructions of one top-level instruction
+///
+/// - Only compiled instructions are expected with binary encodings
+fn create_inner_instructions(
+    inner_instructions: UiInnerInstructions,
+) -> Result<proto::InnerInstructions, JitoBellError> {
+    let mut instructions = Vec::with_capacity(inner_instructions.instructions.len());
+
+    for instruction in inner_instructions.instructions {
+        match instruction {
+            UiInstruction::Compiled(ix) => instructions.push(proto::InnerInstruction {
+                program_id_index: ix.program_id_index as u32,
+                accounts: ix.accounts,
+                data: bs58::decode(&ix.data)
+                    .into_vec()
+                    .map_err(|e| JitoBellError::TransactionParse(e.to_string()))?,
+                stack_height: ix.stack_height,
+            }),
+            UiInstruction::Parsed(_) => {
+                return Err(JitoBellError::TransactionParse(
+                    "Parsed inner instructions are not supported".to_string(),
+                ));
+            }
+        }
+    }
+
+    Ok(proto::InnerInstructions {
+        index: inner_instructions.index as u32,
+        instructions,
+    })
+}
+
+/// Convert token balances
+fn create_token_balances(
+    balances: OptionSerializer<Vec<UiTransactionTokenBalance>>,
+) -> Vec<proto::TokenBalance> {
+    Option::<Vec<UiTransactionTokenBalance>>::from(balances)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|balance| proto::TokenBalance {
+            account_index: balance.account_index as u32,
+            mint: balance.mint,
+            ui_token_amount: Some(proto::UiTokenAmount {
+                ui_amount: balance.ui_token_amount.ui_amount.unwrap_or_default(),
+                decimals: balance.ui_token_amount.decimals as u32,
+                amount: balance.ui_token_amount.amount,
+                ui_amount_string: balance.ui_token_amount.ui_amount_string,
+            }),
+            owner: Option::from(balance.owner).unwrap_or_default(),
+            program_id: Option::from(balance.program_id).unwrap_or_default(),
+        })
+        .collect()
+}
+
+/// Convert base58 pubkeys
+fn create_pubkeys(pubkeys: &[String]) -> Result<Vec<Vec<u8>>, JitoBellError> {
+    pubkeys.iter().map(|pubkey| create_pubkey(pubkey)).collect()
+}
+
+/// Convert a base58 pubkey
+fn create_pubkey(pubkey: &str) -> Result<Vec<u8>, JitoBellError> {
+    Pubkey::from_str(pubkey)
+        .map(|pubkey| pubkey.to_bytes().to_vec())
+        .map_err(|e| JitoBellError::TransactionParse(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use solana_sdk::{
+        hash::Hash,
+        instruction::CompiledInstruction,
+        message::{Message, MessageHeader, VersionedMessage},
+        pubkey::Pubkey,
+        signature::Signature,
+        transaction::VersionedTransaction,
+    };
+    use solana_transaction_status_client_types::{
+        option_serializer::OptionSerializer, EncodedConfirmedTransactionWithStatusMeta,
+        EncodedTransaction, EncodedTransactionWithStatusMeta, TransactionBinaryEncoding,
+        UiCompiledInstruction, UiInnerInstructions, UiInstruction, UiLoadedAddresses,
+        UiTransactionStatusMeta,
+    };
+
+    use super::subscribe_update_transaction_from_rpc;
+
+    #[test]
+    fn test_convert_rpc_transaction() {
+        let account_keys: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let loaded_writable = Pubkey::new_unique();
+        let signature = Signature::new_unique();
+
+        let message = Message {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: account_keys.clone(),
+            recent_blockhash: Hash::new_unique(),
+            instructions: vec![CompiledInstruction {
+                program_id_index: 2,
+                accounts: vec![0, 1],
+                data: vec![1, 2, 3],
+            }],
+        };
+        let tx = VersionedTransaction {
+            signatures: vec![signature],
+            message: VersionedMessage::Legacy(message),
+        };
+        let blob = STANDARD.encode(bincode::serialize(&tx).unwrap());
+
+        let meta = UiTransactionStatusMeta {
+            err: None,
+            status: Ok(()),
+            fee: 5000,
+            pre_balances: vec![],
+            post_balances: vec![],
+            inner_instructions: OptionSerializer::Some(vec![UiInnerInstructions {
+                index: 0,
+                instructions: vec![UiInstruction::Compiled(UiCompiledInstruction {
+                    program_id_index: 2,
+                    accounts: vec![1],
+                    data: bs58::encode([7, 8]).into_string(),
+                    stack_height: Some(2),
+                })],
+            }]),
+            log_messages: OptionSerializer::Some(vec!["Program log: hello".to_string()]),
+            pre_token_balances: OptionSerializer::None,
+            post_token_balances: OptionSerializer::None,
+            rewards: OptionSerializer::None,
+            loaded_addresses: OptionSerializer::Some(UiLoadedAddresses {
+                writable: vec![loaded_writable.to_string()],
+                readonly: vec![],
+            }),
+            return_data: OptionSerializer::Skip,
+            compute_units_consumed: OptionSerializer::Some(1_000),
+        };
+
+        let rpc_tx = EncodedConfirmedTransactionWithStatusMeta {
+            slot: 42,
+            transaction: EncodedTransactionWithStatusMeta {
+                transaction: EncodedTransaction::Binary(blob, TransactionBinaryEncoding::Base64),
+                meta: Some(meta),
+                version: None,
+            },
+            block_time: Some(1_700_000_000),
+        };
+
+        let update = subscribe_update_transaction_from_rpc(rpc_tx).unwrap();
+        assert_eq!(update.slot, 42);
+
+        let info = update.transaction.unwrap();
+        assert_eq!(info.signature, signature.as_ref().to_vec());
+
+        let msg = info.transaction.unwrap().message.unwrap();
+        assert_eq!(msg.account_keys.len(), 3);
+        assert_eq!(msg.account_keys[2], account_keys[2].to_bytes().to_vec());
+        assert_eq!(msg.instructions[0].data, vec![1, 2, 3]);
+
+        let meta = info.meta.unwrap();
+        assert!(meta.err.is_none());
+        assert_eq!(meta.inner_instructions[0].instructions[0].data, vec![7, 8]);
+        assert_eq!(
+            meta.inner_instructions[0].instructions[0].stack_height,
+            Some(2)
+        );
+        assert_eq!(meta.log_messages, vec!["Program log: hello".to_string()]);
+        assert_eq!(
+            meta.loaded_writable_addresses,
+            vec![loaded_writable.to_bytes().to_vec()]
+        );
+        assert!(meta.return_data_none);
+        assert_eq!(meta.compute_units_consumed, Some(1_000));
+    }
+}
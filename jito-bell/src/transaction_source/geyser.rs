@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+
+use futures::{future::BoxFuture, sink::SinkExt, stream::StreamExt, FutureExt};
+use log::{error, warn};
+use maplit::hashmap;
+use tokio::sync::mpsc::Sender;
+use yellowstone_grpc_client::GeyserGrpcClient;
+use yellowstone_grpc_proto::{
+    geyser::SubscribeRequestFilterSlots,
+    prelude::{
+        subscribe_update::UpdateOneof, SubscribeRequest, SubscribeRequestFilterAccounts,
+        SubscribeRequestFilterBlocksMeta, SubscribeRequestFilterTransactions,
+    },
+    tonic::transport::ClientTlsConfig,
+};
+
+use crate::{
+    backoff::Backoff,
+    error::JitoBellError,
+    subscribe_option::{GeyserEndpoint, SubscribeOption},
+    transaction_source::{SourceEvent, SourceMessage, TransactionSource},
+};
+
+/// Supervised subscription to a single Geyser endpoint
+pub(crate) struct GeyserSubscription {
+    /// Endpoint and token
+    endpoint: GeyserEndpoint,
+
+    /// Subscription request sent on every (re)connect
+    request: SubscribeRequest,
+
+    /// Reconnect backoff
+    backoff: Backoff,
+
+    /// Maximum consecutive reconnect attempts
+    max_reconnect_attempts: Option<u32>,
+}
+
+impl GeyserSubscription {
+    /// Initialize Geyser subscription
+    ///
+    /// - `watched_accounts` are subscribed to alongside the transaction filter
+    /// - Block meta is subscribed to for the block time of each slot
+    pub(crate) fn new(
+        endpoint: GeyserEndpoint,
+        subscribe_option: &SubscribeOption,
+        watched_accounts: &[String],
+    ) -> Self {
+        Self {
+            endpoint,
+            request: Self::subscribe_request(subscribe_option, watched_accounts),
+            backoff: Backoff::new(
+                subscribe_option.reconnect_initial_backoff,
+                subscribe_option.reconnect_max_backoff,
+            ),
+            max_reconnect_attempts: subscribe_option.max_reconnect_attempts,
+        }
+    }
+
+    /// Build the Geyser subscription request from the subscribe options
+    fn subscribe_request(
+        subscribe_option: &SubscribeOption,
+        watched_accounts: &[String],
+    ) -> SubscribeRequest {
+        let accounts = if watched_accounts.is_empty() {
+            HashMap::new()
+        } else {
+            hashmap! { "".to_owned() => SubscribeRequestFilterAccounts {
+                account: watched_accounts.to_vec(),
+                owner: vec![],
+                filters: vec![],
+            } }
+        };
+
+        SubscribeRequest {
+            slots: hashmap! { "".to_owned() => SubscribeRequestFilterSlots {
+                filter_by_commitment: Some(true),
+            } },
+            accounts,
+            transactions: hashmap! { "".to_owned() => SubscribeRequestFilterTransactions {
+                vote: subscribe_option.vote,
+                failed: subscribe_option.failed,
+                signature: subscribe_option.signature.clone(),
+                account_include: subscribe_option.account_include.clone(),
+                account_exclude: subscribe_option.account_exclude.clone(),
+                account_required: subscribe_option.account_required.clone(),
+            } },
+            transactions_status: HashMap::new(),
+            entry: HashMap::new(),
+            blocks: HashMap::new(),
+            blocks_meta: hashmap! { "".to_owned() => SubscribeRequestFilterBlocksMeta {} },
+            commitment: Some(subscribe_option.commitment as i32),
+            accounts_data_slice: vec![],
+            ping: None,
+        }
+    }
+
+    /// Forward updates to `tx`, reconnecting with jittered exponential backoff
+    ///
+    /// - Reset the backoff once a session has delivered an update
+    /// - Return when the receiver is gone or `max_reconnect_attempts` is exhausted
+    async fn supervise(
+        mut self,
+        source: usize,
+        tx: Sender<SourceMessage>,
+    ) -> Result<(), JitoBellError> {
+        loop {
+            let mut received = false;
+
+            match self.stream(source, &tx, &mut received).await {
+                Ok(()) => warn!("Geyser stream [{source}] closed by server"),
+                Err(e) => error!("Geyser subscription [{source}] error: {e}"),
+            }
+
+            if tx.is_closed() {
+                return Ok(());
+            }
+
+            if received {
+                self.backoff.reset();
+            }
+
+            if let Some(max_attempts) = self.max_reconnect_attempts {
+                if self.backoff.attempt() >= max_attempts {
+                    return Err(JitoBellError::Subscription(format!(
+                        "Geyser endpoint [{source}] gave up after {max_attempts} reconnect attempts"
+                    )));
+                }
+            }
+
+            let delay = self.backoff.next_delay();
+            let message = SourceMessage {
+                source,
+                event: SourceEvent::Reconnecting {
+                    attempt: self.backoff.attempt(),
+                    delay,
+                },
+            };
+            if tx.send(message).await.is_err() {
+                return Ok(());
+            }
+
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Connect, subscribe and forward updates until the stream ends or fails
+    async fn stream(
+        &self,
+        source: usize,
+        tx: &Sender<SourceMessage>,
+        received: &mut bool,
+    ) -> Result<(), JitoBellError> {
+        let mut client = GeyserGrpcClient::build_from_shared(self.endpoint.endpoint.clone())?
+            .x_token(self.endpoint.x_token.clone())?
+            .tls_config(ClientTlsConfig::new().with_native_roots())?
+            .connect()
+            .await?;
+        let (mut subscribe_tx, mut stream) = client.subscribe().await?;
+
+        if let Err(e) = subscribe_tx.send(self.request.clone()).await {
+            return Err(JitoBellError::Subscription(format!(
+                "Failed to send subscription request: {}",
+                e
+            )));
+        }
+
+        while let Some(message) = stream.next().await {
+            match message {
+                Ok(update) => {
+                    *received = true;
+                    let event = match update.update_oneof {
+                        Some(UpdateOneof::Slot(update_slot)) => SourceEvent::Slot(update_slot.slot),
+                        Some(UpdateOneof::Transaction(transaction)) => {
+                            SourceEvent::Transaction(Box::new(transaction))
+                        }
+                        Some(UpdateOneof::Account(account)) => {
+                            SourceEvent::Account(Box::new(account))
+                        }
+                        Some(UpdateOneof::BlockMeta(block_meta)) => {
+                            let Some(block_time) = block_meta.block_time else {
+                                continue;
+                            };
+                            SourceEvent::BlockMeta {
+                                slot: block_meta.slot,
+                                block_time: block_time.timestamp,
+                            }
+                        }
+                        _ => continue,
+                    };
+                    if tx.send(SourceMessage { source, event }).await.is_err() {
+                        return Ok(());
+                    }
+                }
+                Err(error) => {
+                    return Err(JitoBellError::Subscription(format!(
+                        "Stream error: {error:?}"
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl TransactionSource for GeyserSubscription {
+    fn name(&self) -> String {
+        self.endpoint.host().to_string()
+    }
+
+    fn run(
+        self: Box<Self>,
+        source: usize,
+        tx: Sender<SourceMessage>,
+    ) -> BoxFuture<'static, Result<(), JitoBellError>> {
+        self.supervise(source, tx).boxed()
+    }
+}
@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use solana_sdk::clock::UnixTimestamp;
+use tokio::sync::mpsc::Sender;
+use yellowstone_grpc_proto::geyser::{SubscribeUpdateAccount, SubscribeUpdateTransaction};
+
+use crate::error::JitoBellError;
+
+pub mod convert;
+pub(crate) mod geyser;
+pub(crate) mod replay;
+pub(crate) mod rpc;
+
+/// Event produced by a transaction source
+pub enum SourceEvent {
+    /// Slot reached by the source
+    Slot(u64),
+
+    /// Transaction, in the shape `JitoTransactionParser` consumes
+    Transaction(Box<SubscribeUpdateTransaction>),
+
+    /// Update of a watched account
+    Account(Box<SubscribeUpdateAccount>),
+
+    /// Block time of a slot
+    BlockMeta {
+        slot: u64,
+        block_time: UnixTimestamp,
+    },
+
+    /// The source lost its connection and will retry after `delay`
+    Reconnecting { attempt: u32, delay: Duration },
+}
+
+/// Source event tagged with the index of the source that produced it
+pub struct SourceMessage {
+    /// Index of the source
+    pub source: usize,
+
+    /// Event
+    pub event: SourceEvent,
+}
+
+/// Source of transactions for `JitoBellHandler`
+///
+/// - Every source feeds the same parser and notification path
+pub trait TransactionSource: Send {
+    /// Name of the source, safe to log and export as a metric
+    fn name(&self) -> String;
+
+    /// Send events to `tx` until the receiver is dropped or the source gives up
+    fn run(
+        self: Box<Self>,
+        source: usize,
+        tx: Sender<SourceMessage>,
+    ) -> BoxFuture<'static, Result<(), JitoBellError>>;
+}
@@ -0,0 +1,74 @@
+use std::{path::PathBuf, time::Duration};
+
+use futures::{future::BoxFuture, FutureExt};
+use log::info;
+use tokio::sync::mpsc::Sender;
+
+use crate::{
+    error::JitoBellError,
+    recording::{read_recording, RecordedUpdate},
+    transaction_source::{SourceMessage, TransactionSource},
+};
+
+/// Transaction source replaying a file written with `--record`
+pub(crate) struct ReplaySource {
+    /// Recording file
+    path: PathBuf,
+
+    /// Sleep between updates to reproduce the recorded pace
+    paced: bool,
+}
+
+impl ReplaySource {
+    /// Initialize replay source
+    pub(crate) fn new(path: PathBuf, paced: bool) -> Self {
+        Self { path, paced }
+    }
+
+    /// Send every recorded update, then return
+    async fn replay(self, source: usize, tx: Sender<SourceMessage>) -> Result<(), JitoBellError> {
+        let data = tokio::fs::read(&self.path).await?;
+        let updates = read_recording(&data)?;
+        info!(
+            "Replaying {} updates from {}",
+            updates.len(),
+            self.path.display()
+        );
+
+        let mut previous_ms = None;
+        for update in updates {
+            if self.paced {
+                if let Some(previous_ms) = previous_ms {
+                    let gap = update.received_at_ms.saturating_sub(previous_ms);
+                    tokio::time::sleep(Duration::from_millis(gap)).await;
+                }
+                previous_ms = Some(update.received_at_ms);
+            }
+
+            if let Some(event) = RecordedUpdate::into_event(update) {
+                if tx.send(SourceMessage { source, event }).await.is_err() {
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl TransactionSource for ReplaySource {
+    fn name(&self) -> String {
+        self.path.file_name().map_or_else(
+            || "replay".to_string(),
+            |name| name.to_string_lossy().into_owned(),
+        )
+    }
+
+    fn run(
+        self: Box<Self>,
+        source: usize,
+        tx: Sender<SourceMessage>,
+    ) -> BoxFuture<'static, Result<(), JitoBellError>> {
+        self.replay(source, tx).boxed()
+    }
+}
@@ -0,0 +1,294 @@
+use std::{collections::BTreeSet, str::FromStr, sync::Arc, time::Duration};
+
+use futures::{future::BoxFuture, FutureExt};
+use log::{error, info, warn};
+use solana_rpc_client::{
+    nonblocking::rpc_client::RpcClient, rpc_client::GetConfirmedSignaturesForAddress2Config,
+};
+use solana_rpc_client_api::config::RpcTransactionConfig;
+use solana_sdk::{clock::UnixTimestamp, pubkey::Pubkey, signature::Signature};
+use solana_transaction_status_client_types::UiTransactionEncoding;
+use tokio::sync::mpsc::Sender;
+use yellowstone_grpc_proto::geyser::{SubscribeUpdateAccount, SubscribeUpdateTransaction};
+
+use crate::{
+    error::JitoBellError,
+    subscribe_option::url_host,
+    transaction_source::{
+        convert::{subscribe_update_account_from_rpc, subscribe_update_transaction_from_rpc},
+        SourceEvent, SourceMessage, TransactionSource,
+    },
+};
+
+/// Maximum signatures returned by a single `getSignaturesForAddress` call
+pub(crate) const SIGNATURE_PAGE_LIMIT: usize = 1_000;
+
+/// Maximum accounts fetched by a single `getMultipleAccounts` call
+const MULTIPLE_ACCOUNTS_LIMIT: usize = 100;
+
+/// Signature listed by `getSignaturesForAddress`
+pub(crate) struct AddressSignature {
+    /// Slot the transaction landed in
+    pub(crate) slot: u64,
+
+    /// Transaction signature
+    pub(crate) signature: Signature,
+
+    /// Whether the transaction failed
+    pub(crate) failed: bool,
+}
+
+/// Transaction source polling `getSignaturesForAddress` for each watched address
+///
+/// - Fallback for environments without Geyser access
+/// - The first poll only records the newest signature, history is left to backfill
+/// - Watched accounts are fetched on every poll
+pub(crate) struct RpcPollingSource {
+    /// RPC Client
+    rpc_client: Arc<RpcClient>,
+
+    /// Watched addresses, usually program IDs
+    addresses: Vec<Pubkey>,
+
+    /// Accounts whose state is forwarded
+    accounts: Vec<Pubkey>,
+
+    /// Interval between polls
+    poll_interval: Duration,
+
+    /// Forward failed transactions
+    include_failed: bool,
+}
+
+impl RpcPollingSource {
+    /// Initialize RPC polling source
+    pub(crate) fn new(
+        rpc_client: Arc<RpcClient>,
+        addresses: Vec<Pubkey>,
+        accounts: Vec<Pubkey>,
+        poll_interval: Duration,
+        include_failed: bool,
+    ) -> Self {
+        Self {
+            rpc_client,
+            addresses,
+            accounts,
+            poll_interval,
+            include_failed,
+        }
+    }
+
+    /// Poll every address on `poll_interval`, logging RPC errors and retrying on the next tick
+    async fn poll(self, source: usize, tx: Sender<SourceMessage>) -> Result<(), JitoBellError> {
+        let mut cursors: Vec<Option<Signature>> = vec![None; self.addresses.len()];
+        let mut interval = tokio::time::interval(self.poll_interval);
+
+        loop {
+            interval.tick().await;
+
+            match self.rpc_client.get_slot().await {
+                Ok(slot) => {
+                    let event = SourceEvent::Slot(slot);
+                    if tx.send(SourceMessage { source, event }).await.is_err() {
+                        return Ok(());
+                    }
+                }
+                Err(e) => warn!("RPC source [{source}] failed to fetch slot: {e}"),
+            }
+
+            match self.account_updates().await {
+                Ok(updates) => {
+                    for update in updates {
+                        let event = SourceEvent::Account(Box::new(update));
+                        if tx.send(SourceMessage { source, event }).await.is_err() {
+                            return Ok(());
+                        }
+                    }
+                }
+                Err(e) => error!("RPC source [{source}] failed to fetch accounts: {e}"),
+            }
+
+            // Ordered by slot so transactions are forwarded oldest first, and
+            // transactions touching several watched addresses are fetched once
+            let mut pending = BTreeSet::new();
+
+            for (address, cursor) in self.addresses.iter().zip(cursors.iter_mut()) {
+                let started = cursor.is_some();
+                match self.new_signatures(address, *cursor).await {
+                    Ok(signatures) => {
+                        if let Some(newest) = signatures.first() {
+                            *cursor = Some(newest.signature);
+                        }
+                        if started {
+                            pending.extend(
+                                signatures
+                                    .into_iter()
+                                    .filter(|entry| self.include_failed || !entry.failed)
+                                    .map(|entry| (entry.slot, entry.signature)),
+                            );
+                        }
+                    }
+                    Err(e) => error!("RPC source [{source}] failed to poll {address}: {e}"),
+                }
+            }
+
+            for (_, signature) in pending {
+                match fetch_transaction(&self.rpc_client, &signature).await {
+                    Ok((transaction, block_time)) => {
+                        if let Some(block_time) = block_time {
+                            let event = SourceEvent::BlockMeta {
+                                slot: transaction.slot,
+                                block_time,
+                            };
+                            if tx.send(SourceMessage { source, event }).await.is_err() {
+                                return Ok(());
+                            }
+                        }
+
+                        let event = SourceEvent::Transaction(Box::new(transaction));
+                        if tx.send(SourceMessage { source, event }).await.is_err() {
+                            return Ok(());
+                        }
+                    }
+                    Err(e) => error!("RPC source [{source}] failed to fetch {signature}: {e}"),
+                }
+            }
+        }
+    }
+
+    /// Current state of every watched account that exists
+    async fn account_updates(&self) -> Result<Vec<SubscribeUpdateAccount>, JitoBellError> {
+        let mut updates = Vec::new();
+
+        for chunk in self.accounts.chunks(MULTIPLE_ACCOUNTS_LIMIT) {
+            let response = self
+                .rpc_client
+                .get_multiple_accounts_with_commitment(chunk, self.rpc_client.commitment())
+                .await?;
+            let slot = response.context.slot;
+
+            updates.extend(
+                chunk
+                    .iter()
+                    .zip(response.value)
+                    .filter_map(|(pubkey, account)| {
+                        account
+                            .map(|account| subscribe_update_account_from_rpc(pubkey, account, slot))
+                    }),
+            );
+        }
+
+        Ok(updates)
+    }
+
+    /// Signatures for `address` newer than `until`, newest first
+    ///
+    /// - Without a cursor, return only the newest signature so polling starts from now
+    async fn new_signatures(
+        &self,
+        address: &Pubkey,
+        until: Option<Signature>,
+    ) -> Result<Vec<AddressSignature>, JitoBellError> {
+        let Some(until) = until else {
+            let newest = signatures_page(&self.rpc_client, address, None, None, 1).await?;
+            if let Some(entry) = newest.first() {
+                info!("RPC source polling {address} from slot {}", entry.slot);
+            }
+            return Ok(newest);
+        };
+
+        let mut signatures = Vec::new();
+        let mut before = None;
+
+        loop {
+            let page = signatures_page(
+                &self.rpc_client,
+                address,
+                before,
+                Some(until),
+                SIGNATURE_PAGE_LIMIT,
+            )
+            .await?;
+            let page_len = page.len();
+            before = page.last().map(|entry| entry.signature);
+            signatures.extend(page);
+
+            if page_len < SIGNATURE_PAGE_LIMIT {
+                break;
+            }
+        }
+
+        Ok(signatures)
+    }
+}
+
+impl TransactionSource for RpcPollingSource {
+    fn name(&self) -> String {
+        url_host(&self.rpc_client.url()).to_string()
+    }
+
+    fn run(
+        self: Box<Self>,
+        source: usize,
+        tx: Sender<SourceMessage>,
+    ) -> BoxFuture<'static, Result<(), JitoBellError>> {
+        self.poll(source, tx).boxed()
+    }
+}
+
+/// Fetch one page of signatures for `address`, newest first
+pub(crate) async fn signatures_page(
+    rpc_client: &RpcClient,
+    address: &Pubkey,
+    before: Option<Signature>,
+    until: Option<Signature>,
+    limit: usize,
+) -> Result<Vec<AddressSignature>, JitoBellError> {
+    let statuses = rpc_client
+        .get_signatures_for_address_with_config(
+            address,
+            GetConfirmedSignaturesForAddress2Config {
+                before,
+                until,
+                limit: Some(limit),
+                commitment: Some(rpc_client.commitment()),
+            },
+        )
+        .await?;
+
+    statuses
+        .into_iter()
+        .map(|status| {
+            let signature = Signature::from_str(&status.signature)
+                .map_err(|e| JitoBellError::TransactionParse(e.to_string()))?;
+            Ok(AddressSignature {
+                slot: status.slot,
+                signature,
+                failed: status.err.is_some(),
+            })
+        })
+        .collect()
+}
+
+/// Fetch a transaction and convert it into the Geyser shape, along with its block time
+pub(crate) async fn fetch_transaction(
+    rpc_client: &RpcClient,
+    signature: &Signature,
+) -> Result<(SubscribeUpdateTransaction, Option<UnixTimestamp>), JitoBellError> {
+    let transaction = rpc_client
+        .get_transaction_with_config(
+            signature,
+            RpcTransactionConfig {
+                encoding: Some(UiTransactionEncoding::Base64),
+                commitment: Some(rpc_client.commitment()),
+                max_supported_transaction_version: Some(0),
+            },
+        )
+        .await?;
+    let block_time = transaction.block_time;
+
+    Ok((
+        subscribe_update_transaction_from_rpc(transaction)?,
+        block_time,
+    ))
+}
@@ -0,0 +1,201 @@
+use std::str::FromStr;
+
+use jito_vault_client::errors::JitoVaultError;
+use num_traits::FromPrimitive;
+use solana_pubkey::Pubkey;
+use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+use spl_stake_pool::error::StakePoolError;
+use yellowstone_grpc_proto::prelude::TransactionStatusMeta;
+
+use crate::ix_parser::{
+    jito_steward::JitoStewardInstruction, stake_pool::SplStakePoolProgram, vault::JitoVaultProgram,
+};
+
+/// Decoded error of a failed transaction
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionFailure {
+    /// Runtime error
+    pub error: String,
+
+    /// Index of the failing top-level instruction
+    pub instruction_index: Option<u8>,
+
+    /// Program that returned the error, which may be invoked through CPI
+    pub program_id: Option<Pubkey>,
+
+    /// Name of the program error, for the stake pool, vault and steward programs
+    pub program_error: Option<String>,
+}
+
+impl TransactionFailure {
+    /// Decode the error of a failed transaction, `None` if it succeeded
+    ///
+    /// - The failing program is taken from the `Program <id> failed` log, so errors returned
+    ///   through CPI are attributed to the callee. Falls back to the top-level program.
+    pub fn decode(meta: &TransactionStatusMeta, top_level_program_ids: &[Pubkey]) -> Option<Self> {
+        let err = meta.err.as_ref()?;
+
+        let Ok(error) = bincode::deserialize::<TransactionError>(&err.err) else {
+            return Some(Self {
+                error: "Undecodable transaction error".to_string(),
+                instruction_index: None,
+                program_id: None,
+                program_error: None,
+            });
+        };
+
+        let (instruction_index, custom_code) = match &error {
+            TransactionError::InstructionError(index, InstructionError::Custom(code)) => {
+                (Some(*index), Some(*code))
+            }
+            TransactionError::InstructionError(index, _) => (Some(*index), None),
+            _ => (None, None),
+        };
+
+        let program_id = Self::failed_program_id(&meta.log_messages).or_else(|| {
+            instruction_index.and_then(|index| top_level_program_ids.get(index as usize).copied())
+        });
+        let program_error = match (program_id, custom_code) {
+            (Some(program_id), Some(code)) => {
+                Self::program_error_name(&program_id, code, &meta.log_messages)
+            }
+            _ => None,
+        };
+
+        Some(Self {
+            error: error.to_string(),
+            instruction_index,
+            program_id,
+            program_error,
+        })
+    }
+
+    /// Innermost program that failed, from the first `Program <id> failed: ...` log
+    fn failed_program_id(log_messages: &[String]) -> Option<Pubkey> {
+        log_messages.iter().find_map(|log| {
+            let (program_id, _) = log.strip_prefix("Program ")?.split_once(" failed: ")?;
+            Pubkey::from_str(program_id).ok()
+        })
+    }
+
+    /// Name of a custom program error
+    ///
+    /// - Steward is an Anchor program, so its error name is read from the `AnchorError` log
+    fn program_error_name(
+        program_id: &Pubkey,
+        code: u32,
+        log_messages: &[String],
+    ) -> Option<String> {
+        if program_id.eq(&SplStakePoolProgram::program_id()) {
+            StakePoolError::from_u32(code).map(|error| format!("{error:?}"))
+        } else if program_id.eq(&JitoVaultProgram::program_id()) {
+            JitoVaultError::from_u32(code).map(|error| format!("{error:?}"))
+        } else if program_id.eq(&JitoStewardInstruction::program_id()) {
+            log_messages.iter().find_map(|log| {
+                let (_, rest) = log.split_once("Error Code: ")?;
+                let (name, _) = rest.split_once('.')?;
+                Some(name.to_string())
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl std::fmt::Display for TransactionFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.program_error {
+            Some(program_error) => write!(f, "{program_error} ({})", self.error),
+            None => write!(f, "{}", self.error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_pubkey::Pubkey;
+    use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+    use yellowstone_grpc_proto::prelude::{
+        TransactionError as ProtoTransactionError, TransactionStatusMeta,
+    };
+
+    use super::TransactionFailure;
+    use crate::ix_parser::{
+        jito_steward::JitoStewardInstruction, stake_pool::SplStakePoolProgram,
+        vault::JitoVaultProgram,
+    };
+
+    fn failed_meta(error: TransactionError, log_messages: Vec<String>) -> TransactionStatusMeta {
+        TransactionStatusMeta {
+            err: Some(ProtoTransactionError {
+                err: bincode::serialize(&error).unwrap(),
+            }),
+            log_messages,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_decode_stake_pool_error() {
+        // 39 - ExceededSlippage
+        let meta = failed_meta(
+            TransactionError::InstructionError(0, InstructionError::Custom(39)),
+            vec![],
+        );
+
+        let failure =
+            TransactionFailure::decode(&meta, &[SplStakePoolProgram::program_id()]).unwrap();
+
+        assert_eq!(failure.instruction_index, Some(0));
+        assert_eq!(failure.program_id, Some(SplStakePoolProgram::program_id()));
+        assert_eq!(failure.program_error.as_deref(), Some("ExceededSlippage"));
+    }
+
+    #[test]
+    fn test_decode_vault_error_through_cpi() {
+        let router = Pubkey::new_unique();
+        let meta = failed_meta(
+            TransactionError::InstructionError(1, InstructionError::Custom(1000)),
+            vec![
+                format!("Program {router} invoke [1]"),
+                format!("Program {} invoke [2]", JitoVaultProgram::program_id()),
+                format!(
+                    "Program {} failed: custom program error: 0x3e8",
+                    JitoVaultProgram::program_id()
+                ),
+                format!("Program {router} failed: custom program error: 0x3e8"),
+            ],
+        );
+
+        let failure = TransactionFailure::decode(&meta, &[Pubkey::new_unique(), router]).unwrap();
+
+        assert_eq!(failure.program_id, Some(JitoVaultProgram::program_id()));
+        assert_eq!(
+            failure.program_error.as_deref(),
+            Some("VaultSlashUnderflow")
+        );
+    }
+
+    #[test]
+    fn test_decode_steward_anchor_error() {
+        let meta = failed_meta(
+            TransactionError::InstructionError(0, InstructionError::Custom(6001)),
+            vec![
+                "Program log: AnchorError occurred. Error Code: ScoringNotComplete. Error Number: 6001. Error Message: Scoring must be completed before any other steps can be taken.".to_string(),
+            ],
+        );
+
+        let failure =
+            TransactionFailure::decode(&meta, &[JitoStewardInstruction::program_id()]).unwrap();
+
+        assert_eq!(failure.program_error.as_deref(), Some("ScoringNotComplete"));
+        assert!(failure.to_string().starts_with("ScoringNotComplete ("));
+    }
+
+    #[test]
+    fn test_decode_successful_transaction() {
+        let meta = TransactionStatusMeta::default();
+
+        assert!(TransactionFailure::decode(&meta, &[]).is_none());
+    }
+}
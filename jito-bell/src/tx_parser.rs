@@ -1,13 +1,23 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
 use solana_pubkey::Pubkey;
-use solana_sdk::signature::Signature;
-use yellowstone_grpc_proto::geyser::SubscribeUpdateTransaction;
+use solana_sdk::{clock::UnixTimestamp, signature::Signature};
+use yellowstone_grpc_proto::{geyser::SubscribeUpdateTransaction, prelude::TransactionStatusMeta};
 
 use crate::{
-    event_parser::{jito_steward::JitoStewardEvent, EventParser},
+    event_parser::{
+        jito_steward::JitoStewardEvent, program_log::attribute_logs, EventParser, ParsedEvent,
+    },
     ix_parser::{
-        jito_steward::JitoStewardInstruction, stake_pool::SplStakePoolProgram,
+        instruction::ParsableInstruction, jito_steward::JitoStewardInstruction,
+        spl_token::SplTokenProgram, stake_pool::SplStakePoolProgram,
         token_2022::SplToken2022Program, vault::JitoVaultProgram, InstructionParser,
+        ParsedInstruction,
     },
+    tx_error::TransactionFailure,
 };
 
 /// Parse Transaction
@@ -16,159 +26,204 @@ pub struct JitoTransactionParser {
     /// Transaction signature
     pub transaction_signature: String,
 
-    /// The array of instructions related to Jito Network
-    pub instructions: Vec<InstructionParser>,
+    /// Slot the transaction landed in
+    pub slot: u64,
+
+    /// Block time of `slot`, when known
+    pub block_time: Option<UnixTimestamp>,
+
+    /// The array of instructions related to Jito Network, in execution order
+    ///
+    /// - Each instruction carries its place in the CPI tree of the transaction
+    pub instructions: Vec<ParsedInstruction>,
+
+    /// Events emitted by programs
+    ///
+    /// - Anchor `emit_cpi!` events in execution order, then events from the logs
+    /// - An invocation's log events are dropped if it emitted events through `emit_cpi!`, so an
+    ///   event emitted both ways is reported once
+    /// - Only events emitted by the program they are decoded for, log events are tracked
+    ///   through the invocation stack in the logs
+    pub events: Vec<ParsedEvent>,
+
+    /// Decoded error, if the transaction failed
+    pub failure: Option<TransactionFailure>,
 
-    /// Events emitted by programs, grouped by program
-    pub events: Vec<EventParser>,
+    /// Token accounts with a balance recorded before or after the transaction, by address
+    pub token_balances: HashMap<Pubkey, TokenBalanceChange>,
+}
+
+/// Balance of a token account before and after a transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenBalanceChange {
+    /// Token mint
+    pub mint: Pubkey,
+
+    /// Decimals of the mint
+    pub decimals: u8,
+
+    /// Balance before the transaction, 0 for an account created by it
+    pub pre: u64,
+
+    /// Balance after the transaction, 0 for an account closed by it
+    pub post: u64,
+}
+
+impl TokenBalanceChange {
+    /// Base units received by the account, negative if it sent tokens
+    pub fn delta(&self) -> i128 {
+        self.post as i128 - self.pre as i128
+    }
 }
 
 impl JitoTransactionParser {
     /// Initialize new parser
+    ///
+    /// - The block time is not part of the transaction update, the caller fills it in
     pub fn new(transaction: SubscribeUpdateTransaction) -> Self {
+        let slot = transaction.slot;
         let mut transaction_signature = String::new();
         let mut parsed_instructions = Vec::new();
         let mut parsed_events = Vec::new();
-        let mut pubkeys: Vec<Pubkey> = Vec::new();
+        let mut failure = None;
+        let mut token_balances = HashMap::new();
+        let mut steward_invoked = false;
 
         if let Some(tx) = transaction.transaction {
-            if let Some(ref meta) = tx.meta {
-                if meta.err.is_none() {
-                    if let Some(tx) = tx.transaction {
-                        let signature_slice = &tx.signatures[0];
-                        let mut slice = [0; 64];
-                        slice.copy_from_slice(&signature_slice[..64]);
-                        let tx_signature = Signature::from(slice);
-                        transaction_signature = tx_signature.to_string();
-
-                        if let Some(msg) = tx.message {
-                            pubkeys = msg
-                                .account_keys
-                                .iter()
-                                .map(|account_key| {
-                                    let mut slice = [0; 32];
-                                    slice.copy_from_slice(&account_key[..32]);
-                                    Pubkey::new_from_array(slice)
-                                })
-                                .collect();
-
-                            for instruction in &msg.instructions {
-                                if let Some(program_id) =
-                                    &pubkeys.get(instruction.program_id_index as usize)
+            if let (Some(meta), Some(tx)) = (tx.meta, tx.transaction) {
+                let signature_slice = &tx.signatures[0];
+                let mut slice = [0; 64];
+                slice.copy_from_slice(&signature_slice[..64]);
+                let tx_signature = Signature::from(slice);
+                transaction_signature = tx_signature.to_string();
+
+                if let Some(msg) = tx.message {
+                    let pubkeys = Self::account_keys(&msg.account_keys, &meta);
+                    token_balances = Self::token_balances(&meta, &pubkeys);
+                    let program_id = |program_id_index: u32| {
+                        pubkeys
+                            .get(program_id_index as usize)
+                            .copied()
+                            .unwrap_or_default()
+                    };
+
+                    let top_level_program_ids: Vec<Pubkey> = msg
+                        .instructions
+                        .iter()
+                        .map(|ix| program_id(ix.program_id_index))
+                        .collect();
+                    failure = TransactionFailure::decode(&meta, &top_level_program_ids);
+
+                    for (top_level_index, instruction) in msg.instructions.iter().enumerate() {
+                        let top_level_program_id = top_level_program_ids[top_level_index];
+
+                        if let Some(ix_info) =
+                            Self::parse_instruction(&top_level_program_id, instruction, &pubkeys)
+                        {
+                            parsed_instructions.push(ParsedInstruction {
+                                instruction: ix_info,
+                                program_id: top_level_program_id,
+                                top_level_index,
+                                inner_index: None,
+                                stack_height: 1,
+                                parent_inner_index: None,
+                                parent_program_id: None,
+                                top_level_program_id,
+                            });
+                        }
+
+                        steward_invoked |=
+                            top_level_program_id.eq(&JitoStewardInstruction::program_id());
+
+                        let Some(inner_instructions) = meta
+                            .inner_instructions
+                            .iter()
+                            .find(|inner| inner.index as usize == top_level_index)
+                        else {
+                            continue;
+                        };
+
+                        // Inner index and program of the invoking instruction at each stack
+                        // height, starting with the top-level instruction at height 1
+                        let mut invokers: Vec<(Option<usize>, Pubkey)> =
+                            vec![(None, top_level_program_id)];
+
+                        for (inner_index, instruction) in
+                            inner_instructions.instructions.iter().enumerate()
+                        {
+                            let inner_program_id = program_id(instruction.program_id_index);
+
+                            // Stack height is only recorded since v1.14, treat older
+                            // inner instructions as direct CPIs of the top-level instruction
+                            let stack_height = instruction.stack_height.unwrap_or(2).max(2);
+                            invokers.truncate(stack_height as usize - 1);
+                            let (parent_inner_index, parent_program_id) = invokers
+                                .last()
+                                .copied()
+                                .unwrap_or((None, top_level_program_id));
+                            invokers.push((Some(inner_index), inner_program_id));
+                            steward_invoked |=
+                                inner_program_id.eq(&JitoStewardInstruction::program_id());
+
+                            // Anchor `emit_cpi!` events, only kept if the transaction succeeded
+                            if inner_program_id.eq(&JitoStewardEvent::program_id())
+                                && parent_program_id.eq(&inner_program_id)
+                            {
+                                if let Some(event) =
+                                    JitoStewardEvent::parse_event_cpi(&instruction.data)
                                 {
-                                    match *program_id {
-                                        program_id
-                                            if program_id
-                                                .eq(&SplToken2022Program::program_id()) =>
-                                        {
-                                            if let Some(ix_info) =
-                                                SplToken2022Program::parse_spl_token_2022_program(
-                                                    instruction,
-                                                    &pubkeys,
-                                                )
-                                            {
-                                                parsed_instructions
-                                                    .push(InstructionParser::SplToken2022(ix_info));
-                                            }
-                                        }
-                                        program_id
-                                            if program_id
-                                                .eq(&SplStakePoolProgram::program_id()) =>
-                                        {
-                                            if let Some(ix_info) =
-                                                SplStakePoolProgram::parse_spl_stake_pool_program(
-                                                    instruction,
-                                                    &pubkeys,
-                                                )
-                                            {
-                                                parsed_instructions
-                                                    .push(InstructionParser::SplStakePool(ix_info));
-                                            }
-                                        }
-                                        program_id
-                                            if program_id.eq(&JitoVaultProgram::program_id()) =>
-                                        {
-                                            if let Some(ix_info) =
-                                                JitoVaultProgram::parse_jito_vault_program(
-                                                    instruction,
-                                                    &pubkeys,
-                                                )
-                                            {
-                                                parsed_instructions
-                                                    .push(InstructionParser::JitoVault(ix_info));
-                                            }
-                                        }
-                                        program_id
-                                            if program_id
-                                                .eq(&JitoStewardInstruction::program_id()) =>
-                                        {
-                                            if let Some(ix_info) =
-                                                JitoStewardInstruction::parse(instruction, &pubkeys)
-                                            {
-                                                parsed_instructions
-                                                    .push(InstructionParser::JitoSteward(ix_info));
-                                            }
-
-                                            for log in &meta.log_messages {
-                                                if let Some(event) =
-                                                    JitoStewardEvent::parse_log(log)
-                                                {
-                                                    parsed_events
-                                                        .push(EventParser::JitoSteward(event));
-                                                }
-                                            }
-                                        }
-                                        _ => continue,
+                                    if meta.err.is_none() {
+                                        parsed_events.push(ParsedEvent {
+                                            event: EventParser::JitoSteward(event),
+                                            program_id: inner_program_id,
+                                            top_level_index,
+                                            stack_height: stack_height as usize - 1,
+                                            inner_index: parent_inner_index,
+                                        });
                                     }
+                                    continue;
                                 }
                             }
+
+                            if let Some(ix_info) =
+                                Self::parse_instruction(&inner_program_id, instruction, &pubkeys)
+                            {
+                                parsed_instructions.push(ParsedInstruction {
+                                    instruction: ix_info,
+                                    program_id: inner_program_id,
+                                    top_level_index,
+                                    inner_index: Some(inner_index),
+                                    stack_height,
+                                    parent_inner_index,
+                                    parent_program_id: Some(parent_program_id),
+                                    top_level_program_id,
+                                });
+                            }
                         }
                     }
                 }
-            }
 
-            if let Some(meta) = tx.meta {
-                for instructions in meta.inner_instructions {
-                    for instruction in instructions.instructions {
-                        if let Some(program_id) =
-                            &pubkeys.get(instruction.program_id_index as usize)
+                // Events of a failed transaction were rolled back
+                if steward_invoked && meta.err.is_none() {
+                    let cpi_emitters: HashSet<(usize, Option<usize>)> = parsed_events
+                        .iter()
+                        .map(|event| (event.top_level_index, event.inner_index))
+                        .collect();
+
+                    for log in attribute_logs(&meta.log_messages) {
+                        if !log.program_id.eq(&JitoStewardEvent::program_id())
+                            || cpi_emitters.contains(&(log.top_level_index, log.inner_index))
                         {
-                            match *program_id {
-                                program_id if program_id.eq(&SplToken2022Program::program_id()) => {
-                                    if let Some(ix_info) =
-                                        SplToken2022Program::parse_spl_token_2022_program(
-                                            &instruction,
-                                            &pubkeys,
-                                        )
-                                    {
-                                        parsed_instructions
-                                            .push(InstructionParser::SplToken2022(ix_info));
-                                    }
-                                }
-                                program_id if program_id.eq(&SplStakePoolProgram::program_id()) => {
-                                    if let Some(ix_info) =
-                                        SplStakePoolProgram::parse_spl_stake_pool_program(
-                                            &instruction,
-                                            &pubkeys,
-                                        )
-                                    {
-                                        parsed_instructions
-                                            .push(InstructionParser::SplStakePool(ix_info));
-                                    }
-                                }
-                                program_id if program_id.eq(&JitoVaultProgram::program_id()) => {
-                                    if let Some(ix_info) =
-                                        JitoVaultProgram::parse_jito_vault_program(
-                                            &instruction,
-                                            &pubkeys,
-                                        )
-                                    {
-                                        parsed_instructions
-                                            .push(InstructionParser::JitoVault(ix_info));
-                                    }
-                                }
-                                _ => continue,
-                            }
+                            continue;
+                        }
+                        if let Some(event) = JitoStewardEvent::parse_log(log.log) {
+                            parsed_events.push(ParsedEvent {
+                                event: EventParser::JitoSteward(event),
+                                program_id: log.program_id,
+                                top_level_index: log.top_level_index,
+                                stack_height: log.stack_height,
+                                inner_index: log.inner_index,
+                            });
                         }
                     }
                 }
@@ -177,8 +232,566 @@ impl JitoTransactionParser {
 
         Self {
             transaction_signature,
+            slot,
+            block_time: None,
             instructions: parsed_instructions,
             events: parsed_events,
+            failure,
+            token_balances,
         }
     }
+
+    /// Parse an instruction of the token, token-2022, stake pool, vault or steward program
+    ///
+    /// - Used for top-level and inner instructions alike, so CPIs from a multisig or a crank
+    ///   program are parsed the same way
+    fn parse_instruction<T: ParsableInstruction>(
+        program_id: &Pubkey,
+        instruction: &T,
+        pubkeys: &[Pubkey],
+    ) -> Option<InstructionParser> {
+        match *program_id {
+            program_id if program_id.eq(&SplTokenProgram::program_id()) => {
+                SplTokenProgram::parse_spl_token_program(instruction, pubkeys)
+                    .map(InstructionParser::SplToken)
+            }
+            program_id if program_id.eq(&SplToken2022Program::program_id()) => {
+                SplToken2022Program::parse_spl_token_2022_program(instruction, pubkeys)
+                    .map(InstructionParser::SplToken2022)
+            }
+            program_id if program_id.eq(&SplStakePoolProgram::program_id()) => {
+                SplStakePoolProgram::parse_spl_stake_pool_program(instruction, pubkeys)
+                    .map(InstructionParser::SplStakePool)
+            }
+            program_id if program_id.eq(&JitoVaultProgram::program_id()) => {
+                JitoVaultProgram::parse_jito_vault_program(instruction, pubkeys)
+                    .map(InstructionParser::JitoVault)
+            }
+            program_id if program_id.eq(&JitoStewardInstruction::program_id()) => {
+                JitoStewardInstruction::parse(instruction, pubkeys)
+                    .map(InstructionParser::JitoSteward)
+            }
+            _ => None,
+        }
+    }
+
+    /// Instructions invoked by `parent` through a direct CPI
+    pub fn cpis<'a>(
+        &'a self,
+        parent: &'a ParsedInstruction,
+    ) -> impl Iterator<Item = &'a ParsedInstruction> {
+        self.instructions
+            .iter()
+            .filter(move |instruction| instruction.is_invoked_by(parent))
+    }
+
+    /// Account keys in the order instructions index them
+    ///
+    /// - Static keys, then addresses loaded from lookup tables: writable first, then readonly
+    fn account_keys(static_keys: &[Vec<u8>], meta: &TransactionStatusMeta) -> Vec<Pubkey> {
+        static_keys
+            .iter()
+            .chain(&meta.loaded_writable_addresses)
+            .chain(&meta.loaded_readonly_addresses)
+            .map(|account_key| {
+                let mut slice = [0; 32];
+                slice.copy_from_slice(&account_key[..32]);
+                Pubkey::new_from_array(slice)
+            })
+            .collect()
+    }
+
+    /// Pre and post token balances, by token account
+    fn token_balances(
+        meta: &TransactionStatusMeta,
+        pubkeys: &[Pubkey],
+    ) -> HashMap<Pubkey, TokenBalanceChange> {
+        let mut token_balances: HashMap<Pubkey, TokenBalanceChange> = HashMap::new();
+        let balances = meta
+            .pre_token_balances
+            .iter()
+            .map(|balance| (balance, false))
+            .chain(
+                meta.post_token_balances
+                    .iter()
+                    .map(|balance| (balance, true)),
+            );
+
+        for (balance, post) in balances {
+            let (Some(account), Ok(mint), Some(ui_token_amount)) = (
+                pubkeys.get(balance.account_index as usize),
+                Pubkey::from_str(&balance.mint),
+                balance.ui_token_amount.as_ref(),
+            ) else {
+                continue;
+            };
+            let amount = ui_token_amount.amount.parse().unwrap_or_default();
+
+            let token_balance = token_balances
+                .entry(*account)
+                .or_insert(TokenBalanceChange {
+                    mint,
+                    decimals: ui_token_amount.decimals as u8,
+                    pre: 0,
+                    post: 0,
+                });
+            if post {
+                token_balance.post = amount;
+            } else {
+                token_balance.pre = amount;
+            }
+        }
+
+        token_balances
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use solana_pubkey::Pubkey;
+    use solana_sdk::native_token::sol_to_lamports;
+    use yellowstone_grpc_proto::{
+        geyser::{SubscribeUpdateTransaction, SubscribeUpdateTransactionInfo},
+        prelude::{
+            CompiledInstruction, InnerInstruction, InnerInstructions, Message,
+            MessageAddressTableLookup, MessageHeader, TokenBalance, Transaction,
+            TransactionStatusMeta, UiTokenAmount,
+        },
+    };
+
+    use super::JitoTransactionParser;
+    use crate::{
+        event_parser::{
+            jito_steward::{JitoStewardEvent, EVENT_IX_TAG_LE},
+            EventParser,
+        },
+        ix_parser::{
+            jito_steward::JitoStewardInstruction, stake_pool::SplStakePoolProgram,
+            InstructionParser,
+        },
+    };
+
+    const JITOSOL_STAKE_POOL: &str = "Jito4APyf642JPZPx3hGc6WWJ8zPKtRbRs4P815Awbb";
+    const JITOSOL_RESERVE: &str = "BgKUXdS29YcHCFrPm5M8oLHiTzZaMDjsebggjoaQ6KFL";
+    const JITOSOL_MANAGER_FEE: &str = "feeeFLLsam6xZJFc6UQFrHqkvVt4jfmVvi2BRLkUZ4i";
+    const JITOSOL_MINT: &str = "J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn";
+    const JITOSOL_WITHDRAW_AUTHORITY: &str = "6iQKfEyhr3bZMotVkW6beNZz5CPAkiwvgV2CTje9pVSS";
+    const JUPITER_PROGRAM: &str = "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4";
+    const TOKEN_PROGRAM: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+    const SYSTEM_PROGRAM: &str = "11111111111111111111111111111111";
+
+    fn key(address: &str) -> Vec<u8> {
+        Pubkey::from_str(address).unwrap().to_bytes().to_vec()
+    }
+
+    fn deposit_sol_data(lamports: u64) -> Vec<u8> {
+        let mut data = vec![14];
+        data.extend_from_slice(&lamports.to_le_bytes());
+        data
+    }
+
+    /// v0 transaction whose JitoSOL accounts all come from one lookup table
+    ///
+    /// - Static keys: [0] user, [1] `program`
+    /// - Loaded writable: [2] stake pool, [3] reserve, [4] user JitoSOL account,
+    ///   [5] manager fee account, [6] pool mint
+    /// - Loaded readonly: [7] withdraw authority, [8] stake pool program,
+    ///   [9] token program, [10] system program
+    fn v0_transaction(
+        program: &str,
+        instructions: Vec<CompiledInstruction>,
+        inner_instructions: Vec<InnerInstructions>,
+        user_pool_account: &Pubkey,
+    ) -> SubscribeUpdateTransaction {
+        let user = Pubkey::new_unique();
+        let lookup_table = Pubkey::new_unique();
+
+        SubscribeUpdateTransaction {
+            transaction: Some(SubscribeUpdateTransactionInfo {
+                signature: vec![1; 64],
+                is_vote: false,
+                transaction: Some(Transaction {
+                    signatures: vec![vec![1; 64]],
+                    message: Some(Message {
+                        header: Some(MessageHeader {
+                            num_required_signatures: 1,
+                            num_readonly_signed_accounts: 0,
+                            num_readonly_unsigned_accounts: 1,
+                        }),
+                        account_keys: vec![user.to_bytes().to_vec(), key(program)],
+                        recent_blockhash: vec![0; 32],
+                        instructions,
+                        versioned: true,
+                        address_table_lookups: vec![MessageAddressTableLookup {
+                            account_key: lookup_table.to_bytes().to_vec(),
+                            writable_indexes: vec![0, 1, 2, 3, 4],
+                            readonly_indexes: vec![5, 6, 7, 8],
+                        }],
+                    }),
+                }),
+                meta: Some(TransactionStatusMeta {
+                    inner_instructions,
+                    loaded_writable_addresses: vec![
+                        key(JITOSOL_STAKE_POOL),
+                        key(JITOSOL_RESERVE),
+                        user_pool_account.to_bytes().to_vec(),
+                        key(JITOSOL_MANAGER_FEE),
+                        key(JITOSOL_MINT),
+                    ],
+                    loaded_readonly_addresses: vec![
+                        key(JITOSOL_WITHDRAW_AUTHORITY),
+                        SplStakePoolProgram::program_id().to_bytes().to_vec(),
+                        key(TOKEN_PROGRAM),
+                        key(SYSTEM_PROGRAM),
+                    ],
+                    ..Default::default()
+                }),
+                index: 0,
+            }),
+            slot: 0,
+        }
+    }
+
+    /// Accounts of `DepositSol` in instruction order, as indexes into `v0_transaction` keys
+    const DEPOSIT_SOL_ACCOUNTS: [u8; 10] = [2, 7, 3, 0, 4, 5, 4, 6, 10, 9];
+
+    fn assert_jitosol_deposit(parser: &JitoTransactionParser, user_pool_account: &Pubkey) {
+        let deposit = parser
+            .instructions
+            .iter()
+            .find_map(|ix| match &ix.instruction {
+                InstructionParser::SplStakePool(SplStakePoolProgram::DepositSol { ix, amount }) => {
+                    Some((ix, amount))
+                }
+                _ => None,
+            });
+        let (ix, amount) = deposit.expect("Expected DepositSol");
+
+        assert_eq!(*amount, 2.5);
+        assert_eq!(ix.accounts[0].pubkey.to_string(), JITOSOL_STAKE_POOL);
+        assert_eq!(
+            ix.accounts[1].pubkey.to_string(),
+            JITOSOL_WITHDRAW_AUTHORITY
+        );
+        assert_eq!(ix.accounts[2].pubkey.to_string(), JITOSOL_RESERVE);
+        assert_eq!(ix.accounts[4].pubkey, *user_pool_account);
+        assert_eq!(ix.accounts[5].pubkey.to_string(), JITOSOL_MANAGER_FEE);
+        assert_eq!(ix.accounts[7].pubkey.to_string(), JITOSOL_MINT);
+    }
+
+    #[test]
+    fn test_parse_top_level_ix_with_lookup_table_accounts() {
+        let user_pool_account = Pubkey::new_unique();
+        let transaction = v0_transaction(
+            &SplStakePoolProgram::program_id().to_string(),
+            vec![CompiledInstruction {
+                program_id_index: 1,
+                accounts: DEPOSIT_SOL_ACCOUNTS.to_vec(),
+                data: deposit_sol_data(sol_to_lamports(2.5)),
+            }],
+            vec![],
+            &user_pool_account,
+        );
+
+        let parser = JitoTransactionParser::new(transaction);
+
+        assert_jitosol_deposit(&parser, &user_pool_account);
+    }
+
+    #[test]
+    fn test_parse_cpi_to_program_loaded_from_lookup_table() {
+        let user_pool_account = Pubkey::new_unique();
+        let transaction = v0_transaction(
+            JUPITER_PROGRAM,
+            vec![CompiledInstruction {
+                program_id_index: 1,
+                accounts: (0..11).collect(),
+                data: vec![0xe5, 0x17, 0xcb, 0x97, 0x7a, 0xe3, 0xad, 0x2a],
+            }],
+            vec![InnerInstructions {
+                index: 0,
+                instructions: vec![InnerInstruction {
+                    program_id_index: 8,
+                    accounts: DEPOSIT_SOL_ACCOUNTS.to_vec(),
+                    data: deposit_sol_data(sol_to_lamports(2.5)),
+                    stack_height: Some(2),
+                }],
+            }],
+            &user_pool_account,
+        );
+
+        let parser = JitoTransactionParser::new(transaction);
+
+        assert_jitosol_deposit(&parser, &user_pool_account);
+
+        let deposit = &parser.instructions[0];
+        assert!(deposit.is_cpi());
+        assert_eq!(deposit.top_level_index, 0);
+        assert_eq!(deposit.stack_height, 2);
+        assert_eq!(deposit.parent_inner_index, None);
+        assert_eq!(
+            deposit.parent_program_id.map(|p| p.to_string()).as_deref(),
+            Some(JUPITER_PROGRAM)
+        );
+        assert_eq!(deposit.top_level_program_id.to_string(), JUPITER_PROGRAM);
+    }
+
+    #[test]
+    fn test_parse_steward_cpi_from_multisig() {
+        const SQUADS_PROGRAM: &str = "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf";
+        let copy_directed_stake_targets = hex::decode(
+            "8784097fbda10e054613d432f5da8f600ac38dcd8f5c07df361f5b832400b012604624443b3f457000000000000000006e000000",
+        )
+        .unwrap();
+        let state_transition = "agl496lqzumDAwAAAAAAAFUiLBcAAAAACQAAAFJlYmFsYW5jZQQAAABJZGxl";
+        let state_transition_log = format!("Program data: {state_transition}");
+        let mut emit_cpi_data = EVENT_IX_TAG_LE.to_vec();
+        emit_cpi_data.extend_from_slice(&STANDARD.decode(state_transition).unwrap());
+
+        let transaction = SubscribeUpdateTransaction {
+            transaction: Some(SubscribeUpdateTransactionInfo {
+                signature: vec![1; 64],
+                is_vote: false,
+                transaction: Some(Transaction {
+                    signatures: vec![vec![1; 64]],
+                    message: Some(Message {
+                        header: Some(MessageHeader {
+                            num_required_signatures: 1,
+                            num_readonly_signed_accounts: 0,
+                            num_readonly_unsigned_accounts: 2,
+                        }),
+                        account_keys: vec![
+                            Pubkey::new_unique().to_bytes().to_vec(),
+                            key(SQUADS_PROGRAM),
+                            JitoStewardInstruction::program_id().to_bytes().to_vec(),
+                        ],
+                        recent_blockhash: vec![0; 32],
+                        instructions: vec![CompiledInstruction {
+                            program_id_index: 1,
+                            accounts: vec![0, 2],
+                            data: vec![0xc2, 0x08, 0xa1, 0x57, 0x99, 0xa4, 0x19, 0xab],
+                        }],
+                        versioned: false,
+                        address_table_lookups: vec![],
+                    }),
+                }),
+                meta: Some(TransactionStatusMeta {
+                    inner_instructions: vec![InnerInstructions {
+                        index: 0,
+                        instructions: vec![
+                            InnerInstruction {
+                                program_id_index: 2,
+                                accounts: vec![0, 0, 0, 0, 0],
+                                data: copy_directed_stake_targets,
+                                stack_height: Some(2),
+                            },
+                            InnerInstruction {
+                                program_id_index: 2,
+                                accounts: vec![0],
+                                data: emit_cpi_data,
+                                stack_height: Some(3),
+                            },
+                        ],
+                    }],
+                    log_messages: vec![
+                        format!("Program {SQUADS_PROGRAM} invoke [1]"),
+                        // Same discriminator, but not emitted by the steward
+                        state_transition_log.to_string(),
+                        format!(
+                            "Program {} invoke [2]",
+                            JitoStewardInstruction::program_id()
+                        ),
+                        state_transition_log.to_string(),
+                        format!("Program {} success", JitoStewardInstruction::program_id()),
+                        format!("Program {SQUADS_PROGRAM} success"),
+                    ],
+                    ..Default::default()
+                }),
+                index: 0,
+            }),
+            slot: 0,
+        };
+
+        let parser = JitoTransactionParser::new(transaction);
+
+        assert_eq!(parser.instructions.len(), 1);
+        let steward = &parser.instructions[0];
+        assert!(matches!(
+            steward.instruction,
+            InstructionParser::JitoSteward(JitoStewardInstruction::CopyDirectedStakeTargets { .. })
+        ));
+        assert_eq!(steward.top_level_program_id.to_string(), SQUADS_PROGRAM);
+        // Emitted both through the `emit_cpi!` self-CPI and the steward's logs, reported once
+        assert_eq!(parser.events.len(), 1);
+        let event = &parser.events[0];
+        assert!(matches!(
+            event.event,
+            EventParser::JitoSteward(JitoStewardEvent::StateTransition(_))
+        ));
+        assert_eq!(event.program_id, JitoStewardEvent::program_id());
+        assert_eq!(event.stack_height, 2);
+        assert_eq!(event.top_level_index, 0);
+        assert_eq!(event.inner_index, Some(0));
+    }
+
+    #[test]
+    fn test_dedupe_steward_events_per_invocation() {
+        let steward = JitoStewardInstruction::program_id();
+        let state_transition = "agl496lqzumDAwAAAAAAAFUiLBcAAAAACQAAAFJlYmFsYW5jZQQAAABJZGxl";
+        let state_transition_log = format!("Program data: {state_transition}");
+        let mut emit_cpi_data = EVENT_IX_TAG_LE.to_vec();
+        emit_cpi_data.extend_from_slice(&STANDARD.decode(state_transition).unwrap());
+        let steward_instruction = || CompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data: vec![0; 8],
+        };
+
+        let transaction = SubscribeUpdateTransaction {
+            transaction: Some(SubscribeUpdateTransactionInfo {
+                signature: vec![1; 64],
+                is_vote: false,
+                transaction: Some(Transaction {
+                    signatures: vec![vec![1; 64]],
+                    message: Some(Message {
+                        header: Some(MessageHeader {
+                            num_required_signatures: 1,
+                            num_readonly_signed_accounts: 0,
+                            num_readonly_unsigned_accounts: 1,
+                        }),
+                        account_keys: vec![
+                            Pubkey::new_unique().to_bytes().to_vec(),
+                            steward.to_bytes().to_vec(),
+                        ],
+                        recent_blockhash: vec![0; 32],
+                        instructions: vec![steward_instruction(), steward_instruction()],
+                        versioned: false,
+                        address_table_lookups: vec![],
+                    }),
+                }),
+                meta: Some(TransactionStatusMeta {
+                    inner_instructions: vec![InnerInstructions {
+                        index: 0,
+                        instructions: vec![InnerInstruction {
+                            program_id_index: 1,
+                            accounts: vec![0],
+                            data: emit_cpi_data,
+                            stack_height: Some(2),
+                        }],
+                    }],
+                    log_messages: vec![
+                        // Emitted both ways by the first instruction
+                        format!("Program {steward} invoke [1]"),
+                        state_transition_log.to_string(),
+                        format!("Program {steward} invoke [2]"),
+                        format!("Program {steward} success"),
+                        format!("Program {steward} success"),
+                        // Only logged by the second instruction
+                        format!("Program {steward} invoke [1]"),
+                        state_transition_log.to_string(),
+                        format!("Program {steward} success"),
+                    ],
+                    ..Default::default()
+                }),
+                index: 0,
+            }),
+            slot: 0,
+        };
+
+        let parser = JitoTransactionParser::new(transaction);
+
+        let emitters: Vec<(usize, Option<usize>)> = parser
+            .events
+            .iter()
+            .map(|event| (event.top_level_index, event.inner_index))
+            .collect();
+        assert_eq!(emitters, vec![(0, None), (1, None)]);
+    }
+
+    #[test]
+    fn test_parse_nested_cpi_tree() {
+        let user_pool_account = Pubkey::new_unique();
+        let inner_deposit = |stack_height| InnerInstruction {
+            program_id_index: 8,
+            accounts: DEPOSIT_SOL_ACCOUNTS.to_vec(),
+            data: deposit_sol_data(sol_to_lamports(2.5)),
+            stack_height: Some(stack_height),
+        };
+        let transaction = v0_transaction(
+            JUPITER_PROGRAM,
+            vec![CompiledInstruction {
+                program_id_index: 1,
+                accounts: (0..11).collect(),
+                data: vec![0xe5, 0x17, 0xcb, 0x97, 0x7a, 0xe3, 0xad, 0x2a],
+            }],
+            vec![InnerInstructions {
+                index: 0,
+                instructions: vec![inner_deposit(2), inner_deposit(3), inner_deposit(2)],
+            }],
+            &user_pool_account,
+        );
+
+        let parser = JitoTransactionParser::new(transaction);
+        assert_eq!(parser.instructions.len(), 3);
+
+        let (outer, nested, sibling) = (
+            &parser.instructions[0],
+            &parser.instructions[1],
+            &parser.instructions[2],
+        );
+        assert_eq!(nested.stack_height, 3);
+        assert_eq!(nested.parent_inner_index, Some(0));
+        assert_eq!(
+            nested.parent_program_id,
+            Some(SplStakePoolProgram::program_id())
+        );
+        assert_eq!(sibling.parent_inner_index, None);
+
+        let cpis: Vec<_> = parser.cpis(outer).collect();
+        assert_eq!(cpis.len(), 1);
+        assert_eq!(cpis[0].inner_index, Some(1));
+        assert_eq!(parser.cpis(nested).count(), 0);
+    }
+
+    #[test]
+    fn test_token_balance_changes() {
+        let user_pool_account = Pubkey::new_unique();
+        let mut transaction = v0_transaction(
+            &SplStakePoolProgram::program_id().to_string(),
+            vec![CompiledInstruction {
+                program_id_index: 1,
+                accounts: DEPOSIT_SOL_ACCOUNTS.to_vec(),
+                data: deposit_sol_data(sol_to_lamports(2.5)),
+            }],
+            vec![],
+            &user_pool_account,
+        );
+        let balance = |amount: &str| TokenBalance {
+            account_index: 4,
+            mint: JITOSOL_MINT.to_string(),
+            ui_token_amount: Some(UiTokenAmount {
+                decimals: 9,
+                amount: amount.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let meta = transaction
+            .transaction
+            .as_mut()
+            .and_then(|tx| tx.meta.as_mut())
+            .unwrap();
+        meta.post_token_balances = vec![balance("2000000000")];
+
+        let parser = JitoTransactionParser::new(transaction);
+
+        let token_balance = &parser.token_balances[&user_pool_account];
+        assert_eq!(token_balance.mint.to_string(), JITOSOL_MINT);
+        assert_eq!(token_balance.decimals, 9);
+        assert_eq!(token_balance.pre, 0);
+        assert_eq!(token_balance.delta(), 2_000_000_000);
+    }
 }
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use solana_sdk::pubkey::Pubkey;
+
+/// Step of a vault's update cycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UpdateStep {
+    /// `InitializeVaultUpdateStateTracker`
+    Started,
+
+    /// `CrankVaultUpdateStateTracker`
+    Cranked,
+
+    /// `CloseVaultUpdateStateTracker` of the tracker opened in `ncn_epoch`
+    Closed { ncn_epoch: u64 },
+
+    /// `UpdateVaultBalance`
+    BalanceUpdated,
+}
+
+/// Update cycle of a vault in one vault epoch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UpdateCycle {
+    /// Vault epoch
+    pub(crate) epoch: u64,
+
+    /// Slot the state tracker was initialized in
+    pub(crate) started_slot: Option<u64>,
+
+    /// Operators cranked so far
+    pub(crate) cranked_operators: u64,
+
+    /// Slot the state tracker was closed in, completing the update
+    pub(crate) completed_slot: Option<u64>,
+
+    /// Slot the vault balance was last updated in
+    pub(crate) balance_updated_slot: Option<u64>,
+
+    /// Whether the vault was reported stale in this epoch
+    pub(crate) stale_seen: bool,
+}
+
+impl UpdateCycle {
+    /// Initialize the cycle of vault epoch `epoch`, with nothing done yet
+    fn new(epoch: u64) -> Self {
+        Self {
+            epoch,
+            started_slot: None,
+            cranked_operators: 0,
+            completed_slot: None,
+            balance_updated_slot: None,
+            stale_seen: false,
+        }
+    }
+}
+
+/// Update cycles of the vaults under `vault_updates`, in their latest vault epoch
+///
+/// - Seeded from each vault's `last_full_state_update_slot`, then kept up to date from
+///   observed update instructions
+/// - A vault is up to date once the state tracker of the current vault epoch is closed
+pub(crate) struct VaultUpdates {
+    /// Latest cycle of each tracked vault
+    cycles: HashMap<Pubkey, UpdateCycle>,
+
+    /// Vault program epoch length in slots, from its `Config`
+    epoch_length: Option<u64>,
+}
+
+impl VaultUpdates {
+    /// Initialize update cycles of `vaults`
+    pub(crate) fn new(vaults: impl IntoIterator<Item = Pubkey>) -> Self {
+        Self {
+            cycles: vaults
+                .into_iter()
+                .map(|vault| (vault, UpdateCycle::new(0)))
+                .collect(),
+            epoch_length: None,
+        }
+    }
+
+    /// Vault program epoch length, once read
+    pub(crate) fn epoch_length(&self) -> Option<u64> {
+        self.epoch_length
+    }
+
+    /// Record the vault program epoch length
+    pub(crate) fn set_epoch_length(&mut self, epoch_length: u64) {
+        self.epoch_length = Some(epoch_length).filter(|epoch_length| *epoch_length > 0);
+    }
+
+    /// Vault epoch of `slot`, and how many slots into it `slot` is
+    pub(crate) fn epoch_position(&self, slot: u64) -> Option<(u64, u64)> {
+        self.epoch_length
+            .map(|epoch_length| (slot / epoch_length, slot % epoch_length))
+    }
+
+    /// Latest cycle of `vault`, if tracked
+    pub(crate) fn get(&self, vault: &Pubkey) -> Option<&UpdateCycle> {
+        self.cycles.get(vault)
+    }
+
+    /// Record `step` of `vault`'s update cycle, landed in `slot`
+    ///
+    /// - Steps from before the vault's latest cycle are ignored
+    /// - Closing the tracker of an earlier vault epoch does not complete the cycle
+    pub(crate) fn record(
+        &mut self,
+        vault: &Pubkey,
+        step: UpdateStep,
+        slot: u64,
+    ) -> Option<&UpdateCycle> {
+        let (epoch, _) = self.epoch_position(slot)?;
+        let cycle = self.cycles.get_mut(vault)?;
+        if cycle.epoch < epoch {
+            *cycle = UpdateCycle::new(epoch);
+        }
+        if cycle.epoch != epoch {
+            return None;
+        }
+
+        match step {
+            UpdateStep::Started => cycle.started_slot = Some(slot),
+            UpdateStep::Cranked => cycle.cranked_operators += 1,
+            UpdateStep::Closed { ncn_epoch } => {
+                if ncn_epoch == epoch {
+                    cycle.completed_slot = Some(slot);
+                }
+            }
+            UpdateStep::BalanceUpdated => cycle.balance_updated_slot = Some(slot),
+        }
+
+        Some(cycle)
+    }
+
+    /// Vaults still not updated `stale_after_slots` into the vault epoch of `slot`
+    ///
+    /// - `stale_after_slots` gives the offset of each vault, `None` to skip it
+    /// - Each vault is reported once per vault epoch
+    pub(crate) fn stale(
+        &mut self,
+        slot: u64,
+        stale_after_slots: impl Fn(&Pubkey) -> Option<u64>,
+    ) -> Vec<(Pubkey, UpdateCycle)> {
+        let Some((epoch, offset)) = self.epoch_position(slot) else {
+            return Vec::new();
+        };
+
+        let mut stale = Vec::new();
+        for (vault, cycle) in self.cycles.iter_mut() {
+            if cycle.epoch < epoch {
+                *cycle = UpdateCycle::new(epoch);
+            }
+            if cycle.epoch != epoch || cycle.completed_slot.is_some() || cycle.stale_seen {
+                continue;
+            }
+
+            if stale_after_slots(vault).is_some_and(|stale_after| offset >= stale_after) {
+                cycle.stale_seen = true;
+                stale.push((*vault, cycle.clone()));
+            }
+        }
+
+        stale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::pubkey::Pubkey;
+
+    use super::{UpdateStep, VaultUpdates};
+
+    #[test]
+    fn test_stale_reported_once_per_epoch() {
+        let vault = Pubkey::new_unique();
+        let mut updates = VaultUpdates::new([vault]);
+        updates.set_epoch_length(1_000);
+
+        assert!(updates.stale(1_099, |_| Some(100)).is_empty());
+        let stale = updates.stale(1_100, |_| Some(100));
+        assert_eq!(stale.len(), 1);
+        assert_eq!(stale[0].1.epoch, 1);
+        assert!(updates.stale(1_500, |_| Some(100)).is_empty());
+
+        assert_eq!(updates.stale(2_100, |_| Some(100)).len(), 1);
+    }
+
+    #[test]
+    fn test_closing_current_tracker_completes_cycle() {
+        let vault = Pubkey::new_unique();
+        let mut updates = VaultUpdates::new([vault]);
+        updates.set_epoch_length(1_000);
+
+        updates.record(&vault, UpdateStep::Started, 1_010);
+        updates.record(&vault, UpdateStep::Cranked, 1_020);
+        updates.record(&vault, UpdateStep::Closed { ncn_epoch: 0 }, 1_030);
+        assert!(updates.get(&vault).unwrap().completed_slot.is_none());
+
+        let cycle = updates
+            .record(&vault, UpdateStep::Closed { ncn_epoch: 1 }, 1_040)
+            .unwrap();
+        assert_eq!(cycle.started_slot, Some(1_010));
+        assert_eq!(cycle.cranked_operators, 1);
+        assert_eq!(cycle.completed_slot, Some(1_040));
+        assert!(updates.stale(1_900, |_| Some(100)).is_empty());
+
+        // Late steps of an earlier epoch leave the current cycle untouched
+        updates.stale(2_000, |_| Some(100));
+        assert!(updates.record(&vault, UpdateStep::Started, 1_999).is_none());
+    }
+}
@@ -0,0 +1,14 @@
+use serde::Deserialize;
+
+use crate::notification_info::NotificationInfo;
+
+/// Update cycle alerts of a vault
+#[derive(Debug, Clone, Deserialize)]
+pub struct VaultUpdateConfig {
+    /// Slots into the vault epoch the update cycle has to be closed by
+    pub stale_after_slots: u64,
+
+    /// Notification for a vault still stale `stale_after_slots` into the epoch, and once it
+    /// is updated after that
+    pub notification: NotificationInfo,
+}
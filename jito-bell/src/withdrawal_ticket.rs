@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+
+use solana_sdk::pubkey::Pubkey;
+
+/// Withdrawal ticket enqueued and not burned yet
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct WithdrawalTicket {
+    /// Vault
+    pub(crate) vault: Pubkey,
+
+    /// Current owner
+    pub(crate) staker: Pubkey,
+
+    /// VRT amount, in VRT
+    pub(crate) vrt_amount: f64,
+
+    /// VRT symbol
+    pub(crate) symbol: String,
+
+    /// Slot the withdrawal was enqueued in
+    pub(crate) slot_unstaked: u64,
+
+    /// Signature of the enqueue transaction
+    pub(crate) signature: String,
+
+    /// Whether the ticket was seen becoming claimable
+    claimable_seen: bool,
+
+    /// Whether the ticket was seen left unclaimed
+    unclaimed_seen: bool,
+}
+
+impl WithdrawalTicket {
+    /// Initialize a ticket enqueued in `slot_unstaked`
+    pub(crate) fn new(
+        vault: Pubkey,
+        staker: Pubkey,
+        vrt_amount: f64,
+        symbol: String,
+        slot_unstaked: u64,
+        signature: String,
+    ) -> Self {
+        Self {
+            vault,
+            staker,
+            vrt_amount,
+            symbol,
+            slot_unstaked,
+            signature,
+            claimable_seen: false,
+            unclaimed_seen: false,
+        }
+    }
+
+    /// First slot the ticket can be burned in
+    ///
+    /// - A full vault epoch has to pass after the epoch the withdrawal was enqueued in
+    pub(crate) fn claimable_slot(&self, epoch_length: u64) -> u64 {
+        (self.slot_unstaked / epoch_length + 2) * epoch_length
+    }
+}
+
+/// Lifecycle event of a tracked withdrawal ticket
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum TicketEvent {
+    /// Ticket can be burned from now on
+    Claimable {
+        address: Pubkey,
+        ticket: WithdrawalTicket,
+    },
+
+    /// Ticket is still not burned `epochs` vault epochs after becoming claimable
+    Unclaimed {
+        address: Pubkey,
+        ticket: WithdrawalTicket,
+        epochs: u64,
+    },
+}
+
+/// Withdrawal tickets from `EnqueueWithdrawal` to `BurnWithdrawalTicket`
+///
+/// - Tickets enqueued before startup are not tracked
+pub(crate) struct WithdrawalTickets {
+    /// Tracked tickets, by address
+    tickets: HashMap<Pubkey, WithdrawalTicket>,
+
+    /// Vault program epoch length in slots, from its `Config`
+    epoch_length: Option<u64>,
+
+    /// VRT divisor and symbol of each vault
+    vrts: HashMap<Pubkey, (f64, String)>,
+}
+
+impl WithdrawalTickets {
+    /// Initialize withdrawal tickets
+    pub(crate) fn new() -> Self {
+        Self {
+            tickets: HashMap::new(),
+            epoch_length: None,
+            vrts: HashMap::new(),
+        }
+    }
+
+    /// Record the vault program epoch length
+    pub(crate) fn set_epoch_length(&mut self, epoch_length: u64) {
+        self.epoch_length = Some(epoch_length).filter(|epoch_length| *epoch_length > 0);
+    }
+
+    /// VRT divisor and symbol of `vault`, once read
+    pub(crate) fn vrt(&self, vault: &Pubkey) -> Option<&(f64, String)> {
+        self.vrts.get(vault)
+    }
+
+    /// Record the VRT divisor and symbol of `vault`
+    pub(crate) fn set_vrt(&mut self, vault: Pubkey, divisor: f64, symbol: String) {
+        self.vrts.insert(vault, (divisor, symbol));
+    }
+
+    /// Tracked ticket at `address`
+    pub(crate) fn get(&self, address: &Pubkey) -> Option<&WithdrawalTicket> {
+        self.tickets.get(address)
+    }
+
+    /// Track a newly enqueued ticket
+    pub(crate) fn enqueue(&mut self, address: Pubkey, ticket: WithdrawalTicket) {
+        self.tickets.insert(address, ticket);
+    }
+
+    /// Move a tracked ticket to `new_owner`
+    pub(crate) fn change_owner(&mut self, address: &Pubkey, new_owner: Pubkey) {
+        if let Some(ticket) = self.tickets.get_mut(address) {
+            ticket.staker = new_owner;
+        }
+    }
+
+    /// Stop tracking a burned ticket
+    pub(crate) fn burn(&mut self, address: &Pubkey) -> Option<WithdrawalTicket> {
+        self.tickets.remove(address)
+    }
+
+    /// Tickets that became claimable, or were left unclaimed, by `slot`
+    ///
+    /// - `unclaimed_epochs` gives the vault epochs a vault's tickets may stay unclaimed
+    /// - Each event is reported once per ticket
+    pub(crate) fn due(
+        &mut self,
+        slot: u64,
+        unclaimed_epochs: impl Fn(&Pubkey) -> Option<u64>,
+    ) -> Vec<TicketEvent> {
+        let Some(epoch_length) = self.epoch_length else {
+            return Vec::new();
+        };
+
+        let mut events = Vec::new();
+        for (address, ticket) in self.tickets.iter_mut() {
+            let claimable_slot = ticket.claimable_slot(epoch_length);
+            if slot < claimable_slot {
+                continue;
+            }
+
+            if !ticket.claimable_seen {
+                ticket.claimable_seen = true;
+                events.push(TicketEvent::Claimable {
+                    address: *address,
+                    ticket: ticket.clone(),
+                });
+            }
+
+            if let Some(epochs) = unclaimed_epochs(&ticket.vault) {
+                let unclaimed_slot =
+                    claimable_slot.saturating_add(epochs.saturating_mul(epoch_length));
+                if !ticket.unclaimed_seen && slot >= unclaimed_slot {
+                    ticket.unclaimed_seen = true;
+                    events.push(TicketEvent::Unclaimed {
+                        address: *address,
+                        ticket: ticket.clone(),
+                        epochs,
+                    });
+                }
+            }
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::pubkey::Pubkey;
+
+    use super::{TicketEvent, WithdrawalTicket, WithdrawalTickets};
+
+    fn ticket(slot_unstaked: u64) -> WithdrawalTicket {
+        WithdrawalTicket::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            10.0,
+            "VRT".to_string(),
+            slot_unstaked,
+            "5sig".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_claimable_after_one_full_epoch() {
+        assert_eq!(ticket(1_000).claimable_slot(1_000), 3_000);
+        assert_eq!(ticket(1_999).claimable_slot(1_000), 3_000);
+    }
+
+    #[test]
+    fn test_due_reports_each_event_once() {
+        let address = Pubkey::new_unique();
+        let mut tickets = WithdrawalTickets::new();
+        tickets.set_epoch_length(1_000);
+        tickets.enqueue(address, ticket(1_500));
+
+        assert!(tickets.due(2_999, |_| Some(2)).is_empty());
+        assert!(matches!(
+            tickets.due(3_000, |_| Some(2)).as_slice(),
+            [TicketEvent::Claimable { .. }]
+        ));
+        assert!(tickets.due(4_999, |_| Some(2)).is_empty());
+        assert!(matches!(
+            tickets.due(5_000, |_| Some(2)).as_slice(),
+            [TicketEvent::Unclaimed { epochs: 2, .. }]
+        ));
+
+        tickets.burn(&address);
+        assert!(tickets.due(10_000, |_| Some(2)).is_empty());
+    }
+}